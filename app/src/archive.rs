@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: 0BSD
+// sponge256sum
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{ErrorKind as IoErrorKind, Read, Result as IoResult, Seek, SeekFrom, Take},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+use tar::Archive;
+
+use crate::{
+    common::FileMeta,
+    os::{path_from_bytes, path_to_bytes},
+};
+
+/// The character that separates the path of the archive from the name of the member, i.e., `archive.tar!member`
+pub const MEMBER_SEPARATOR: u8 = b'!';
+
+/// The file extension of archives that are expanded into their members (case-insensitive)
+const ARCHIVE_EXTENSION: &str = "tar";
+
+// ---------------------------------------------------------------------------
+// Archive index
+// ---------------------------------------------------------------------------
+
+/// The location of a regular file that is stored in an archive
+#[derive(Clone)]
+struct Member {
+    offset: u64,
+    size: u64,
+    mtime: Option<u64>,
+}
+
+/// The regular files that are stored in an archive, in archive order, as well as a lookup table by name
+struct Index {
+    members: Vec<(PathBuf, Member)>,
+    lookup: BTreeMap<PathBuf, usize>,
+}
+
+/// Identifies an archive by its path, size and modification time, so that the index of a modified archive is *not* reused
+#[derive(PartialEq, Eq)]
+struct ArchiveKey {
+    path: PathBuf,
+    size: u64,
+    mtime: Option<SystemTime>,
+}
+
+impl ArchiveKey {
+    fn new(archive_path: &Path) -> IoResult<Self> {
+        let metadata = fs::metadata(archive_path)?;
+        Ok(Self { path: archive_path.to_owned(), size: metadata.len(), mtime: metadata.modified().ok() })
+    }
+}
+
+/// The most recently loaded index, because the members of an archive are usually processed consecutively
+static LAST_INDEX: Mutex<Option<(ArchiveKey, Arc<Index>)>> = Mutex::new(None);
+
+/// Read the headers of all entries in the given archive, the contents of the entries are skipped
+fn read_index(archive_path: &Path) -> IoResult<Index> {
+    let mut archive = Archive::new(File::open(archive_path)?);
+    let (mut members, mut lookup) = (Vec::new(), BTreeMap::new());
+
+    for entry in archive.entries_with_seek()? {
+        let entry = entry?;
+        if entry.header().entry_type().is_file() {
+            let member = Member { offset: entry.raw_file_position(), size: entry.size(), mtime: entry.header().mtime().ok() };
+            let name = entry.path()?.into_owned();
+            lookup.insert(name.clone(), members.len()); /* a later entry replaces an earlier one of the same name */
+            members.push((name, member));
+        }
+    }
+
+    Ok(Index { members, lookup })
+}
+
+/// Load the index of the given archive, or return the cached index, if it refers to the same archive and the archive has *not* been modified
+fn load_index(archive_path: &Path) -> IoResult<Arc<Index>> {
+    let archive_key = ArchiveKey::new(archive_path)?;
+    let mut last_index = LAST_INDEX.lock().unwrap();
+    if let Some((_, index)) = last_index.as_ref().filter(|(key, _)| *key == archive_key) {
+        return Ok(Arc::clone(index));
+    }
+
+    let index = Arc::new(read_index(archive_path)?);
+    *last_index = Some((archive_key, Arc::clone(&index)));
+    Ok(index)
+}
+
+// ---------------------------------------------------------------------------
+// Member paths
+// ---------------------------------------------------------------------------
+
+/// Check whether the given file is an archive that can be expanded, based on its file extension
+pub fn is_archive(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case(ARCHIVE_EXTENSION))
+}
+
+/// Create the synthetic path of the form `archive.tar!member` for the given member
+fn member_path(archive_path: &Path, name: &Path) -> PathBuf {
+    let mut path_bytes = path_to_bytes(archive_path).into_owned();
+    path_bytes.push(MEMBER_SEPARATOR);
+    path_bytes.extend_from_slice(&path_to_bytes(name));
+    path_from_bytes(path_bytes)
+}
+
+/// Split a synthetic path of the form `archive.tar!member` into the path of the archive and the name of the member
+///
+/// The path is split at the *first* separator that follows an archive file extension. Returns `None`, if there is no such separator.
+fn split_member_path(path: &Path) -> Option<(PathBuf, PathBuf)> {
+    let path_bytes = path_to_bytes(path);
+    path_bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| **value == MEMBER_SEPARATOR)
+        .map(|(position, _)| path_bytes.split_at(position))
+        .map(|(archive_path, name)| (path_from_bytes(archive_path.to_vec()), &name[1usize..]))
+        .find(|(archive_path, name)| !name.is_empty() && is_archive(archive_path))
+        .map(|(archive_path, name)| (archive_path, path_from_bytes(name.to_vec())))
+}
+
+/// List the regular files that are stored in the given archive, as synthetic paths, in archive order
+pub fn list_members(archive_path: &Path) -> IoResult<Vec<PathBuf>> {
+    let index = load_index(archive_path)?;
+    Ok(index
+        .members
+        .iter()
+        .enumerate()
+        .filter(|(position, (name, _))| index.lookup.get(name) == Some(position))
+        .map(|(_, (name, _))| member_path(archive_path, name))
+        .collect())
+}
+
+/// Open the member of an archive that the given synthetic path refers to, returns a reader for its contents as well as its metadata
+///
+/// Returns `None`, if the path does *not* refer to a member of an existing archive, or if a file of that exact name exists.
+pub fn open_member(path: &Path) -> Option<IoResult<(Take<File>, FileMeta)>> {
+    let (archive_path, name) = split_member_path(path)?;
+    if path.symlink_metadata().is_ok() || !archive_path.is_file() {
+        return None;
+    }
+
+    Some(load_index(&archive_path).and_then(|index| {
+        let (_, member) = index.lookup.get(&name).map(|position| &index.members[*position]).ok_or(IoErrorKind::NotFound)?;
+        let mut file = File::open(&archive_path)?;
+        file.seek(SeekFrom::Start(member.offset))?;
+        Ok((file.take(member.size), FileMeta { size: Some(member.size), mtime: member.mtime }))
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+    use tar::{Builder, Header};
+
+    fn write_archive(archive_path: &Path, data: &[u8]) {
+        let mut header = Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        let mut builder = Builder::new(File::create(archive_path).unwrap());
+        builder.append_data(&mut header, "member.txt", data).unwrap();
+        builder.finish().unwrap();
+    }
+
+    fn read_member(archive_path: &Path) -> Vec<u8> {
+        let (mut reader, _file_meta) = open_member(&member_path(archive_path, Path::new("member.txt"))).unwrap().unwrap();
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_index_reload() {
+        let archive_path = env::temp_dir().join(format!("sponge256sum_index_{:08X}.tar", process::id()));
+
+        write_archive(&archive_path, b"Hello, world!");
+        assert_eq!(read_member(&archive_path), b"Hello, world!");
+
+        write_archive(&archive_path, &[0x41u8; 1024usize]);
+        assert_eq!(read_member(&archive_path), [0x41u8; 1024usize]);
+
+        fs::remove_file(&archive_path).unwrap();
+    }
+}
@@ -2,21 +2,28 @@
 // sponge256sum
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
+use anstream::{AutoStream, ColorChoice};
 use build_time::build_time_utc;
-use clap::{builder::TypedValueParser, error::ErrorKind, Arg, ArgAction, ArgGroup, Command, Error, Parser};
+use cfg_if::cfg_if;
+use clap::{builder::TypedValueParser, error::ErrorKind, Arg, ArgAction, ArgGroup, Command, Error, Parser, ValueEnum};
 use const_format::formatcp;
+use glob::Pattern;
+use hex::{decode as decode_hex, encode as encode_hex};
 use rustc_version_const::rustc_version_full;
 use sponge_hash_aes256::version;
 use std::{
     env::consts::{ARCH, OS},
     ffi::OsStr,
-    num::NonZeroUsize,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    io::stdout,
+    num::{NonZeroU64, NonZeroUsize},
     path::{Component, Path, PathBuf},
-    sync::OnceLock,
+    sync::{LazyLock, OnceLock},
 };
 use wild::args_os;
 
-use crate::common::ExitStatus;
+use crate::{common::ExitStatus, digest::snail_rounds, os::STDIN_NAME};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -28,8 +35,11 @@ pub const BUILD_PROFILE: &str = if cfg!(debug_assertions) { "debug" } else { "re
 /// Version string
 pub const VERSION: &str = formatcp!("v{} [SpongeHash-AES256 v{}] [{OS}] [{ARCH}] [{BUILD_PROFILE}]", env!("CARGO_PKG_VERSION"), version());
 
-/// Full version string
-pub const LONG_VERSION: &str = formatcp!("{VERSION}\nBuilt on: {}\nCompiled using rustc version: {}", build_time_utc!("%F, %T"), rustc_version_full());
+/// Build information
+pub const BUILD_INFO: &str = formatcp!("{VERSION}\nBuilt on: {}\nCompiled using rustc version: {}", build_time_utc!("%F, %T"), rustc_version_full());
+
+/// Full version string, including the AES backend and the SIMD features (detected at runtime)
+pub static LONG_VERSION: LazyLock<String> = LazyLock::new(|| format!("{BUILD_INFO}\nAES backend: {}\nSIMD features: {}", aes_backend(), simd_features()));
 
 /// Header line
 pub const HEADER_LINE: &str = formatcp!("{} v{} (with SpongeHash-AES256 v{})", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), version());
@@ -43,6 +53,41 @@ pub const HELP_TEXT: &str = "If no input files are specified, reads input data f
     Returns a non-zero exit code if any errors occurred; otherwise, zero.\n\
     For details please refer to: <https://crates.io/crates/sponge-hash-aes256>";
 
+// ---------------------------------------------------------------------------
+// Platform features
+// ---------------------------------------------------------------------------
+
+/// Target features that are relevant for the SIMD operations, as enabled at compile-time
+const SIMD_FEATURES: [(&str, bool); 6usize] = [
+    ("sse2", cfg!(target_feature = "sse2")),
+    ("sse4.1", cfg!(target_feature = "sse4.1")),
+    ("avx", cfg!(target_feature = "avx")),
+    ("avx2", cfg!(target_feature = "avx2")),
+    ("neon", cfg!(target_feature = "neon")),
+    ("simd128", cfg!(target_feature = "simd128")),
+];
+
+/// Detect the AES implementation that is going to be used, based on the CPU features that are available at runtime
+fn aes_backend() -> &'static str {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("aes") {
+        return "AES-NI (hardware accelerated)";
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("aes") {
+        return "ARMv8 Crypto Extensions (hardware accelerated)";
+    }
+
+    "Software (constant-time fixslice implementation)"
+}
+
+/// Format the list of SIMD target features, along with the lane width
+fn simd_features() -> String {
+    let features: Vec<&str> = SIMD_FEATURES.iter().filter_map(|(name, enabled)| enabled.then_some(*name)).collect();
+    format!("u8x16 [{}]", if features.is_empty() { "scalar fallback".to_owned() } else { features.join(", ") })
+}
+
 // ---------------------------------------------------------------------------
 // Normalize paths
 // ---------------------------------------------------------------------------
@@ -63,6 +108,66 @@ impl TypedValueParser for NormalizingFileParser {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Binary values
+// ---------------------------------------------------------------------------
+
+/// Binary value, e.g., the salt or the binary context information, decoded from hex format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexBytes(Vec<u8>);
+
+impl HexBytes {
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for HexBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&encode_hex(&self.0))
+    }
+}
+
+/// Parser for binary values in hex format, with the given name used in error messages
+#[derive(Clone)]
+struct HexBytesParser(&'static str);
+
+impl TypedValueParser for HexBytesParser {
+    type Value = HexBytes;
+
+    fn parse_ref(&self, cmd: &Command, _arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+        match value.to_str().map(decode_hex) {
+            Some(Ok(bytes)) if (!bytes.is_empty()) && (bytes.len() <= u8::MAX as usize) => Ok(HexBytes(bytes)),
+            _ => Err(Error::raw(ErrorKind::InvalidValue, format!("{} must be a hex string of 1 to 255 bytes, got {:?}\n", self.0, value)).with_cmd(cmd)),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plain output fields
+// ---------------------------------------------------------------------------
+
+/// Fields that can be selected for the plain output format
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlainField {
+    Hex,
+    Size,
+    Name,
+}
+
+// ---------------------------------------------------------------------------
+// Color mode
+// ---------------------------------------------------------------------------
+
+/// Selects whether the verification results are colorized
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
 // ---------------------------------------------------------------------------
 // Command-line arguments
 // ---------------------------------------------------------------------------
@@ -72,9 +177,9 @@ impl TypedValueParser for NormalizingFileParser {
 #[command(about = ABOUT_TEXT)]
 #[command(after_help = HELP_TEXT)]
 #[command(before_help = HEADER_LINE)]
-#[command(long_version = LONG_VERSION)]
+#[command(long_version = LONG_VERSION.as_str())]
 #[command(version = VERSION)]
-#[command(group(ArgGroup::new("walk").args(["dirs", "recursive", "cross_dev"]).multiple(true)))]
+#[command(group(ArgGroup::new("walk").args(["dirs", "recursive", "cross_dev", "tree_digest"]).multiple(true)))]
 pub struct Args {
     /// Read the input file(s) in binary mode, i.e., default mode
     #[arg(short, long, conflicts_with = "text")]
@@ -84,10 +189,62 @@ pub struct Args {
     #[arg(short, long, conflicts_with = "binary")]
     pub text: bool,
 
+    /// In text mode, also treat a lone carriage return ('\r') as a line break
+    #[arg(long, requires = "text")]
+    pub cr_newline: bool,
+
+    /// Compute the digest in "tree" hashing mode, which allows a single file to be hashed in parallel
+    #[arg(long, conflicts_with_all = ["check", "text", "info", "info_file", "info_hex", "salt", "snail", "snail_target"])]
+    pub tree: bool,
+
     /// Read and verify checksums from the provided input file(s)
     #[arg(short, long)]
     pub check: bool,
 
+    /// Verify the input file(s) against the checksums that are stored in their extended attributes (Unix only)
+    #[arg(long, requires = "files", conflicts_with_all = ["check", "self_test", "walk", "files_from", "length", "bytes", "tree", "with_meta", "dry_run"])]
+    pub check_xattr: bool,
+
+    /// Store the digest of each input file in its extended attributes, in addition to printing it (Unix only)
+    #[arg(long, conflicts_with_all = ["check", "check_xattr", "compare", "verify_one", "self_test", "dry_run", "tree_digest"])]
+    pub write_xattr: bool,
+
+    /// Compare the digest of a single input (file or 'stdin') to the given digest, in hex format
+    #[arg(long, value_name = "HEX", conflicts_with_all = ["check", "check_xattr", "self_test", "walk", "files_from", "length", "bytes", "tree", "with_meta", "dry_run"])]
+    pub compare: Option<String>,
+
+    /// Compare the digest of exactly one input file ('-' for 'stdin') to the given digest, in hex format
+    #[arg(long, value_name = "HEX", requires = "files", conflicts_with_all = ["compare", "check", "check_xattr", "self_test", "walk", "files_from", "length", "bytes", "tree", "with_meta", "dry_run"])]
+    pub verify_one: Option<String>,
+
+    /// Compare the digest block by block, as it is squeezed, stopping at the first mismatch (requires '--check')
+    #[arg(long, requires = "check")]
+    pub lazy_compare: bool,
+
+    /// Warn about improperly formatted checksum lines (instead of failing), or about skipped inputs
+    #[arg(long)]
+    pub warn: bool,
+
+    /// Warn about checksum lines that are well-formed, but not in the canonical form, e.g., uppercase hex digits, and reject comment lines (requires '--check')
+    #[arg(long, requires = "check")]
+    pub strict: bool,
+
+    /// Print only the files that failed the verification, i.e., suppress "OK" results
+    #[arg(long, requires = "check")]
+    pub fail_only: bool,
+
+    /// Print the list of all files that failed the verification at the end (requires '--check')
+    #[arg(long, requires = "check")]
+    pub list_failed: bool,
+
+    /// Write a header line that identifies the algorithm and its parameters, which is verified by '--check'
+    #[arg(long, conflicts_with_all = ["check", "self_test", "compare", "verify_one", "dry_run", "plain", "ndjson", "tree_digest"])]
+    pub self_describing: bool,
+
+    /// Write an updated copy of the checksum file, where the digests of all files that did not match are replaced (requires '--check' with a single checksum file, implies -k)
+    #[arg(long, value_name = "FILE", requires = "check", conflicts_with_all = ["lazy_compare", "with_meta"])]
+    pub regenerate: Option<PathBuf>,
+
     /// Enable processing of directories as arguments
     #[arg(short, long, conflicts_with = "check")]
     pub dirs: bool,
@@ -100,26 +257,82 @@ pub struct Args {
     #[arg(short = 'x', long, conflicts_with = "check")]
     pub cross_dev: bool,
 
+    /// Skip files and directories matching the given pattern (may be repeated)
+    #[arg(long, value_name = "GLOB", requires = "walk")]
+    pub exclude: Vec<Pattern>,
+
+    /// Follow symbolic links found in directories, instead of skipping them
+    #[arg(short = 'L', long, requires = "walk")]
+    pub follow_symlinks: bool,
+
     /// Iterate all kinds of files, instead of just regular files
     #[arg(short, long, requires = "walk")]
     pub all: bool,
 
+    /// Skip files found in directories whose size exceeds the given number of bytes
+    #[arg(long, value_name = "BYTES", requires = "walk")]
+    pub max_size: Option<u64>,
+
+    /// Stop walking the directories after the given number of files have been found, e.g., for untrusted inputs
+    #[arg(long, value_name = "N", requires = "walk")]
+    pub limit_files: Option<NonZeroUsize>,
+
+    /// Sort the entries of each directory by file name, so that the order of the output is reproducible
+    #[arg(long, requires = "walk")]
+    pub sorted: bool,
+
+    /// Skip inputs that refer to a file that has already been processed
+    #[arg(long, conflicts_with = "check")]
+    pub dedup: bool,
+
     /// Continue processing even if errors are encountered.
     #[arg(short, long)]
     pub keep_going: bool,
 
-    /// Digest output size, in bits (default: 256, maximum: 2048)
+    /// Digest output size, in bits (default: 256, minimum: 8, maximum: 2048)
     #[arg(short, long, conflicts_with = "check")]
-    pub length: Option<NonZeroUsize>,
+    pub length: Option<usize>,
+
+    /// Digest output size, in bytes (default: 32, maximum: 256)
+    #[arg(long, value_name = "N", conflicts_with_all = ["check", "length"])]
+    pub bytes: Option<NonZeroUsize>,
 
     /// Include additional context information
     #[arg(short, long)]
     pub info: Option<String>,
 
+    /// Read the additional context information from the given file
+    #[arg(long, value_name = "FILE", conflicts_with = "info")]
+    pub info_file: Option<PathBuf>,
+
+    /// Include additional context information, given as binary value in hex format
+    #[arg(long, value_name = "HEX", value_parser = HexBytesParser("Context info"), conflicts_with_all = ["info", "info_file"])]
+    pub info_hex: Option<HexBytes>,
+
+    /// Absorb the given salt, in hex format, after the context information (e.g., a random per-run value)
+    #[arg(long, value_name = "HEX", value_parser = HexBytesParser("Salt"))]
+    pub salt: Option<HexBytes>,
+
+    /// Abort reading any single input that exceeds the given number of bytes
+    #[arg(long, value_name = "N")]
+    pub max_bytes: Option<NonZeroU64>,
+
+    /// Wipe (zeroize) the read buffer after each input has been hashed, e.g., for sensitive files
+    #[arg(long)]
+    pub wipe: bool,
+
+    /// Size of the read buffer, in KiB (maximum: 65536)
+    #[arg(long, value_name = "KIB")]
+    pub buffer_size: Option<NonZeroUsize>,
+
     /// Enable "snail" mode, i.e., slow down the hash computation
     #[arg(short, long, action = ArgAction::Count)]
     pub snail: u8,
 
+    /// Calibrate the number of permutation rounds, such that a hash takes about the given time on this machine
+    #[arg(long, value_name = "MILLIS", conflicts_with = "snail")]
+    pub snail_target: Option<NonZeroU64>,
+
     /// Do not output any error messages or warnings
     #[arg(short, long)]
     pub quiet: bool,
@@ -128,29 +341,156 @@ pub struct Args {
     #[arg(short, long, conflicts_with = "quiet")]
     pub no_color: bool,
 
+    /// Colorize the verification results, 'auto' only if the output is a terminal (default: 'auto')
+    #[arg(long, value_name = "WHEN", default_value = "auto", hide_default_value = true)]
+    pub color: ColorMode,
+
     /// Print digest(s) in plain format, i.e., without file names
     #[arg(short, long, conflicts_with = "check")]
     pub plain: bool,
 
-    /// Separate digest(s) by NULL characters instead of newlines
+    /// Select the fields of the plain format, as a comma-separated list (default: 'hex')
+    #[arg(long, value_name = "SPEC", value_delimiter = ',', requires = "plain")]
+    pub plain_fields: Vec<PlainField>,
+
+    /// Include the file size and modification time with each digest, or verify them in '--check' mode
+    #[arg(long, conflicts_with_all = ["plain", "tag"])]
+    pub with_meta: bool,
+
+    /// Append the number of permutation rounds to each digest, so that '--check' can use the same number of rounds
+    #[arg(long, conflicts_with_all = ["check", "plain", "tree", "tree_digest", "dry_run"])]
+    pub show_rounds: bool,
+
+    /// Print digest(s) in BSD-style "tagged" format, including the digest size
+    #[arg(long, conflicts_with_all = ["check", "plain"])]
+    pub tag: bool,
+
+    /// Escape file names that contain backslash or newline characters, in the style of GNU coreutils
+    #[arg(long, conflicts_with_all = ["check", "plain", "ndjson"])]
+    pub quote: bool,
+
+    /// Separate digest(s) by NULL characters instead of newlines (in '--check' mode, also for reading the checksum file)
     #[arg(short = '0', long, alias = "zero", short_alias = 'z')]
     pub null: bool,
 
-    /// Enable multi-threaded processing of input files
-    #[arg(short, long, conflicts_with = "self_test")]
+    /// Print result(s) as newline-delimited JSON (NDJSON) objects
+    #[arg(long, conflicts_with_all = ["plain", "null", "tag"])]
+    pub ndjson: bool,
+
+    /// Enable multi-threaded processing of input files (or multi-threaded self-test)
+    #[arg(short, long)]
     pub multi_threading: bool,
 
     /// Explicitly flush 'stdout' stream after printing a digest
     #[arg(short, long)]
     pub flush: bool,
 
+    /// Print the total number of files and bytes processed, as well as the elapsed time, at the end (or the verification statistics)
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Print the time that was spent on hashing each file, as well as the throughput, to 'stderr'
+    #[arg(long, conflicts_with_all = ["check", "self_test", "compare", "verify_one", "dry_run"])]
+    pub time: bool,
+
+    /// Periodically print the number of bytes processed and the throughput to 'stderr'
+    #[arg(long, conflicts_with = "multi_threading")]
+    pub progress: bool,
+
+    /// List the files that would be processed, without actually computing any digests
+    #[arg(long, conflicts_with_all = ["check", "self_test", "tag", "plain", "with_meta", "summary", "progress"])]
+    pub dry_run: bool,
+
+    /// Skip files whose checksum is already listed in the given checksum file, e.g., from an interrupted run
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["check", "self_test", "compare", "verify_one", "plain", "ndjson"])]
+    pub continue_from: Option<PathBuf>,
+
+    /// Print the file names relative to the given base directory, e.g., so that the checksum file is portable across machines
+    #[arg(long, value_name = "BASE", conflicts_with_all = ["check", "check_xattr", "compare", "verify_one", "self_test", "continue_from", "tree_digest"])]
+    pub relative_to: Option<PathBuf>,
+
+    /// Resolve relative file names from the checksum file against the given directory, instead of the current directory (requires '--check')
+    #[arg(long, value_name = "DIR", requires = "check")]
+    pub base_dir: Option<PathBuf>,
+
+    /// Hash the members of archive files (.tar) individually, as 'archive.tar!member', or verify such members in '--check' mode
+    #[cfg(feature = "with-tar")]
+    #[arg(long, conflicts_with_all = ["check_xattr", "write_xattr", "compare", "verify_one", "self_test", "continue_from", "block_stdin"])]
+    pub into_archives: bool,
+
+    /// Print a single digest for the whole directory tree, computed from the sorted file names and digests (implies '--recursive')
+    #[arg(long, conflicts_with_all = ["check", "self_test", "compare", "verify_one", "dry_run", "tag", "plain", "ndjson", "with_meta", "quote", "continue_from"])]
+    pub tree_digest: bool,
+
+    /// Read records from 'stdin' and print a separate digest for each record, delimited by newlines (or see '--record-size')
+    #[arg(long, conflicts_with_all = ["check", "check_xattr", "write_xattr", "compare", "verify_one", "self_test", "walk", "files_from", "files", "tree", "tree_digest", "text", "time", "dry_run", "continue_from"])]
+    pub block_stdin: bool,
+
+    /// Split the input into records of the given number of bytes, instead of at newlines (requires '--block-stdin')
+    #[arg(long, value_name = "BYTES", requires = "block_stdin")]
+    pub record_size: Option<NonZeroUsize>,
+
     /// Run the built-in self-test (BIST)
     #[arg(short = 'T', long, conflicts_with_all = ["check", "files"])]
     pub self_test: bool,
 
+    /// Read the list of files to be processed from the given file ('-' for 'stdin')
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["check", "files", "self_test"])]
+    pub files_from: Option<PathBuf>,
+
     /// Files to be processed
     #[arg(value_parser = NormalizingFileParser)]
     pub files: Vec<PathBuf>,
+
+    /// The number of permutation rounds that was selected by the '--snail-target' calibration
+    #[arg(skip)]
+    pub calibrated_rounds: OnceLock<NonZeroUsize>,
+}
+
+impl Args {
+    /// Returns `true`, if archive files are expanded into their members, which requires the "with-tar" feature
+    #[inline]
+    pub fn expand_archives(&self) -> bool {
+        cfg_if! {
+            if #[cfg(feature = "with-tar")] {
+                self.into_archives
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Returns the number of permutation rounds, either as selected by the '--snail-target' calibration or as given by the "snail" level
+    #[inline]
+    pub fn rounds(&self) -> NonZeroUsize {
+        self.calibrated_rounds.get().copied().unwrap_or_else(|| snail_rounds(self.snail))
+    }
+
+    /// Returns the context information as bytes, either from '--info' (or '--info-file') or from '--info-hex'
+    #[inline]
+    pub fn info_bytes(&self) -> Option<&[u8]> {
+        self.info.as_ref().map(String::as_bytes).or_else(|| self.info_hex.as_ref().map(HexBytes::as_bytes))
+    }
+}
+
+/// Read the context information from a file, trimming a single trailing line break
+fn read_info_file(path: &Path) -> Result<String, Error> {
+    match fs::read_to_string(path) {
+        Ok(info) => match info.strip_suffix('\n') {
+            Some(trimmed) => Ok(trimmed.strip_suffix('\r').unwrap_or(trimmed).to_owned()),
+            None => Ok(info),
+        },
+        Err(error) => Err(Error::raw(ErrorKind::Io, format!("Failed to read context info file {:?}: {}\n", path, error))),
+    }
+}
+
+/// Canonicalize the base directory for '--relative-to', so that it can be compared to the canonicalized input paths
+fn canonicalize_base_dir(path: &Path) -> Result<PathBuf, Error> {
+    match fs::canonicalize(path) {
+        Ok(base_dir) if base_dir.is_dir() => Ok(base_dir),
+        Ok(_) => Err(Error::raw(ErrorKind::InvalidValue, format!("Base directory is not a directory: {:?}\n", path))),
+        Err(error) => Err(Error::raw(ErrorKind::Io, format!("Failed to resolve base directory {:?}: {}\n", path, error))),
+    }
 }
 
 /// Singleton instance
@@ -160,8 +500,22 @@ static ARGS_INSTANCE: OnceLock<Result<Args, Error>> = OnceLock::new();
 pub fn parse_command_line() -> Result<&'static Args, ExitStatus> {
     let instance = ARGS_INSTANCE.get_or_init(|| match Args::try_parse_from(args_os()) {
         Ok(mut args) => {
-            args.recursive |= args.cross_dev;
+            args.recursive |= args.cross_dev || args.tree_digest;
             args.dirs |= args.recursive;
+            args.keep_going |= args.regenerate.is_some();
+            if args.regenerate.is_some() && !matches!(args.files.as_slice(), [file_name] if !STDIN_NAME.eq(file_name)) {
+                return Err(Error::raw(ErrorKind::ArgumentConflict, "The '--regenerate' option requires exactly one checksum file, other than 'stdin'\n"));
+            }
+            if args.color == ColorMode::Auto {
+                let is_enabled = !(args.no_color || AutoStream::choice(&stdout()) == ColorChoice::Never);
+                args.color = if is_enabled { ColorMode::Always } else { ColorMode::Never };
+            }
+            if let Some(info_file) = args.info_file.as_ref() {
+                args.info = Some(read_info_file(info_file)?);
+            }
+            if let Some(relative_to) = args.relative_to.as_mut() {
+                *relative_to = canonicalize_base_dir(relative_to)?;
+            }
             Ok(args)
         }
         Err(error) => Err(error),
@@ -192,6 +546,18 @@ mod tests {
         assert_eq!(NormalizingFileParser.parse_ref(&cmd, None, OsStr::new(input)).unwrap().to_str().unwrap(), expected);
     }
 
+    #[test]
+    fn test_hex_bytes_parser() {
+        let (cmd, parser) = (Command::new(""), HexBytesParser("Salt"));
+        assert_eq!(parser.parse_ref(&cmd, None, OsStr::new("00ff1a")).unwrap(), HexBytes(vec![0x00u8, 0xFFu8, 0x1Au8]));
+        assert_eq!(parser.parse_ref(&cmd, None, OsStr::new("00FF1A")).unwrap().to_string(), "00ff1a");
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("")).is_err());
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("abc")).is_err());
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("xy")).is_err());
+        assert!(parser.parse_ref(&cmd, None, OsStr::new(&"00".repeat(256usize))).is_err());
+        assert!(parser.parse_ref(&cmd, None, OsStr::new(&"00".repeat(255usize))).is_ok());
+    }
+
     #[cfg(target_family = "unix")]
     #[test]
     fn test_parser() {
@@ -2,15 +2,22 @@
 // sponge256sum
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
+use const_format::formatcp;
 use num::traits::SaturatingAdd;
 use sponge_hash_aes256::DEFAULT_DIGEST_SIZE;
 use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    fs::Metadata,
+    io::ErrorKind as IoErrorKind,
     num::NonZeroUsize,
     process::ExitCode,
     sync::atomic::{AtomicUsize, Ordering},
+    time::UNIX_EPOCH,
 };
 use tinyvec::{ArrayVec, TinyVec};
 
+use crate::{arguments::Args, io::OutStream, print_error};
+
 // ---------------------------------------------------------------------------
 // Common definitions
 // ---------------------------------------------------------------------------
@@ -21,6 +28,21 @@ pub const MAX_SNAIL_LEVEL: u8 = 4u8;
 /// Maximum allowable digest size, specified in bytes
 pub const MAX_DIGEST_SIZE: usize = 8usize * DEFAULT_DIGEST_SIZE;
 
+/// Maximum allowable read buffer size, specified in KiB
+pub const MAX_BUFFER_SIZE: usize = 65536usize;
+
+/// Algorithm name, as used in the BSD-style "tagged" format
+pub const ALGORITHM_TAG: &str = "SpongeHash256";
+
+/// Prefix of the header line that describes the algorithm and its parameters, as used by the `--self-describing` option
+pub const HEADER_PREFIX: &str = formatcp!("#! {} ", ALGORITHM_TAG);
+
+/// Separator between the digest and the number of permutation rounds, as used by the `--show-rounds` option
+pub const ROUNDS_SEPARATOR: u8 = b'@';
+
+/// Name of the extended attribute that holds the checksum, as used by the `--write-xattr` and `--check-xattr` options
+pub const XATTR_NAME: &str = "user.sponge256";
+
 /// Type for holding a digest
 pub type Digest = TinyVec<[u8; DEFAULT_DIGEST_SIZE]>;
 
@@ -37,6 +59,8 @@ pub enum ExitStatus {
     Success,
     /// One or more warnings have been encountered (but **no** fatal error)
     Warning,
+    /// One or more checksums did not match (but **no** other error)
+    Mismatch,
     /// A fatal error has been encountered
     Failure,
 }
@@ -45,7 +69,7 @@ impl From<ExitStatus> for ExitCode {
     fn from(value: ExitStatus) -> Self {
         match value {
             ExitStatus::Success => Self::from(0u8),
-            ExitStatus::Warning => Self::from(1u8),
+            ExitStatus::Warning | ExitStatus::Mismatch => Self::from(1u8),
             ExitStatus::Failure => Self::from(2u8),
         }
     }
@@ -130,6 +154,112 @@ impl<const N: usize, T: Copy + Default> TinyVecEx for TinyVec<[T; N]> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// JSON string
+// ---------------------------------------------------------------------------
+
+/// Wrapper that formats the contained string as an escaped JSON string (without the surrounding quotes)
+pub struct JsonStr<'a>(pub &'a str);
+
+impl Display for JsonStr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// File metadata
+// ---------------------------------------------------------------------------
+
+/// File metadata, i.e., the file size (in bytes) and the modification time (in seconds since the Unix epoch)
+///
+/// Fields that are not available, e.g., for the standard input stream, are set to `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FileMeta {
+    pub size: Option<u64>,
+    pub mtime: Option<u64>,
+}
+
+impl FileMeta {
+    /// Create a new instance from the given `Metadata`
+    pub fn from_metadata(meta_data: &Metadata) -> Self {
+        let mtime = meta_data.modified().ok().and_then(|time| time.duration_since(UNIX_EPOCH).ok()).map(|duration| duration.as_secs());
+        Self { size: Some(meta_data.len()), mtime }
+    }
+
+    /// Check whether the `actual` metadata matches *this* (expected) metadata, fields that are not available are ignored
+    pub fn matches(&self, actual: &Self) -> bool {
+        fn field_matches(expected: Option<u64>, actual: Option<u64>) -> bool {
+            expected.is_none_or(|value| actual == Some(value))
+        }
+        field_matches(self.size, actual.size) && field_matches(self.mtime, actual.mtime)
+    }
+}
+
+/// Wrapper that formats an optional metadata field, using `-` if the field is not available
+pub struct MetaField(pub Option<u64>);
+
+impl Display for MetaField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0 {
+            Some(value) => write!(f, "{}", value),
+            None => f.write_char('-'),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// File name escaping
+// ---------------------------------------------------------------------------
+
+/// Check whether the given file name contains any characters that need to be escaped in `--quote` mode
+pub fn needs_escape(name: &[u8]) -> bool {
+    name.iter().any(|value| matches!(value, b'\\' | b'\n' | b'\r'))
+}
+
+/// Escape the given file name, in the style of GNU coreutils, i.e., backslash, newline and carriage return characters are replaced by `\\`, `\n` and `\r`
+pub fn escape_name(name: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(name.len().saturating_add(8usize));
+    for value in name.iter().copied() {
+        match value {
+            b'\\' => escaped.extend_from_slice(b"\\\\"),
+            b'\n' => escaped.extend_from_slice(b"\\n"),
+            b'\r' => escaped.extend_from_slice(b"\\r"),
+            _ => escaped.push(value),
+        }
+    }
+    escaped
+}
+
+/// Reverse the escaping that was applied by [`escape_name()`], returns `None` if an invalid escape sequence is encountered
+pub fn unescape_name(name: &[u8]) -> Option<Vec<u8>> {
+    let mut unescaped = Vec::with_capacity(name.len());
+    let mut iter = name.iter().copied();
+    while let Some(value) = iter.next() {
+        unescaped.push(match value {
+            b'\\' => match iter.next()? {
+                b'\\' => b'\\',
+                b'n' => b'\n',
+                b'r' => b'\r',
+                _ => return None,
+            },
+            _ => value,
+        });
+    }
+    Some(unescaped)
+}
+
 // ---------------------------------------------------------------------------
 // Utility functions
 // ---------------------------------------------------------------------------
@@ -140,6 +270,30 @@ pub fn increment<T: SaturatingAdd + From<u8>>(counter: &mut T) {
     *counter = counter.saturating_add(&T::from(1u8));
 }
 
+/// Convert the given "raw" number of bytes to the proper binary units
+pub fn format_bytes(mut value: f64) -> (f64, &'static str) {
+    const BIN_UNITS: [&str; 5usize] = ["Byte", "KiB", "MiB", "GiB", "TiB"];
+    const MAX_INDEX: usize = BIN_UNITS.len() - 1usize;
+
+    let mut index = 0usize;
+    while (index < MAX_INDEX) && (value + f64::EPSILON > 999.9) {
+        value /= 1024.0;
+        index += 1usize;
+    }
+
+    (value, BIN_UNITS[index])
+}
+
+/// Handle a failure to write to the 'stdout' stream, a "broken pipe" is treated as a clean early termination
+pub fn write_failed(output: &mut OutStream, error_kind: IoErrorKind, args: &Args) -> ExitStatus {
+    if error_kind == IoErrorKind::BrokenPipe {
+        ExitStatus::Success
+    } else {
+        print_error!(output, args, "Error: Failed to write to standard output stream!");
+        ExitStatus::Failure
+    }
+}
+
 /// Compute the thread-count-specific capacity for a bounded channel
 #[inline]
 pub fn get_capacity(thread_count: &NonZeroUsize) -> usize {
@@ -155,6 +309,21 @@ pub fn get_capacity(thread_count: &NonZeroUsize) -> usize {
 // Helper macros
 // ---------------------------------------------------------------------------
 
+/// Conditional printing of informational message
+#[macro_export]
+#[doc(hidden)]
+macro_rules! print_info {
+    ($out:ident, $args:ident, $fmt:literal $(,$arg:expr)*$(,)?) => {
+        if !$args.quiet {
+            if !$args.no_color {
+                let _ = writeln!($out.err(), concat!("\x1b[1;36m[sponge256sum]\x1b[22;36m ", $fmt, "\x1b[0m") $(, $arg)*);
+            } else {
+                let _ = writeln!($out.err(), concat!("[sponge256sum] ", $fmt) $(, $arg)*);
+            }
+        }
+    };
+}
+
 /// Conditional printing of warning message
 #[macro_export]
 #[doc(hidden)]
@@ -193,6 +362,33 @@ macro_rules! print_error {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_file_meta() {
+        let expected = FileMeta { size: Some(42u64), mtime: None };
+        assert!(expected.matches(&FileMeta { size: Some(42u64), mtime: Some(1234u64) }));
+        assert!(!expected.matches(&FileMeta { size: Some(43u64), mtime: Some(1234u64) }));
+        assert!(!expected.matches(&FileMeta { size: None, mtime: None }));
+        assert_eq!(MetaField(Some(42u64)).to_string(), "42");
+        assert_eq!(MetaField(None).to_string(), "-");
+    }
+
+    #[test]
+    fn test_escape_name() {
+        assert!(!needs_escape(b"foo/bar.txt"));
+        assert!(needs_escape(b"foo\\bar.txt"));
+        assert_eq!(escape_name(b"a\nb\\c\rd"), b"a\\nb\\\\c\\rd");
+        assert_eq!(unescape_name(b"a\\nb\\\\c\\rd").unwrap(), b"a\nb\\c\rd");
+        assert!(unescape_name(b"a\\tb").is_none());
+        assert!(unescape_name(b"ab\\").is_none());
+    }
+
+    #[test]
+    fn test_json_str() {
+        assert_eq!(JsonStr("foo/bar.txt").to_string(), "foo/bar.txt");
+        assert_eq!(JsonStr("\"a\\b\"").to_string(), "\\\"a\\\\b\\\"");
+        assert_eq!(JsonStr("a\nb\tc\u{1}").to_string(), "a\\nb\\tc\\u0001");
+    }
+
     #[test]
     fn test_flag_1() {
         let flag = Flag::default();
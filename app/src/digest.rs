@@ -2,36 +2,44 @@
 // sponge256sum
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
-use sponge_hash_aes256::SpongeHash256;
+use hex::encode as encode_hex;
+use sponge_hash_aes256::{DynSpongeHash256, SpongeHash256Builder, SpongeTree256, DEFAULT_DIGEST_SIZE, DEFAULT_PERMUTE_ROUNDS, TREE_LEAF_SIZE};
 use std::{
-    io::{BufRead, BufReader, Error as IoError, Read},
-    mem::MaybeUninit,
+    cell::Cell,
+    hint::black_box,
+    io::{stderr, Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult, Write},
+    mem::{self, MaybeUninit},
+    num::{NonZeroU64, NonZeroUsize},
     ops::{Deref, DerefMut},
+    thread,
+    time::{Duration, Instant},
 };
+use zeroize::Zeroize;
 
 use crate::{
-    arguments::Args,
-    common::{Flag, MAX_SNAIL_LEVEL},
+    arguments::{Args, HexBytes},
+    common::{format_bytes, Flag, MAX_SNAIL_LEVEL},
     io::DataSource,
     os::{is_pipe, IO_READ_BUFFER_SIZE},
 };
 
-// ---------------------------------------------------------------------------
-// Platform support
-// ---------------------------------------------------------------------------
-
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
 
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     IoError,
+    TooLarge,
     Cancelled,
 }
 
 impl From<IoError> for Error {
-    fn from(_io_error: IoError) -> Self {
-        Self::IoError
+    fn from(io_error: IoError) -> Self {
+        match io_error.kind() {
+            IoErrorKind::FileTooLarge => Self::TooLarge,
+            _ => Self::IoError,
+        }
     }
 }
 
@@ -56,14 +64,33 @@ impl<const CAPACITY: usize> AlignedBuffer<CAPACITY> {
 enum ReadBuffer {
     Small(AlignedBuffer<IO_READ_BUFFER_SIZE>),
     Large(AlignedBuffer<{ 4usize * IO_READ_BUFFER_SIZE }>),
+    Custom(Vec<u8>),
+}
+
+thread_local! {
+    /// The custom-sized buffer of the current thread, which is retained, so that its allocation can be reused for the next input
+    static CUSTOM_BUFFER: Cell<Vec<u8>> = const { Cell::new(Vec::new()) };
 }
 
 impl ReadBuffer {
-    const fn new(large: bool) -> Self {
-        if large {
-            Self::Large(AlignedBuffer::uninit())
-        } else {
-            Self::Small(AlignedBuffer::uninit())
+    fn new(buffer_size: Option<NonZeroUsize>, large: bool) -> Self {
+        match buffer_size {
+            Some(size_kib) => {
+                let mut buffer = CUSTOM_BUFFER.take();
+                buffer.clear();
+                buffer.resize(size_kib.get().saturating_mul(1024usize), 0u8);
+                Self::Custom(buffer)
+            }
+            None if large => Self::Large(AlignedBuffer::uninit()),
+            None => Self::Small(AlignedBuffer::uninit()),
+        }
+    }
+}
+
+impl Drop for ReadBuffer {
+    fn drop(&mut self) {
+        if let ReadBuffer::Custom(buffer) = self {
+            CUSTOM_BUFFER.set(mem::take(buffer));
         }
     }
 }
@@ -76,6 +103,7 @@ impl Deref for ReadBuffer {
         match self {
             ReadBuffer::Small(buffer) => &buffer.0,
             ReadBuffer::Large(buffer) => &buffer.0,
+            ReadBuffer::Custom(buffer) => buffer,
         }
     }
 }
@@ -86,10 +114,139 @@ impl DerefMut for ReadBuffer {
         match self {
             ReadBuffer::Small(buffer) => &mut buffer.0,
             ReadBuffer::Large(buffer) => &mut buffer.0,
+            ReadBuffer::Custom(buffer) => buffer,
+        }
+    }
+}
+
+impl Zeroize for ReadBuffer {
+    fn zeroize(&mut self) {
+        self.deref_mut().zeroize();
+    }
+}
+
+/// Wrapper that wipes (zeroizes) the contained buffer when it goes out of scope, if `enabled` is set
+struct WipeOnDrop<T: Zeroize> {
+    buffer: T,
+    enabled: bool,
+}
+
+impl<T: Zeroize> WipeOnDrop<T> {
+    #[inline(always)]
+    fn new(buffer: T, args: &Args) -> Self {
+        Self { buffer, enabled: args.wipe }
+    }
+}
+
+impl<T: Zeroize> Deref for WipeOnDrop<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T: Zeroize> DerefMut for WipeOnDrop<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl<T: Zeroize> Drop for WipeOnDrop<T> {
+    fn drop(&mut self) {
+        if self.enabled {
+            self.buffer.zeroize();
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Progress indicator
+// ---------------------------------------------------------------------------
+
+/// The number of bytes after which the timer is checked again
+const PROGRESS_STEP: u64 = 1024u64 * 1024u64;
+
+/// The minimum interval between two consecutive progress updates
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1u64);
+
+/// Tracks the number of bytes processed and prints a throttled progress message to 'stderr'
+struct Progress {
+    start_time: Instant,
+    last_update: Instant,
+    next_check: u64,
+}
+
+impl Progress {
+    fn new(args: &Args) -> Option<Self> {
+        (args.progress && !args.quiet).then(|| {
+            let now = Instant::now();
+            Self { start_time: now, last_update: now, next_check: PROGRESS_STEP }
+        })
+    }
+
+    #[inline(always)]
+    fn update(&mut self, total_bytes: u64) {
+        if total_bytes >= self.next_check {
+            self.next_check = total_bytes.saturating_add(PROGRESS_STEP);
+            let now = Instant::now();
+            if now.saturating_duration_since(self.last_update) >= PROGRESS_INTERVAL {
+                self.last_update = now;
+                self.print(now, total_bytes);
+            }
+        }
+    }
+
+    #[cold]
+    fn print(&self, now: Instant, total_bytes: u64) {
+        let elapsed = now.saturating_duration_since(self.start_time).as_secs_f64();
+        let (total_value, total_unit) = format_bytes(total_bytes as f64);
+        let (rate_value, rate_unit) = format_bytes(total_bytes as f64 / elapsed.max(f64::EPSILON));
+        let _ = writeln!(stderr(), "[sponge256sum] Progress: {:.1} {} processed ({:.1} {}/s)", total_value, total_unit, rate_value, rate_unit);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Size limit
+// ---------------------------------------------------------------------------
+
+/// Wrapper that fails with `FileTooLarge`, once more than the permitted number of bytes have been read
+struct LimitedSource<'a, 'b> {
+    inner: &'a mut DataSource<'b>,
+    remaining: u64,
+    total_bytes: u64,
+    progress: Option<Progress>,
+}
+
+impl<'a, 'b> LimitedSource<'a, 'b> {
+    #[inline(always)]
+    fn new(inner: &'a mut DataSource<'b>, args: &Args) -> Self {
+        Self { inner, remaining: args.max_bytes.map_or(u64::MAX, NonZeroU64::get), total_bytes: u64::MIN, progress: Progress::new(args) }
+    }
+
+    /// Account for the given number of bytes that have been consumed from the underlying source
+    #[inline(always)]
+    fn consume(&mut self, length: usize) -> IoResult<()> {
+        self.remaining = self.remaining.checked_sub(length as u64).ok_or(IoErrorKind::FileTooLarge)?;
+        self.total_bytes = self.total_bytes.saturating_add(length as u64);
+        if let Some(progress) = self.progress.as_mut() {
+            progress.update(self.total_bytes);
+        }
+        Ok(())
+    }
+}
+
+impl Read for LimitedSource<'_, '_> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let length = self.inner.read(buf)?;
+        self.consume(length)?;
+        Ok(length)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SpongeHash256 wrapper
 // ---------------------------------------------------------------------------
@@ -99,57 +256,183 @@ const SNAIL_ITERATIONS_2: usize = 251usize;
 const SNAIL_ITERATIONS_3: usize = 4093usize;
 const SNAIL_ITERATIONS_4: usize = 65521usize;
 
-enum Hasher {
-    Default(SpongeHash256),
-    SnailV1(SpongeHash256<SNAIL_ITERATIONS_1>),
-    SnailV2(SpongeHash256<SNAIL_ITERATIONS_2>),
-    SnailV3(SpongeHash256<SNAIL_ITERATIONS_3>),
-    SnailV4(SpongeHash256<SNAIL_ITERATIONS_4>),
+/// Number of permutation rounds for each "snail" level
+const SNAIL_ROUNDS: [usize; MAX_SNAIL_LEVEL as usize + 1usize] =
+    [DEFAULT_PERMUTE_ROUNDS, SNAIL_ITERATIONS_1, SNAIL_ITERATIONS_2, SNAIL_ITERATIONS_3, SNAIL_ITERATIONS_4];
+
+/// Maximum number of permutation rounds that is accepted from a checksum file, or selected by the calibration
+pub const MAX_ROUNDS: usize = SNAIL_ITERATIONS_4;
+
+/// Returns the number of permutation rounds for the given "snail" level
+#[inline(always)]
+pub fn snail_rounds(snail_level: u8) -> NonZeroUsize {
+    debug_assert!(snail_level <= MAX_SNAIL_LEVEL);
+    NonZeroUsize::new(SNAIL_ROUNDS[snail_level as usize]).unwrap()
 }
 
+struct Hasher(DynSpongeHash256);
+
 impl Hasher {
     #[inline(always)]
-    pub fn new(info: &Option<String>, snail_level: u8) -> Self {
-        debug_assert!(snail_level <= MAX_SNAIL_LEVEL);
-        match info {
-            Some(info) => match snail_level {
-                0u8 => Self::Default(SpongeHash256::with_info(info)),
-                1u8 => Self::SnailV1(SpongeHash256::with_info(info)),
-                2u8 => Self::SnailV2(SpongeHash256::with_info(info)),
-                3u8 => Self::SnailV3(SpongeHash256::with_info(info)),
-                4u8 => Self::SnailV4(SpongeHash256::with_info(info)),
-                _ => unreachable!(),
-            },
-            None => match snail_level {
-                0u8 => Self::Default(SpongeHash256::new()),
-                1u8 => Self::SnailV1(SpongeHash256::new()),
-                2u8 => Self::SnailV2(SpongeHash256::new()),
-                3u8 => Self::SnailV3(SpongeHash256::new()),
-                4u8 => Self::SnailV4(SpongeHash256::new()),
-                _ => unreachable!(),
-            },
+    pub fn new(info: Option<&[u8]>, salt: &Option<HexBytes>, rounds: NonZeroUsize) -> Self {
+        let builder = SpongeHash256Builder::new().rounds(rounds);
+        let builder = match info {
+            Some(info) => builder.info_bytes(info),
+            None => builder,
+        };
+        match salt {
+            Some(salt) => Self(builder.salt(salt.as_bytes()).build()),
+            None => Self(builder.build()),
         }
     }
 
     #[inline(always)]
     pub fn update<T: AsRef<[u8]>>(&mut self, input: T) {
-        match self {
-            Hasher::Default(hasher_instance) => hasher_instance.update(input),
-            Hasher::SnailV1(hasher_instance) => hasher_instance.update(input),
-            Hasher::SnailV2(hasher_instance) => hasher_instance.update(input),
-            Hasher::SnailV3(hasher_instance) => hasher_instance.update(input),
-            Hasher::SnailV4(hasher_instance) => hasher_instance.update(input),
-        }
+        self.0.update(input)
+    }
+
+    #[inline(always)]
+    pub fn digest_iter(self) -> Box<dyn Iterator<Item = u8>> {
+        Box::new(self.0.digest_iter())
     }
 
     #[inline(always)]
     pub fn digest_to_slice(self, output: &mut [u8]) {
-        match self {
-            Hasher::Default(hasher) => hasher.digest_to_slice(output),
-            Hasher::SnailV1(hasher) => hasher.digest_to_slice(output),
-            Hasher::SnailV2(hasher) => hasher.digest_to_slice(output),
-            Hasher::SnailV3(hasher) => hasher.digest_to_slice(output),
-            Hasher::SnailV4(hasher) => hasher.digest_to_slice(output),
+        self.0.digest_to_slice(output)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rounds calibration
+// ---------------------------------------------------------------------------
+
+/// Number of measurements per probed number of rounds (the fastest one is used)
+const CALIBRATION_ITERATIONS: usize = 3usize;
+
+/// Minimum time per measurement, so that the resolution of the timer becomes negligible
+const CALIBRATION_MIN_TIME: Duration = Duration::from_millis(10u64);
+
+/// Fixed message that is hashed during the calibration
+const CALIBRATION_DATA: [u8; 64usize] = [0x5Au8; 64usize];
+
+/// Determine the number of permutation rounds for which the time per (short) hash is about the given target duration
+///
+/// The number of rounds is doubled, until a single hash takes at least `CALIBRATION_MIN_TIME`. From that measurement, the rounds per unit of time are
+/// estimated and the number of rounds is scaled to the target duration. The result is limited to `MAX_ROUNDS`, so that it is accepted by `--check`.
+pub fn calibrate_rounds(target: Duration) -> NonZeroUsize {
+    let max_rounds = NonZeroUsize::new(MAX_ROUNDS).unwrap();
+    let mut probe_rounds = NonZeroUsize::MIN;
+
+    let elapsed = loop {
+        let elapsed = (0usize..CALIBRATION_ITERATIONS).map(|_| measure_hash_time(probe_rounds)).min().unwrap_or_default();
+        if (elapsed >= CALIBRATION_MIN_TIME) || (probe_rounds >= max_rounds) {
+            break elapsed;
+        }
+        probe_rounds = probe_rounds.saturating_mul(NonZeroUsize::new(2usize).unwrap()).min(max_rounds);
+    };
+
+    let rounds = (probe_rounds.get() as u128).saturating_mul(target.as_nanos()) / elapsed.as_nanos().max(1u128);
+    NonZeroUsize::new(usize::try_from(rounds).unwrap_or(MAX_ROUNDS).clamp(1usize, MAX_ROUNDS)).unwrap()
+}
+
+/// Measure the time that is required to hash the calibration data with the given number of rounds
+#[inline(never)]
+fn measure_hash_time(rounds: NonZeroUsize) -> Duration {
+    let mut digest = [0u8; DEFAULT_DIGEST_SIZE];
+    let start_time = Instant::now();
+    let mut hasher = Hasher::new(None, &None, rounds);
+    hasher.update(black_box(&CALIBRATION_DATA));
+    hasher.digest_to_slice(&mut digest);
+    black_box(digest);
+    start_time.elapsed()
+}
+
+// ---------------------------------------------------------------------------
+// Text mode
+// ---------------------------------------------------------------------------
+
+/// Byte-level state machine that normalizes line breaks, independent of how the input is split into chunks
+///
+/// Every `\r\n` sequence is collapsed to a single `\n` character, and a lone `\r` character is either preserved or,
+/// if `cr_newline` is set, treated as a line break too. The *final* line break of the input is dropped.
+struct TextNormalizer {
+    cr_newline: bool,
+    pending_cr: bool,
+    pending_lf: bool,
+}
+
+impl TextNormalizer {
+    const fn new(cr_newline: bool) -> Self {
+        Self { cr_newline, pending_cr: false, pending_lf: false }
+    }
+
+    /// Process the next chunk of input data
+    fn update(&mut self, hasher: &mut Hasher, mut chunk: &[u8]) {
+        loop {
+            if self.pending_cr {
+                match chunk.split_first() {
+                    Some((b'\n', remainder)) => {
+                        chunk = remainder;
+                        self.line_break(hasher);
+                    }
+                    Some(_) => self.lone_cr(hasher),
+                    None => return,
+                }
+                self.pending_cr = false;
+            }
+
+            let (text, remainder) = match chunk.iter().position(|value| matches!(value, b'\r' | b'\n')) {
+                Some(position) => chunk.split_at(position),
+                None => (chunk, &[][..]),
+            };
+
+            if !text.is_empty() {
+                self.flush_lf(hasher);
+                hasher.update(text);
+            }
+
+            match remainder.split_first() {
+                Some((b'\r', remainder)) => {
+                    self.pending_cr = true;
+                    chunk = remainder;
+                }
+                Some((_, remainder)) => {
+                    self.line_break(hasher);
+                    chunk = remainder;
+                }
+                None => return,
+            }
+        }
+    }
+
+    /// Complete the processing, a *pending* line break at the end of the input is dropped
+    fn finish(mut self, hasher: &mut Hasher) {
+        if self.pending_cr {
+            self.lone_cr(hasher);
+        }
+    }
+
+    #[inline(always)]
+    fn lone_cr(&mut self, hasher: &mut Hasher) {
+        if self.cr_newline {
+            self.line_break(hasher);
+        } else {
+            self.flush_lf(hasher);
+            hasher.update(b"\r");
+        }
+    }
+
+    #[inline(always)]
+    fn line_break(&mut self, hasher: &mut Hasher) {
+        self.flush_lf(hasher);
+        self.pending_lf = true;
+    }
+
+    #[inline(always)]
+    fn flush_lf(&mut self, hasher: &mut Hasher) {
+        if self.pending_lf {
+            hasher.update(b"\n");
+            self.pending_lf = false;
         }
     }
 }
@@ -158,6 +441,12 @@ impl Hasher {
 // Compute digest
 // ---------------------------------------------------------------------------
 
+/// The size of the chunks in which a memory-mapped file is passed to the hasher
+const MMAP_CHUNK_SIZE: usize = 4usize * IO_READ_BUFFER_SIZE;
+
+/// The size of the blocks in which the squeezed output is compared to the expected digest
+const COMPARE_BLOCK_SIZE: usize = 16usize;
+
 /// Check if the computation has been aborted
 macro_rules! check_cancelled {
     ($halt:ident) => {
@@ -167,34 +456,184 @@ macro_rules! check_cancelled {
     };
 }
 
-/// Process a single input file
-pub fn compute_digest(input: &mut DataSource, digest_out: &mut [u8], args: &Args, halt: &Flag) -> Result<(), Error> {
-    static LINE_BREAK: &str = "\n";
-    let mut hasher = Hasher::new(&args.info, args.snail);
+/// Process a single input file, using the given number of permutation rounds, returns the total number of bytes that have been read
+pub fn compute_digest(input: &mut DataSource, digest_out: &mut [u8], rounds: NonZeroUsize, args: &Args, halt: &Flag) -> Result<u64, Error> {
+    let (hasher, total_bytes) = absorb_input(input, rounds, args, halt)?;
+    hasher.digest_to_slice(digest_out);
+    Ok(total_bytes)
+}
+
+/// Process a single input file and compare the digest to the expected value, block by block, as it is being squeezed
+///
+/// The comparison stops at the first mismatching block, so that no further output blocks need to be squeezed.
+pub fn compare_digest(input: &mut DataSource, digest_expected: &[u8], rounds: NonZeroUsize, args: &Args, halt: &Flag) -> Result<(bool, u64), Error> {
+    let (hasher, total_bytes) = absorb_input(input, rounds, args, halt)?;
+    Ok((compare_squeezed(hasher.digest_iter(), digest_expected), total_bytes))
+}
 
-    if !args.text {
-        let mut buffer = ReadBuffer::new(is_pipe(input));
-        loop {
+/// Compare the squeezed output to the expected digest, block by block, *without* buffering the complete digest
+///
+/// Each block is compared in constant time, but the comparison stops at the first mismatching block. Hence, the timing reveals the index of the first mismatching block, but *not* the position of the mismatch within that block.
+fn compare_squeezed(mut squeezed: impl Iterator<Item = u8>, digest_expected: &[u8]) -> bool {
+    let mut block = [0u8; COMPARE_BLOCK_SIZE];
+    for chunk_expected in digest_expected.chunks(COMPARE_BLOCK_SIZE) {
+        let block_computed = &mut block[..chunk_expected.len()];
+        block_computed.iter_mut().for_each(|value| *value = squeezed.next().unwrap());
+        if !digest_equal(block_computed, chunk_expected) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Compute the combined digest of a directory tree from the given (relative path, digest) pairs, which must be sorted by path
+///
+/// Each path is absorbed prefixed by its length, as a 64-bit big-endian value, followed by the digest of the file.
+pub fn compute_listing_digest<'a>(entries: impl Iterator<Item = (&'a [u8], &'a [u8])>, digest_out: &mut [u8], args: &Args) {
+    let mut hasher = Hasher::new(args.info_bytes(), &args.salt, args.rounds());
+    for (path, digest) in entries {
+        hasher.update(u64::to_be_bytes(path.len() as u64));
+        hasher.update(path);
+        hasher.update(digest);
+    }
+    hasher.digest_to_slice(digest_out);
+}
+
+/// Compute the digest of a single record that is held in memory, e.g., as read by '--block-stdin'
+pub fn compute_record_digest(record: &[u8], digest_out: &mut [u8], args: &Args) {
+    let mut hasher = Hasher::new(args.info_bytes(), &args.salt, args.rounds());
+    hasher.update(record);
+    hasher.digest_to_slice(digest_out);
+}
+
+/// Describe all parameters that affect the computed digests, i.e., the number of rounds, the input mode and the context
+///
+/// The context is identified by a short fingerprint of the `info` string and the salt, so that these are not disclosed.
+pub fn describe_parameters(args: &Args) -> String {
+    let mode = match (args.tree, args.text, args.cr_newline) {
+        (true, _, _) => "tree",
+        (false, true, true) => "text-cr",
+        (false, true, false) => "text",
+        (false, false, _) => "binary",
+    };
+
+    let context = if args.info_bytes().is_some() || args.salt.is_some() {
+        let mut fingerprint = [0u8; 8usize];
+        Hasher::new(args.info_bytes(), &args.salt, snail_rounds(0u8)).digest_to_slice(&mut fingerprint);
+        encode_hex(fingerprint)
+    } else {
+        String::from("none")
+    };
+
+    format!("rounds={} mode={} context={}", args.rounds(), mode, context)
+}
+
+/// Absorb all data from the input file into a new hasher instance, returns the hasher and the total number of bytes
+fn absorb_input(input: &mut DataSource, rounds: NonZeroUsize, args: &Args, halt: &Flag) -> Result<(Hasher, u64), Error> {
+    let mut hasher = Hasher::new(args.info_bytes(), &args.salt, rounds);
+    let large_buffer = is_pipe(input);
+    let mapped = if !args.text { input.map() } else { None };
+    let mut input = LimitedSource::new(input, args);
+
+    if let Some(mapped) = mapped {
+        for chunk in mapped.chunks(MMAP_CHUNK_SIZE) {
             check_cancelled!(halt);
-            match input.read(&mut buffer)? {
-                0usize => break,
-                length => hasher.update(&buffer[..length]),
-            }
+            input.consume(chunk.len())?;
+            hasher.update(chunk);
+        }
+    } else {
+        let mut buffer = WipeOnDrop::new(ReadBuffer::new(args.buffer_size, large_buffer), args);
+        let normalizer = args.text.then(|| TextNormalizer::new(args.cr_newline));
+        absorb_stream(&mut hasher, &mut input, &mut buffer, normalizer, halt)?;
+    }
+
+    Ok((hasher, input.total_bytes))
+}
+
+/// Absorb all data from the given reader into the hasher, using the given buffer
+///
+/// A read that was interrupted by a signal (`EINTR`) is simply retried, rather than being treated as a read error.
+fn absorb_stream<T: Read>(hasher: &mut Hasher, input: &mut T, buffer: &mut [u8], mut normalizer: Option<TextNormalizer>, halt: &Flag) -> Result<(), Error> {
+    loop {
+        check_cancelled!(halt);
+        match input.read(buffer) {
+            Ok(0usize) => break,
+            Ok(length) => match normalizer.as_mut() {
+                Some(normalizer) => normalizer.update(hasher, &buffer[..length]),
+                None => hasher.update(&buffer[..length]),
+            },
+            Err(error) if error.kind() == IoErrorKind::Interrupted => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    if let Some(normalizer) = normalizer {
+        normalizer.finish(hasher);
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tree hashing
+// ---------------------------------------------------------------------------
+
+/// The number of leaves per batch, for each thread
+const TREE_BATCH_FACTOR: usize = 4usize;
+
+/// Compute the leaf digests for a batch of leaves, using up to `n_threads` parallel threads
+fn leaf_digests(leaves: &[&[u8]], n_threads: NonZeroUsize) -> Vec<[u8; DEFAULT_DIGEST_SIZE]> {
+    if (n_threads == NonZeroUsize::MIN) || (leaves.len() < 2usize) {
+        return leaves.iter().map(|leaf| SpongeTree256::leaf_digest(leaf)).collect();
+    }
+
+    let chunk_size = leaves.len().div_ceil(n_threads.get());
+    thread::scope(|scope| {
+        let handles: Vec<_> =
+            leaves.chunks(chunk_size).map(|chunk| scope.spawn(move || chunk.iter().map(|leaf| SpongeTree256::leaf_digest(leaf)).collect::<Vec<_>>())).collect();
+        handles.into_iter().flat_map(|handle| handle.join().expect("Failed to join the worker thread!")).collect()
+    })
+}
+
+/// Process a single input file in tree hashing mode, returns the total number of bytes that have been read
+pub fn compute_tree_digest(input: &mut DataSource, digest_out: &mut [u8], n_threads: NonZeroUsize, args: &Args, halt: &Flag) -> Result<u64, Error> {
+    let batch_size = n_threads.get().saturating_mul(TREE_BATCH_FACTOR);
+    let mapped = input.map();
+    let mut input = LimitedSource::new(input, args);
+    let mut tree = SpongeTree256::new();
+
+    if let Some(mapped) = mapped {
+        let leaves: Vec<&[u8]> = mapped.chunks(TREE_LEAF_SIZE).collect();
+        for batch in leaves.chunks(batch_size) {
+            check_cancelled!(halt);
+            input.consume(batch.iter().map(|leaf| leaf.len()).sum())?;
+            leaf_digests(batch, n_threads).iter().for_each(|leaf_digest| tree.update(leaf_digest));
         }
     } else {
-        let mut lines = BufReader::with_capacity(IO_READ_BUFFER_SIZE, input).lines();
-        if let Some(line) = lines.next() {
-            hasher.update(&(line?));
-            for line in lines {
+        let mut buffers: WipeOnDrop<Vec<Vec<u8>>> = WipeOnDrop::new(Vec::with_capacity(batch_size), args);
+        let mut end_of_file = false;
+        while !end_of_file {
+            let mut count = 0usize;
+            while (count < batch_size) && (!end_of_file) {
                 check_cancelled!(halt);
-                hasher.update(LINE_BREAK);
-                hasher.update(&(line?));
+                if buffers.len() <= count {
+                    buffers.push(Vec::with_capacity(TREE_LEAF_SIZE));
+                }
+                let buffer = &mut buffers[count];
+                buffer.clear();
+                (&mut input).take(TREE_LEAF_SIZE as u64).read_to_end(buffer)?;
+                end_of_file = buffer.len() < TREE_LEAF_SIZE;
+                if !buffer.is_empty() {
+                    count += 1usize;
+                }
             }
+            let batch: Vec<&[u8]> = buffers[..count].iter().map(Vec::as_slice).collect();
+            leaf_digests(&batch, n_threads).iter().for_each(|leaf_digest| tree.update(leaf_digest));
         }
     }
 
-    hasher.digest_to_slice(digest_out);
-    Ok(())
+    tree.digest_to_slice(digest_out);
+    Ok(input.total_bytes)
 }
 
 // ---------------------------------------------------------------------------
@@ -210,3 +649,121 @@ pub fn digest_equal(digest0: &[u8], digest1: &[u8]) -> bool {
     }
     mask == 0u8
 }
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(chunks: &[&[u8]], cr_newline: bool) -> [u8; 32usize] {
+        let mut hasher = Hasher::new(None, &None, snail_rounds(0u8));
+        let mut normalizer = TextNormalizer::new(cr_newline);
+        chunks.iter().for_each(|chunk| normalizer.update(&mut hasher, chunk));
+        normalizer.finish(&mut hasher);
+        let mut digest = [0u8; 32usize];
+        hasher.digest_to_slice(&mut digest);
+        digest
+    }
+
+    fn reference(data: &[u8]) -> [u8; 32usize] {
+        let mut hasher = Hasher::new(None, &None, snail_rounds(0u8));
+        hasher.update(data);
+        let mut digest = [0u8; 32usize];
+        hasher.digest_to_slice(&mut digest);
+        digest
+    }
+
+    /// Reader that fails with the given error on every other call
+    struct FlakyReader<'a> {
+        data: &'a [u8],
+        error_kind: IoErrorKind,
+        count: usize,
+    }
+
+    impl Read for FlakyReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+            self.count += 1usize;
+            if self.count % 2usize == 1usize {
+                return Err(self.error_kind.into());
+            }
+            let length = self.data.len().min(buf.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+            Ok(length)
+        }
+    }
+
+    fn absorb(data: &[u8], error_kind: IoErrorKind) -> Result<[u8; 32usize], Error> {
+        let mut hasher = Hasher::new(None, &None, snail_rounds(0u8));
+        let mut reader = FlakyReader { data, error_kind, count: 0usize };
+        absorb_stream(&mut hasher, &mut reader, &mut [0u8; 5usize], None, &Flag::default())?;
+        let mut digest = [0u8; 32usize];
+        hasher.digest_to_slice(&mut digest);
+        Ok(digest)
+    }
+
+    #[test]
+    fn test_compare_squeezed() {
+        let digest: Vec<u8> = (0u8..40u8).collect();
+        for length in [1usize, 16usize, 17usize, 40usize] {
+            assert!(compare_squeezed(digest.iter().copied(), &digest[..length]));
+        }
+
+        let mut corrupted = digest.clone();
+        corrupted[20usize] ^= 0x01u8;
+        assert!(compare_squeezed(digest.iter().copied(), &corrupted[..20usize]));
+        assert!(!compare_squeezed(digest.iter().copied(), &corrupted[..21usize]));
+        assert!(!compare_squeezed(digest.iter().copied(), &corrupted));
+
+        let mut consumed = 0usize;
+        assert!(!compare_squeezed(digest.iter().copied().inspect(|_| consumed += 1usize), &corrupted));
+        assert_eq!(consumed, 2usize * COMPARE_BLOCK_SIZE);
+    }
+
+    #[test]
+    fn test_read_buffer_reuse() {
+        let size_kib = NonZeroUsize::new(4usize).unwrap();
+        let address = {
+            let mut buffer = ReadBuffer::new(Some(size_kib), false);
+            buffer.fill(0xA5u8);
+            buffer.as_ptr()
+        };
+        let buffer = ReadBuffer::new(Some(size_kib), false);
+        assert_eq!(buffer.as_ptr(), address);
+        assert_eq!(buffer.len(), 4096usize);
+        assert!(buffer.iter().all(|value| *value == 0u8));
+        drop(buffer);
+        assert_eq!(ReadBuffer::new(Some(NonZeroUsize::MIN), false).len(), 1024usize);
+    }
+
+    #[test]
+    fn test_absorb_interrupted() {
+        const INPUT: &[u8] = b"The quick brown fox jumps over the lazy dog";
+        assert!(absorb(INPUT, IoErrorKind::Interrupted).is_ok_and(|digest| digest == reference(INPUT)));
+        assert!(matches!(absorb(INPUT, IoErrorKind::Other), Err(Error::IoError)));
+    }
+
+    #[test]
+    fn test_text_normalizer() {
+        const INPUT: &[u8] = b"foo\r\nbar\rbaz\n\r\n\r\rqux\r\n";
+        let byte_wise: Vec<&[u8]> = INPUT.chunks(1usize).collect();
+
+        for cr_newline in [false, true] {
+            let expected = normalize(&[INPUT], cr_newline);
+            assert_eq!(normalize(&byte_wise, cr_newline), expected);
+            for split in 0usize..=INPUT.len() {
+                let (chunk0, chunk1) = INPUT.split_at(split);
+                assert_eq!(normalize(&[chunk0, chunk1], cr_newline), expected);
+            }
+        }
+
+        assert_eq!(normalize(&[INPUT], false), reference(b"foo\nbar\rbaz\n\n\r\rqux"));
+        assert_eq!(normalize(&[INPUT], true), reference(b"foo\nbar\nbaz\n\n\n\nqux"));
+        assert_eq!(normalize(&[b"foo\r"], false), reference(b"foo\r"));
+        assert_eq!(normalize(&[b"foo\r"], true), reference(b"foo"));
+        assert_eq!(normalize(&[b"\n"], false), reference(b""));
+    }
+}
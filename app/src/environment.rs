@@ -19,22 +19,59 @@ impl InvalidValue {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Directory walking strategy
+// ---------------------------------------------------------------------------
+
+/// Search strategy to be used for walking the directory tree
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirWalk {
+    /// Select the strategy based on a quick probe of the directory tree
+    Auto,
+    /// Breadth-first search
+    Bfs,
+    /// Depth-first search
+    Dfs,
+}
+
+impl DirWalk {
+    const NAMES: [&str; 3usize] = ["AUTO", "BFS", "DFS"];
+    const VALUES: [Self; 3usize] = [Self::Auto, Self::Bfs, Self::Dfs];
+
+    /// Returns `true` for breadth-first search, or `false` for depth-first search
+    ///
+    /// The given `probe` function is invoked *only* in `Auto` mode, explicit strategies are always honored.
+    pub fn breadth_first(self, probe: impl FnOnce() -> bool) -> bool {
+        match self {
+            Self::Auto => probe(),
+            Self::Bfs => true,
+            Self::Dfs => false,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Environment
 // ---------------------------------------------------------------------------
 
 pub struct Env {
-    pub dirwalk_strategy: Option<bool>,
+    pub dirwalk_strategy: Option<DirWalk>,
+    pub dirwalk_queue_limit: Option<NonZeroUsize>,
     pub thread_count: Option<usize>,
+    pub parse_chunk_size: Option<NonZeroUsize>,
     pub sefltest_passes: Option<NonZeroUsize>,
+    pub sefltest_samples: Option<NonZeroUsize>,
 }
 
 impl Env {
     pub fn from_env() -> Result<Self, InvalidValue> {
         Ok(Self {
-            dirwalk_strategy: parse_enum("SPONGE256SUM_DIRWALK_STRATEGY", &["BFS", "DFS"])?.map(|index| index == 0usize),
+            dirwalk_strategy: parse_enum("SPONGE256SUM_DIRWALK_STRATEGY", &DirWalk::NAMES)?.map(|index| DirWalk::VALUES[index]),
+            dirwalk_queue_limit: parse_nonzero_usize("SPONGE256SUM_DIRWALK_QUEUE_LIMIT")?,
             thread_count: parse_usize("SPONGE256SUM_THREAD_COUNT")?,
+            parse_chunk_size: parse_nonzero_usize("SPONGE256SUM_PARSE_CHUNK_SIZE")?,
             sefltest_passes: parse_nonzero_usize("SPONGE256SUM_SELFTEST_PASSES")?,
+            sefltest_samples: parse_nonzero_usize("SPONGE256SUM_SELFTEST_SAMPLES")?,
         })
     }
 }
@@ -73,10 +110,41 @@ fn parse_nonzero_usize(name: &str) -> Result<Option<NonZeroUsize>, InvalidValue>
 #[inline]
 fn parse_enum(name: &str, options: &[&str]) -> Result<Option<usize>, InvalidValue> {
     match get_env(name) {
-        Some(value) => match options.iter().position(|str| value.eq_ignore_ascii_case(str)) {
+        Some(value) => match find_option(&value, options) {
             Some(index) => Ok(Some(index)),
             None => Err(InvalidValue::new(name, value)),
         },
         None => Ok(None),
     }
 }
+
+#[inline]
+fn find_option(value: &str, options: &[&str]) -> Option<usize> {
+    options.iter().position(|str| value.eq_ignore_ascii_case(str))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dirwalk_parse() {
+        assert_eq!(find_option("AUTO", &DirWalk::NAMES).map(|index| DirWalk::VALUES[index]), Some(DirWalk::Auto));
+        assert_eq!(find_option("auto", &DirWalk::NAMES).map(|index| DirWalk::VALUES[index]), Some(DirWalk::Auto));
+        assert_eq!(find_option("Bfs", &DirWalk::NAMES).map(|index| DirWalk::VALUES[index]), Some(DirWalk::Bfs));
+        assert_eq!(find_option("dfs", &DirWalk::NAMES).map(|index| DirWalk::VALUES[index]), Some(DirWalk::Dfs));
+        assert_eq!(find_option("foo", &DirWalk::NAMES), None);
+    }
+
+    #[test]
+    fn test_dirwalk_override() {
+        assert!(DirWalk::Auto.breadth_first(|| true));
+        assert!(!DirWalk::Auto.breadth_first(|| false));
+        assert!(DirWalk::Bfs.breadth_first(|| unreachable!()));
+        assert!(!DirWalk::Dfs.breadth_first(|| unreachable!()));
+    }
+}
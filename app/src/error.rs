@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: 0BSD
+// sponge256sum
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as StdIoError, ErrorKind as StdIoErrorKind},
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+};
+
+use crate::{common::MAX_DIGEST_SIZE, io::Error as IoError, os::FileId};
+
+// ---------------------------------------------------------------------------
+// Error Type
+// ---------------------------------------------------------------------------
+
+/// Error type for processing or verifying file tasks
+#[derive(Debug)]
+pub enum Error {
+    InputFile(ErrorKind),
+    ChkSumFile(ErrorKind),
+    TargetFile(ErrorKind),
+    WalkOpen(PathBuf),
+    WalkRead(PathBuf),
+    ListOpen(PathBuf),
+    ListRead(PathBuf),
+    Duplicate(PathBuf),
+    IsCycle(PathBuf, FileId),
+    Oversized(PathBuf, u64),
+    LineBreak(PathBuf),
+    LimitReached(NonZeroUsize),
+    Malformed(PathBuf, usize),
+    Incompatible(PathBuf, usize),
+    DigestTooLong(PathBuf, usize),
+    Anomalous(PathBuf, usize),
+    Xattr(XattrError, PathBuf),
+}
+
+/// Error category that describes a file error in greater detail
+#[derive(Debug)]
+pub enum ErrorKind {
+    FileOpen(PathBuf),
+    FileRead(PathBuf),
+    NotFound(PathBuf),
+    ObjIsDir(PathBuf),
+    ParseErr(PathBuf, usize),
+    TooLarge(PathBuf, NonZeroU64),
+    StdinUse(PathBuf),
+}
+
+/// Error category that describes an extended attribute error in greater detail
+#[derive(Debug)]
+pub enum XattrError {
+    NotFound,
+    Invalid,
+    ReadErr,
+    WriteErr,
+    Unsupported,
+}
+
+impl XattrError {
+    #[inline]
+    pub fn from_io_error(error: &StdIoError, default: Self) -> Self {
+        match error.kind() {
+            StdIoErrorKind::Unsupported => XattrError::Unsupported,
+            StdIoErrorKind::InvalidData => XattrError::Invalid,
+            _ => default,
+        }
+    }
+}
+
+impl ErrorKind {
+    #[inline]
+    pub fn from_io_error(error: IoError, path: PathBuf) -> Self {
+        match error {
+            IoError::AccessDenied => ErrorKind::FileOpen(path),
+            IoError::FileNotFound => ErrorKind::NotFound(path),
+            IoError::IsADirectory => ErrorKind::ObjIsDir(path),
+            IoError::StdinInUse => ErrorKind::StdinUse(path),
+        }
+    }
+
+    fn fmt_with(&self, f: &mut Formatter<'_>, name: &str) -> FmtResult {
+        match self {
+            ErrorKind::FileOpen(path) => write!(f, "Failed to open {} file: {:?}", name.to_ascii_lowercase(), path),
+            ErrorKind::FileRead(path) => write!(f, "Failed to read {} file: {:?}", name.to_ascii_lowercase(), path),
+            ErrorKind::NotFound(path) => write!(f, "{} file not found: {:?}", name, path),
+            ErrorKind::ObjIsDir(path) => write!(f, "{} file is a directory: {:?}", name, path),
+            ErrorKind::ParseErr(path, line) => write!(f, "Malformed {} file: {:?} [line #{}]", name.to_ascii_lowercase(), path, line),
+            ErrorKind::TooLarge(path, limit) => write!(f, "{} file exceeds the limit of {} bytes: {:?}", name, limit, path),
+            ErrorKind::StdinUse(path) => write!(f, "{} file refers to the standard input stream, which can only be read once: {:?}", name, path),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::InputFile(kind) => kind.fmt_with(f, "Input"),
+            Error::ChkSumFile(kind) => kind.fmt_with(f, "Checksum"),
+            Error::TargetFile(kind) => kind.fmt_with(f, "Target"),
+            Error::WalkOpen(path) => write!(f, "Failed to open directory: {:?}", path),
+            Error::WalkRead(path) => write!(f, "Failed to read directory: {:?}", path),
+            Error::ListOpen(path) => write!(f, "Failed to open file list: {:?}", path),
+            Error::ListRead(path) => write!(f, "Failed to read file list: {:?}", path),
+            Error::Duplicate(path) => write!(f, "Skipped duplicate input file: {:?}", path),
+            Error::IsCycle(path, file_id) => write!(f, "Skipped directory cycle: {:?} [already visited: {}]", path, file_id),
+            Error::Oversized(path, size) => write!(f, "Skipped oversized input file: {:?} [size: {} bytes]", path, size),
+            Error::LineBreak(path) => write!(f, "File name contains a line break, use '--quote' or '--null' to process it: {:?}", path),
+            Error::LimitReached(limit) => write!(f, "File limit reached, stopped the walk after {} file(s)", limit),
+            Error::Malformed(path, line) => write!(f, "Improperly formatted checksum line: {:?} [line #{}]", path, line),
+            Error::Incompatible(path, line) => write!(f, "Checksum file was generated with incompatible parameters: {:?} [line #{}]", path, line),
+            Error::Anomalous(path, line) => write!(f, "Non-canonical checksum line: {:?} [line #{}]", path, line),
+            Error::Xattr(XattrError::NotFound, path) => write!(f, "Checksum attribute not found: {:?}", path),
+            Error::Xattr(XattrError::Invalid, path) => write!(f, "Invalid checksum attribute: {:?}", path),
+            Error::Xattr(XattrError::ReadErr, path) => write!(f, "Failed to read checksum attribute: {:?}", path),
+            Error::Xattr(XattrError::WriteErr, path) => write!(f, "Failed to write checksum attribute: {:?}", path),
+            Error::Xattr(XattrError::Unsupported, path) => write!(f, "Extended attributes are not supported: {:?}", path),
+            Error::DigestTooLong(path, line) => {
+                write!(f, "Digest too long: {:?} [line #{}, maximum is {} bits]", path, line, MAX_DIGEST_SIZE * (u8::BITS as usize))
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path() -> PathBuf {
+        PathBuf::from("foo/bar.txt")
+    }
+
+    #[test]
+    fn test_display_input_file() {
+        let limit = NonZeroU64::new(42u64).unwrap();
+        assert_eq!(Error::InputFile(ErrorKind::FileOpen(path())).to_string(), "Failed to open input file: \"foo/bar.txt\"");
+        assert_eq!(Error::InputFile(ErrorKind::FileRead(path())).to_string(), "Failed to read input file: \"foo/bar.txt\"");
+        assert_eq!(Error::InputFile(ErrorKind::NotFound(path())).to_string(), "Input file not found: \"foo/bar.txt\"");
+        assert_eq!(Error::InputFile(ErrorKind::ObjIsDir(path())).to_string(), "Input file is a directory: \"foo/bar.txt\"");
+        assert_eq!(Error::InputFile(ErrorKind::TooLarge(path(), limit)).to_string(), "Input file exceeds the limit of 42 bytes: \"foo/bar.txt\"");
+        assert_eq!(
+            Error::InputFile(ErrorKind::StdinUse(path())).to_string(),
+            "Input file refers to the standard input stream, which can only be read once: \"foo/bar.txt\""
+        );
+    }
+
+    #[test]
+    fn test_display_chksum_file() {
+        assert_eq!(Error::ChkSumFile(ErrorKind::FileOpen(path())).to_string(), "Failed to open checksum file: \"foo/bar.txt\"");
+        assert_eq!(Error::ChkSumFile(ErrorKind::FileRead(path())).to_string(), "Failed to read checksum file: \"foo/bar.txt\"");
+        assert_eq!(Error::ChkSumFile(ErrorKind::NotFound(path())).to_string(), "Checksum file not found: \"foo/bar.txt\"");
+        assert_eq!(Error::ChkSumFile(ErrorKind::ObjIsDir(path())).to_string(), "Checksum file is a directory: \"foo/bar.txt\"");
+        assert_eq!(Error::ChkSumFile(ErrorKind::ParseErr(path(), 7usize)).to_string(), "Malformed checksum file: \"foo/bar.txt\" [line #7]");
+    }
+
+    #[test]
+    fn test_display_target_file() {
+        let limit = NonZeroU64::new(42u64).unwrap();
+        assert_eq!(Error::TargetFile(ErrorKind::FileOpen(path())).to_string(), "Failed to open target file: \"foo/bar.txt\"");
+        assert_eq!(Error::TargetFile(ErrorKind::FileRead(path())).to_string(), "Failed to read target file: \"foo/bar.txt\"");
+        assert_eq!(Error::TargetFile(ErrorKind::NotFound(path())).to_string(), "Target file not found: \"foo/bar.txt\"");
+        assert_eq!(Error::TargetFile(ErrorKind::ObjIsDir(path())).to_string(), "Target file is a directory: \"foo/bar.txt\"");
+        assert_eq!(Error::TargetFile(ErrorKind::TooLarge(path(), limit)).to_string(), "Target file exceeds the limit of 42 bytes: \"foo/bar.txt\"");
+    }
+
+    #[test]
+    fn test_display_other() {
+        assert_eq!(Error::WalkOpen(path()).to_string(), "Failed to open directory: \"foo/bar.txt\"");
+        assert_eq!(Error::WalkRead(path()).to_string(), "Failed to read directory: \"foo/bar.txt\"");
+        assert_eq!(Error::ListOpen(path()).to_string(), "Failed to open file list: \"foo/bar.txt\"");
+        assert_eq!(Error::ListRead(path()).to_string(), "Failed to read file list: \"foo/bar.txt\"");
+        assert_eq!(Error::Duplicate(path()).to_string(), "Skipped duplicate input file: \"foo/bar.txt\"");
+        assert_eq!(Error::Oversized(path(), 42u64).to_string(), "Skipped oversized input file: \"foo/bar.txt\" [size: 42 bytes]");
+        assert_eq!(
+            Error::LineBreak(PathBuf::from("foo\nbar.txt")).to_string(),
+            "File name contains a line break, use '--quote' or '--null' to process it: \"foo\\nbar.txt\""
+        );
+        assert_eq!(Error::LimitReached(NonZeroUsize::new(42usize).unwrap()).to_string(), "File limit reached, stopped the walk after 42 file(s)");
+        assert_eq!(Error::Malformed(path(), 7usize).to_string(), "Improperly formatted checksum line: \"foo/bar.txt\" [line #7]");
+        assert_eq!(Error::Incompatible(path(), 7usize).to_string(), "Checksum file was generated with incompatible parameters: \"foo/bar.txt\" [line #7]");
+        assert_eq!(Error::Anomalous(path(), 7usize).to_string(), "Non-canonical checksum line: \"foo/bar.txt\" [line #7]");
+        assert_eq!(Error::DigestTooLong(path(), 7usize).to_string(), "Digest too long: \"foo/bar.txt\" [line #7, maximum is 2048 bits]");
+    }
+
+    #[test]
+    fn test_display_xattr() {
+        assert_eq!(Error::Xattr(XattrError::NotFound, path()).to_string(), "Checksum attribute not found: \"foo/bar.txt\"");
+        assert_eq!(Error::Xattr(XattrError::Invalid, path()).to_string(), "Invalid checksum attribute: \"foo/bar.txt\"");
+        assert_eq!(Error::Xattr(XattrError::ReadErr, path()).to_string(), "Failed to read checksum attribute: \"foo/bar.txt\"");
+        assert_eq!(Error::Xattr(XattrError::WriteErr, path()).to_string(), "Failed to write checksum attribute: \"foo/bar.txt\"");
+        assert_eq!(Error::Xattr(XattrError::Unsupported, path()).to_string(), "Extended attributes are not supported: \"foo/bar.txt\"");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_display_is_cycle() {
+        let error = Error::IsCycle(path(), FileId::new(1u64, 2u64));
+        assert_eq!(error.to_string(), "Skipped directory cycle: \"foo/bar.txt\" [already visited: 1:2]");
+    }
+}
@@ -3,14 +3,22 @@
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
 use anstream::AutoStream;
+use memmap2::Mmap;
 use std::{
     fs::File,
     io::{stderr, stdin, stdout, Read, Result as IoResult, StderrLock, StdinLock, StdoutLock, Write},
     path::Path,
-    sync::{Mutex, MutexGuard},
+    sync::atomic::{AtomicBool, Ordering},
 };
 
-use crate::os::STDIN_NAME;
+use crate::{
+    arguments::Args,
+    common::FileMeta,
+    os::{is_stdin_alias, STDIN_NAME},
+};
+
+#[cfg(feature = "with-tar")]
+use {crate::archive::open_member, std::io::Take};
 
 // ---------------------------------------------------------------------------
 // Error type
@@ -21,33 +29,44 @@ pub enum Error {
     FileNotFound,
     AccessDenied,
     IsADirectory,
+    StdinInUse,
 }
 
 // ---------------------------------------------------------------------------
 // Source wrapper
 // ---------------------------------------------------------------------------
 
-static STDIN_MUTEX: Mutex<()> = Mutex::new(());
+/// Set once the standard input stream has been opened, because it can only be read once
+static STDIN_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// Regular files of at least this size (in bytes) will be memory-mapped
+const MMAP_THRESHOLD: u64 = 1024u64 * 1024u64;
 
 pub enum DataSource<'a> {
     File(File),
-    Stream((StdinLock<'a>, MutexGuard<'a, ()>)),
+    Stream(StdinLock<'a>),
+    #[cfg(feature = "with-tar")]
+    Member(Take<File>, FileMeta),
 }
 
 impl DataSource<'_> {
-    pub fn from_stdin() -> Self {
-        let guard = STDIN_MUTEX.try_lock().expect("Failed to lock 'stdin' stream!");
-        Self::Stream((stdin().lock(), guard))
+    pub fn from_stdin() -> Result<Self, Error> {
+        match STDIN_TAKEN.swap(true, Ordering::AcqRel) {
+            false => Ok(Self::Stream(stdin().lock())),
+            true => Err(Error::StdinInUse),
+        }
     }
 
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         if !STDIN_NAME.eq(path.as_ref()) {
             match File::open(path) {
                 Ok(file) => {
-                    if !Self::is_directory(&file) {
-                        Ok(Self::File(file))
-                    } else {
+                    if Self::is_directory(&file) {
                         Err(Error::IsADirectory)
+                    } else if is_stdin_alias(&file) {
+                        Self::from_stdin() /* must not be read concurrently with 'stdin' */
+                    } else {
+                        Ok(Self::File(file))
                     }
                 }
                 Err(io_error) => match io_error.kind() {
@@ -57,7 +76,50 @@ impl DataSource<'_> {
                 },
             }
         } else {
-            Ok(Self::from_stdin())
+            Self::from_stdin()
+        }
+    }
+
+    /// Open the given input file, where a path of the form `archive.tar!member` refers to a member of an archive, if `--into-archives` is in effect
+    pub fn from_input<P: AsRef<Path>>(path: P, args: &Args) -> Result<Self, Error> {
+        match args.expand_archives() {
+            #[cfg(feature = "with-tar")]
+            true => match open_member(path.as_ref()) {
+                Some(Ok((member, file_meta))) => Ok(Self::Member(member, file_meta)),
+                Some(Err(io_error)) if io_error.kind() == std::io::ErrorKind::NotFound => Err(Error::FileNotFound),
+                Some(Err(_)) => Err(Error::AccessDenied),
+                None => Self::from_path(path),
+            },
+            _ => Self::from_path(path),
+        }
+    }
+
+    /// Try to memory-map the underlying file, if it is a regular file that exceeds the threshold
+    ///
+    /// Returns `None` for standard input, small files, special files, or if mapping fails, in which case the caller is
+    /// expected to fall back to streamed reads.
+    pub fn map(&self) -> Option<Mmap> {
+        match self {
+            DataSource::File(file) => match file.metadata() {
+                Ok(meta) if meta.is_file() && (meta.len() >= MMAP_THRESHOLD) => unsafe { Mmap::map(file) }.ok().inspect(|mapped| {
+                    #[cfg(unix)]
+                    let _ = mapped.advise(memmap2::Advice::Sequential);
+                }),
+                _ => None,
+            },
+            DataSource::Stream(_) => None,
+            #[cfg(feature = "with-tar")]
+            DataSource::Member(_, _) => None,
+        }
+    }
+
+    /// Get the size and modification time of the underlying file, which are *not* available for standard input
+    pub fn file_meta(&self) -> FileMeta {
+        match self {
+            DataSource::File(file) => file.metadata().map(|meta_data| FileMeta::from_metadata(&meta_data)).unwrap_or_default(),
+            DataSource::Stream(_) => FileMeta::default(),
+            #[cfg(feature = "with-tar")]
+            DataSource::Member(_, file_meta) => *file_meta,
         }
     }
 
@@ -72,7 +134,9 @@ impl Read for DataSource<'_> {
     fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
         match self {
             DataSource::File(file) => file.read(buf),
-            DataSource::Stream(stream) => stream.0.read(buf),
+            DataSource::Stream(stream) => stream.read(buf),
+            #[cfg(feature = "with-tar")]
+            DataSource::Member(member, _) => member.read(buf),
         }
     }
 }
@@ -25,26 +25,71 @@
 //!   [FILES]...  Files to be processed
 //!
 //! Options:
-//!   -b, --binary           Read the input file(s) in binary mode, i.e., default mode
-//!   -t, --text             Read the input file(s) in text mode
-//!   -c, --check            Read and verify checksums from the provided input file(s)
-//!   -d, --dirs             Enable processing of directories as arguments
-//!   -r, --recursive        Recursively process the provided directories (implies -d)
-//!   -x, --cross-dev        Descend into directories on other devices (implies -r)
-//!   -a, --all              Iterate all kinds of files, instead of just regular files
-//!   -k, --keep-going       Continue processing even if errors are encountered
-//!   -l, --length <LENGTH>  Digest output size, in bits (default: 256, maximum: 2048)
-//!   -i, --info <INFO>      Include additional context information
-//!   -s, --snail...         Enable "snail" mode, i.e., slow down the hash computation
-//!   -q, --quiet            Do not output any error messages or warnings
-//!   -n, --no-color         Disable colored terminal output (ANSI color codes)
-//!   -p, --plain            Print digest(s) in plain format, i.e., without file names
-//!   -0, --null             Separate digest(s) by NULL characters instead of newlines
-//!   -m, --multi-threading  Enable multi-threaded processing of input files
-//!   -f, --flush            Explicitly flush 'stdout' stream after printing a digest
-//!   -T, --self-test        Run the built-in self-test (BIST)
-//!   -h, --help             Print help
-//!   -V, --version          Print version
+//!   -b, --binary                 Read the input file(s) in binary mode, i.e., default mode
+//!   -t, --text                   Read the input file(s) in text mode
+//!       --cr-newline             In text mode, also treat a lone carriage return ('\r') as a line break
+//!       --tree                   Compute the digest in "tree" hashing mode, which allows a single file to be hashed in parallel
+//!   -c, --check                  Read and verify checksums from the provided input file(s)
+//!       --check-xattr            Verify the input file(s) against the checksums that are stored in their extended attributes (Unix only)
+//!       --write-xattr            Store the digest of each input file in its extended attributes, in addition to printing it (Unix only)
+//!       --compare <HEX>          Compare the digest of a single input (file or 'stdin') to the given digest, in hex format
+//!       --verify-one <HEX>       Compare the digest of exactly one input file ('-' for 'stdin') to the given digest, in hex format
+//!       --lazy-compare           Compare the digest block by block, as it is squeezed, stopping at the first mismatch (requires '--check')
+//!       --warn                   Warn about improperly formatted checksum lines (instead of failing), or about skipped inputs
+//!       --strict                 Warn about checksum lines that are well-formed, but not in the canonical form, e.g., uppercase hex digits, and reject comment lines (requires '--check')
+//!       --fail-only              Print only the files that failed the verification, i.e., suppress "OK" results
+//!       --list-failed            Print the list of all files that failed the verification at the end (requires '--check')
+//!       --self-describing        Write a header line that identifies the algorithm and its parameters, which is verified by '--check'
+//!       --regenerate <FILE>      Write an updated copy of the checksum file, where the digests of all files that did not match are replaced (requires '--check' with a single checksum file, implies -k)
+//!   -d, --dirs                   Enable processing of directories as arguments
+//!   -r, --recursive              Recursively process the provided directories (implies -d)
+//!   -x, --cross-dev              Descend into directories on other devices (implies -r)
+//!       --exclude <GLOB>         Skip files and directories matching the given pattern (may be repeated)
+//!   -L, --follow-symlinks        Follow symbolic links found in directories, instead of skipping them
+//!   -a, --all                    Iterate all kinds of files, instead of just regular files
+//!       --max-size <BYTES>       Skip files found in directories whose size exceeds the given number of bytes
+//!       --limit-files <N>        Stop walking the directories after the given number of files have been found, e.g., for untrusted inputs
+//!       --sorted                 Sort the entries of each directory by file name, so that the order of the output is reproducible
+//!       --dedup                  Skip inputs that refer to a file that has already been processed
+//!   -k, --keep-going             Continue processing even if errors are encountered
+//!   -l, --length <LENGTH>        Digest output size, in bits (default: 256, minimum: 8, maximum: 2048)
+//!       --bytes <N>              Digest output size, in bytes (default: 32, maximum: 256)
+//!   -i, --info <INFO>            Include additional context information
+//!       --info-file <FILE>       Read the additional context information from the given file
+//!       --info-hex <HEX>         Include additional context information, given as binary value in hex format
+//!       --salt <HEX>             Absorb the given salt, in hex format, after the context information (e.g., a random per-run value)
+//!       --max-bytes <N>          Abort reading any single input that exceeds the given number of bytes
+//!       --wipe                   Wipe (zeroize) the read buffer after each input has been hashed, e.g., for sensitive files
+//!       --buffer-size <KIB>      Size of the read buffer, in KiB (maximum: 65536)
+//!   -s, --snail...               Enable "snail" mode, i.e., slow down the hash computation
+//!       --snail-target <MILLIS>  Calibrate the number of permutation rounds, such that a hash takes about the given time on this machine
+//!   -q, --quiet                  Do not output any error messages or warnings
+//!   -n, --no-color               Disable colored terminal output (ANSI color codes)
+//!       --color <WHEN>           Colorize the verification results, 'auto' only if the output is a terminal (default: 'auto') [possible values: auto, always, never]
+//!   -p, --plain                  Print digest(s) in plain format, i.e., without file names
+//!       --plain-fields <SPEC>    Select the fields of the plain format, as a comma-separated list (default: 'hex') [possible values: hex, size, name]
+//!       --with-meta              Include the file size and modification time with each digest, or verify them in '--check' mode
+//!       --show-rounds            Append the number of permutation rounds to each digest, so that '--check' can use the same number of rounds
+//!       --tag                    Print digest(s) in BSD-style "tagged" format, including the digest size
+//!       --quote                  Escape file names that contain backslash or newline characters, in the style of GNU coreutils
+//!   -0, --null                   Separate digest(s) by NULL characters instead of newlines (in '--check' mode, also for reading the checksum file)
+//!       --ndjson                 Print result(s) as newline-delimited JSON (NDJSON) objects
+//!   -m, --multi-threading        Enable multi-threaded processing of input files (or multi-threaded self-test)
+//!   -f, --flush                  Explicitly flush 'stdout' stream after printing a digest
+//!       --summary                Print the total number of files and bytes processed, as well as the elapsed time, at the end (or the verification statistics)
+//!       --time                   Print the time that was spent on hashing each file, as well as the throughput, to 'stderr'
+//!       --progress               Periodically print the number of bytes processed and the throughput to 'stderr'
+//!       --dry-run                List the files that would be processed, without actually computing any digests
+//!       --continue-from <FILE>   Skip files whose checksum is already listed in the given checksum file, e.g., from an interrupted run
+//!       --relative-to <BASE>     Print the file names relative to the given base directory, e.g., so that the checksum file is portable across machines
+//!       --base-dir <DIR>         Resolve relative file names from the checksum file against the given directory, instead of the current directory (requires '--check')
+//!       --tree-digest            Print a single digest for the whole directory tree, computed from the sorted file names and digests (implies '--recursive')
+//!       --block-stdin            Read records from 'stdin' and print a separate digest for each record, delimited by newlines (or see '--record-size')
+//!       --record-size <BYTES>    Split the input into records of the given number of bytes, instead of at newlines (requires '--block-stdin')
+//!   -T, --self-test              Run the built-in self-test (BIST)
+//!       --files-from <FILE>      Read the list of files to be processed from the given file ('-' for 'stdin')
+//!   -h, --help                   Print help
+//!   -V, --version                Print version
 //!
 //! If no input files are specified, reads input data from the 'stdin' stream.
 //! Returns a non-zero exit code if any errors occurred; otherwise, zero
@@ -91,7 +136,37 @@
 //!
 //!   By default, the program does **not** descend into directories that have a device number different than that of the directory from which the descent began. This restriction may be bypassed by specifying the **`--cross-dev`** option.
 //!
-//!   Furthermore, the **`--all`** option can be combined with `--dirs`, `--recursive` or `--cross-dev` to process **all** files found in a directory. Otherwise, the program will only process “regular” files, *skipping* special files like FIFOs or sockets.
+//!   Symbolic links that are encountered while iterating a directory are **skipped** by default, so that the iteration can not escape from the given directory tree. The **`--follow-symlinks`** option may be specified to follow symbolic links, to files as well as to directories, instead. Note that paths given on the command-line are *always* resolved. Directories that would form a cycle, i.e., that have already been visited on the current path, are **skipped**; if **`--warn`** is in effect, a note is printed for each skipped directory.
+//!
+//!   The **`--exclude <GLOB>`** option, which may be specified multiple times, can be used to skip all files and directories whose file name *or* full path matches the given [glob](https://en.wikipedia.org/wiki/Glob_(programming)) pattern, e.g., `--exclude '*.txt'` or `--exclude .git`. Excluded directories are **not** descended into.
+//!
+//!   The **`--max-size <BYTES>`** option can be used to skip all files found in a directory whose size exceeds the given number of bytes, e.g., to exclude huge disk images from a scan. Files that are given on the command-line explicitly are **not** affected by this option. If **`--warn`** is in effect, a note is printed for each skipped file.
+//!
+//!   The **`--limit-files <N>`** option can be used as a safety valve, e.g., when walking an *untrusted* directory: The walk is stopped as soon as `N` files have been found, and a note is printed. The files that have been found up to that point are still processed as usual.
+//!
+//!   The entries of a directory are processed in the order in which they are returned by the file system, which differs between machines. The **`--sorted`** option causes the entries of each directory to be sorted by file name (as raw bytes on Unix-like systems), so that the output is reproducible, e.g., for reproducible builds. Note that in multi-threaded mode, the order of the output still depends on the order in which the files are completed.
+//!
+//!   Furthermore, the **`--all`** option can be combined with `--dirs`, `--recursive` or `--cross-dev` to process **all** files found in a directory. Otherwise, the program will only process “regular” files, *skipping* special files like FIFOs or sockets. Note that named pipes (FIFOs) are read until the writer closes its end of the pipe, i.e., until EOF is reached.
+//!
+//! - **Duplicate inputs**
+//!
+//!   The **`--dedup`** option causes inputs that refer to a file that has already been processed, e.g., because the same file was specified multiple times or was reached via different paths, to be skipped. Files are identified by their device and inode number, where available, so that hard links to the same file are detected too; otherwise, by their canonicalized path. Duplicates are detected in the order in which the inputs are specified (or found), so the *first* path that refers to a file is always the one that is processed, and the file is counted only once by `--summary`, even in multi-threaded mode. If **`--warn`** is in effect, a note is printed for each skipped input.
+//!
+//! - **Dry run**
+//!
+//!   The **`--dry-run`** option causes the program to only list the files that would be processed, one per line, but *without* actually reading any file contents or computing any digests. This is useful for checking the effect of options like `--recursive`, `--exclude` or `--dedup` in advance. With **`--ndjson`**, each file is printed as a JSON object `{"file":"<name>"}`.
+//!
+//! - **Resuming**
+//!
+//!   The **`--continue-from <FILE>`** option reads the checksum file that was written by a previous (e.g., interrupted) run, and then skips all input files that are already listed in that checksum file, so that only the remaining files are processed. Files are matched by their path, exactly as it appears in the checksum file. Improperly formatted lines, such as an incomplete last line, are ignored. Append the output to the existing checksum file in order to complete it.
+//!
+//! - **File list**
+//!
+//!   The **`--files-from <FILE>`** option causes the list of files to be processed to be read from the specified file, instead of from the command-line. The file names must be separated by newline characters (or by NULL characters, if **`--null`** is in effect). Specify `-` in order to read the list of files from the standard input stream.
+//!
+//!   Note that the standard input stream can be read only *once* per invocation. If it is referenced more than once, e.g., because `/dev/stdin` is given as an input file while the list of files is read from the standard input stream, each further reference fails with an error. On Unix-like systems, this also applies to other paths that refer to the same pipe as the standard input stream, e.g., `/dev/fd/0`.
+//!
+//!   This avoids the limitations of the maximum command-line length, e.g., when processing a list of thousands of files.
 //!
 //! - **Checksum verification**
 //!
@@ -102,10 +177,94 @@
 //!   <HASH_VALUE_HEX><SPACE><FILE_PATH><EOL>
 //!   ```
 //!
-//!   All checksums (hash values) in a particular checksum file are expected to have the same length, in bits.
+//!   Alternatively, the BSD-style “tagged” format, as generated by the **`--tag`** option, is supported too:
+//!   ```
+//!   SpongeHash256-<BITS><SPACE>(<FILE_PATH>)<SPACE>=<SPACE><HASH_VALUE_HEX><EOL>
+//!   ```
+//!
+//!   The checksums (hash values) in a particular checksum file may have *different* lengths. Each checksum is verified against a digest of the same length, in bits, as the checksum itself. Because a shorter digest is always a prefix of a longer digest of the same input, a truncated checksum still verifies correctly. In the “tagged” format, the `<BITS>` value is authoritative: if it does not match the length of the given hex string exactly, then the line is rejected as improperly formatted.
+//!
+//!   On Unix-like platforms, file paths are written to (and read from) checksum files as *raw* bytes, so that file paths which are **not** valid UTF-8 are preserved exactly. On the Windows platform, file paths are always written as UTF-8; any unpaired surrogates (which are allowed in Windows file paths) are replaced by the `U+FFFD` character.
+//!
+//!   If the **`--null`** option is specified too, then the records in the checksum file are expected to be separated by NULL characters instead of newlines, as generated by the **`--null`** option. This allows for file names that contain newline characters. A checksum file that does not contain any NULL character at all is still read line by line.
+//!
+//!   Empty lines as well as comment lines, i.e., lines whose first non-whitespace character is a `#`, are skipped. This allows for checksum files that carry additional information, e.g., the tool version or the date of creation.
+//!
+//!   The **`--self-describing`** option causes a header line to be written *before* the checksums, which identifies the algorithm and all parameters that affect the computed digests, i.e., the number of permutation rounds (`--snail`), the input mode (`--text`, `--cr-newline` or `--tree`) and the context (`--info`, `--info-hex` and `--salt`). The context is identified by a short fingerprint only, so that it is not disclosed. If a checksum file contains such a header line, then the verification is aborted with an error, *unless* the parameters that are in effect for the verification match the header line exactly. This prevents a checksum file from being verified against an incompatible set of parameters, which would only result in confusing mismatches. For example:
+//!   ```
+//!   #! SpongeHash256 rounds=1 mode=binary context=none
+//!   ```
+//!
+//!   By default, an improperly formatted line in a checksum file is treated as an error. If the **`--warn`** option is specified, a warning (including the line number) is printed for each improperly formatted line instead, and the verification continues with the next line.
+//!
+//!   The **`--strict`** option additionally causes a warning to be printed for each line that is well-formed, but *not* in the canonical form, as it is generated by this program, e.g., because the checksum contains uppercase hex digits or because the line has leading or trailing whitespace. Such lines are still verified as usual. Also, comment lines are **not** skipped in this mode, but are treated as improperly formatted lines.
+//!
+//!   The **`--lazy-compare`** option causes the computed digest to be compared to the expected checksum block by block, *while* it is being squeezed from the hash state, so that the comparison stops at the first mismatching block of 16 bytes, and the complete digest never needs to be buffered. Each block is compared in constant time, so the timing reveals the index of the first mismatching block, but not the position of the mismatch within that block. The default is to generate the complete digest first and then compare it in constant time.
+//!
+//!   As an alternative to a checksum file, the checksum of each file can be stored in an *extended attribute* of the file itself, named `user.sponge256`. The **`--write-xattr`** option causes the digest of each input file to be stored in that attribute, in addition to printing it. Conversely, the **`--check-xattr`** option causes each input file to be verified against the checksum that is stored in its attribute, and `OK` or `FAILED` is printed for each file, just like in `--check` mode. A file that does *not* have the attribute is reported as an error. These options are currently supported on Linux and macOS only, and only on file systems that support extended attributes.
+//!
+//!   The **`--fail-only`** option suppresses the output of all successfully verified files, so that only the files that did *not* match (`FAILED`) are printed, followed by the final summary. The exit status is not affected by this option.
+//!
+//!   The **`--color <WHEN>`** option controls whether the verification results are colorized, i.e., `OK` in green and `FAILED` in red, which makes it easier to scan the results of a large verification. With `auto`, which is the default, colors are used only if the standard output is a terminal and `--no-color` is *not* in effect, so that redirected output never contains any ANSI escape codes. The values `always` and `never` force colors on or off. With `--ndjson`, the results are never colorized.
+//!
+//!   The **`--list-failed`** option causes the list of all files that did *not* match to be printed to the standard error stream once more, after all checksums have been verified. This is useful for triage, when the failed files are buried in a long output. At most 1024 files are listed; any additional files are counted, but omitted from the list.
+//!
+//!   The **`--regenerate <FILE>`** option causes an *updated* copy of the checksum file to be written to the given file, after all checksums have been verified, which makes it easy to maintain a checksum file. For each file that did *not* match, the digest in the original line is replaced by the newly computed digest, of the same length. All other lines, including the lines of files that could not be read, e.g., because they no longer exist, as well as comment lines, header lines and improperly formatted lines, are retained verbatim, in their original order. This option requires exactly one checksum file, which may also be the given file. New files are *not* appended, because `--check` cannot be combined with a directory scan; instead, run a directory scan with `--continue-from`, and append its output to the checksum file. This option implies `--keep-going`.
 //!
 //!   If the `--info`, `--text` or `--snail` option has been used to calculate the hash values in a checksum file, then the ***same*** `--info`, `--text` or `--snail` parameter(s) **must** be used for the checksum verification again! &#128680;
 //!
+//! - **Digest comparison**
+//!
+//!   The **`--compare <HEX>`** option computes the digest of a *single* input file (or of the standard input stream, if no file is specified) and compares it to the given digest, in hex format, without the need for a checksum file. The result is printed as `OK` or `FAILED`, like in `--check` mode, and the process returns a non-zero exit status if the digests do **not** match. The digest size is inferred from the length of the given hex string.
+//!
+//!   The **`--verify-one <HEX>`** option works just like `--compare`, except that *exactly one* input file must be specified, which makes it convenient for checking a single downloaded artifact in a CI script, e.g., `sponge256sum --verify-one <HEX> <FILE>`. The file name `-` denotes the standard input stream.
+//!
+//! - **Record-wise hashing**
+//!
+//!   The **`--block-stdin`** option causes the standard input stream to be split into a sequence of *records*, and a separate digest to be printed for each record, in the order in which the records were read, rather than a single digest for the whole stream. This is useful for streaming protocols, where each line or record needs its own digest. By default, records are delimited by newline characters, which are *not* included in the digest. Alternatively, the **`--record-size <BYTES>`** option splits the input into records of a fixed size, where the final record may be shorter.
+//!
+//! - **Plain output**
+//!
+//!   The **`--plain`** option causes only the digest, as hex string, to be printed for each input, i.e., without the file name.
+//!
+//!   Optionally, the **`--plain-fields <SPEC>`** option can be used, in addition to `--plain`, in order to select which fields are printed and in what order. The `SPEC` is a comma-separated list of the fields `hex` (the digest), `size` (the number of bytes hashed, in decimal) and `name` (the file name), e.g., `hex,size,name`. Fields are separated by a single space character, and each record is terminated by a newline character, or by a NULL character if `--null` is in effect.
+//!
+//! - **Quoted file names**
+//!
+//!   The **`--quote`** option causes file names that contain backslash, newline or carriage return characters to be escaped, in the style of GNU coreutils, i.e., these characters are replaced by `\\`, `\n` and `\r`, respectively. Lines that contain an escaped file name are marked by a leading `\` character. In `--check` mode, escaped file names are recognized and restored automatically.
+//!
+//!   A file name that contains a newline character would break the line-based output format. Therefore, such a file is rejected with an error, unless either `--quote` or `--null` is in effect, or the file name is not printed at all.
+//!
+//! - **Relative file names**
+//!
+//!   The **`--relative-to <BASE>`** option causes each file name to be printed relative to the given base directory, e.g., `sub/file.txt` instead of `/mnt/data/sub/file.txt`, so that a checksum file that was generated from an absolute scan root is portable across machines. Files outside of the base directory are printed with leading `..` components. Both the base directory and the directory containing each file are resolved to their canonical form, but the file name itself is *not*, so that symbolic links are retained.
+//!
+//!   In `--check` mode, the **`--base-dir <DIR>`** option causes relative file names from the checksum file to be resolved against the given directory, rather than against the current working directory, e.g., `sponge256sum --check --base-dir /mnt/data checksums.txt`. File names are still printed as they appear in the checksum file.
+//!
+//! - **Archive members**
+//!
+//!   If `sponge256sum` was built with the optional **`with-tar`** feature, the **`--into-archives`** option causes each `.tar` file that is encountered, either as an input file or while walking a directory, to be expanded into its members, without extracting it. Each regular file stored in the archive is hashed individually and printed with a synthetic path of the form `archive.tar!member`, in archive order, whereas the archive itself is *not* hashed. With `--with-meta`, the size and modification time are taken from the archive headers.
+//!
+//!   In `--check` mode, the **`--into-archives`** option causes file names of the form `archive.tar!member` to be resolved to the corresponding member of the archive, unless a file of that exact name exists. The path is split at the *first* `!` character that follows the `.tar` extension.
+//!
+//! - **File metadata**
+//!
+//!   The **`--with-meta`** option causes the file size, in bytes, and the modification time, in seconds since the Unix epoch, to be included with each digest. Each line then has the form `<HASH_VALUE_HEX> <SIZE> <MTIME> <FILE_PATH>`, where a field that is not available, e.g., the modification time of the standard input stream, is written as `-`. In `--ndjson` mode, the fields `"size"` and `"mtime"` are added to each object instead.
+//!
+//!   In `--check` mode, the **`--with-meta`** option causes the checksum file to be parsed in the above format, and a file is reported as `FAILED` if either its digest, or its size or modification time, does *not* match.
+//!
+//! - **Permutation rounds**
+//!
+//!   The **`--show-rounds`** option causes the number of permutation rounds that was actually used, e.g., as selected by `--snail` or `--snail-target`, to be appended to each digest, separated by an `@` character, i.e., `<HASH_VALUE_HEX>@<ROUNDS>`. In `--ndjson` mode, the field `"rounds"` is added to each object instead.
+//!
+//!   In `--check` mode, a digest that is followed by the number of rounds is *always* verified with the recorded number of rounds, regardless of the `--snail` option, so that the verification uses the same work factor as the original computation. The recorded number of rounds must not exceed 65521.
+//!
+//! - **NDJSON output**
+//!
+//!   The **`--ndjson`** option causes each result to be printed as a separate JSON object, one per line ([NDJSON](https://github.com/ndjson/ndjson-spec)). The output stream is flushed after each line, which makes this format suitable for *streaming* consumption, e.g., by log processors.
+//!
+//!   In hashing mode, each object has the form `{"file":"<FILE_PATH>","digest":"<HASH_VALUE_HEX>"}`, whereas in `--check` mode each object has the form `{"file":"<FILE_PATH>","result":"OK"|"FAILED"}`.
+//!
 //! - **Multi-threading**
 //!
 //!   The **`--multi-threading`** option enables [multithreading](https://en.wikipedia.org/wiki/Thread_(computing)) mode, in which multiple files can be processed concurrently.
@@ -114,20 +273,70 @@
 //!
 //!   Also note that each file still is processed by a single thread, so this mode is mostly useful when processing ***many*** files.
 //!
+//!   In `--self-test` mode, the `--multi-threading` option causes the independent test streams to be hashed in parallel; the reported throughput is the aggregate throughput of all threads.
+//!
 //! - **Output length**
 //!
 //!   The **`--length <LENGTH>`** option can be used to specify the digest output size, in bits. The default size is 256 bits.
 //!
-//!   Currently, the maximum output size is 1024 bits. Also, the output size, in bits, must be divisible by eight!
+//!   Currently, the minimum output size is 8 bits and the maximum output size is 2048 bits. Also, the output size, in bits, must be divisible by eight!
+//!
+//!   Alternatively, the **`--bytes <N>`** option can be used to specify the digest output size directly in bytes, e.g., `--bytes 32` is equivalent to `--length 256`. The options `--length` and `--bytes` are mutually exclusive.
 //!
 //! - **Context information**
 //!
-//!   The **`--info <INFO>`** option can be used to include some additional context information in the hash computation.
+//!   The **`--info <INFO>`** option can be used to include some additional context information in the hash computation. The length of the “info” string is limited to 255 **bytes** of UTF-8 encoding, so non-ASCII characters count as multiple bytes.
 //!
 //!   For each unique “info” string, different digests (hash values) are generated from the same messages (inputs).
 //!
 //!   This enables proper *domain separation* for different uses, e.g., applications or protocols, of the same hash function.
 //!
+//!   Alternatively, the **`--info-file <FILE>`** option can be used to read the context information from a file. A single trailing line break is removed. The options `--info` and `--info-file` are mutually exclusive; the same length limit applies to both.
+//!
+//!   If the context information is a *binary* value, e.g., a protocol identifier, the **`--info-hex <HEX>`** option can be used to pass it in hex format. The length limit applies to the *decoded* value. For example, `--info-hex 616263` is equivalent to `--info abc`. This option can not be combined with `--info` or `--info-file`.
+//!
+//!   In addition, the **`--salt <HEX>`** option can be used to absorb a salt value, given in hex format, after the context information. Unlike the “info” string, which is intended for a *fixed* domain separation, the salt is intended to be chosen, e.g., randomly, for each run. The salt is printed to the standard error stream, so that the digests can be reproduced later, by passing the *same* salt again. The length of the salt is limited to 255 bytes.
+//!
+//! - **Size limit**
+//!
+//!   The **`--max-bytes <N>`** option can be used to limit the number of bytes that will be read from any *single* input. If an input exceeds this limit, then reading is aborted and an error is reported for that input.
+//!
+//!   This protects against *accidental* unbounded reads, e.g., when a special file like `/dev/zero` or `/dev/urandom` was passed to the program.
+//!
+//! - **Tree hashing**
+//!
+//!   The **`--tree`** option enables “tree” hashing mode. In this mode, each input is split into leaves of 1 MiB, which are hashed *independently* of each other, before the concatenation of all leaf digests is hashed again to obtain the final digest.
+//!
+//!   If combined with **`--multi-threading`**, the leaves of each *single* input are hashed in parallel, rather than hashing multiple inputs in parallel. This speeds up the processing of very large files. The resulting digest does *not* depend on the number of threads.
+//!
+//!   Please note that the digests computed in “tree” mode are ***different*** from the “plain” digests! The option `--tree` can *not* be combined with `--check`, `--text`, `--info` or `--snail`. &#128680;
+//!
+//! - **Directory digest**
+//!
+//!   The **`--tree-digest`** option computes a *single* digest for a whole directory tree, e.g., for the verification of reproducible builds. All files are hashed as usual, but instead of printing a line for each file, the file names and digests are sorted and then hashed once more, so that only the final digest is printed. This option implies **`--recursive`** and can be combined with `--multi-threading`; the result does *not* depend on the order in which the files are processed. If any file could not be processed, then *no* digest is printed.
+//!
+//!   Please note that the file names are hashed *exactly* as they would appear in the regular output, i.e., as raw bytes and including the given directory prefix. Hence, the same directory must be specified in the same way (e.g., a relative vs. an absolute path) to obtain the same digest. Also, file names are encoded differently on Windows (UTF-8, with `\` separators) and on Unix-like systems (raw bytes, with `/` separators), so the digests are *not* comparable across platforms. &#128680;
+//!
+//! - **Read buffer**
+//!
+//!   The **`--buffer-size <KIB>`** option can be used to override the size of the buffer that is used for reading the input, in KiB. By default, the buffer size is selected automatically. A larger buffer may improve the throughput, e.g., on network file systems. The maximum size is 65536 KiB.
+//!
+//!   The **`--wipe`** option causes the read buffer to be wiped (zeroized) after each input has been hashed, so that the contents of sensitive files do not linger in memory. The buffer is wiped only *once* per input, so this does not noticeably affect the throughput. Note that memory-mapped files are not copied into the read buffer in the first place.
+//!
+//! - **Summary**
+//!
+//!   The **`--summary`** option causes a final summary line of the form `Summary: <N> file(s), <M> bytes, <T> seconds` to be printed to the standard error stream, after all inputs have been processed. Here, `<N>` is the number of inputs that have been hashed successfully, `<M>` is the total number of bytes that have actually been hashed, and `<T>` is the elapsed time. This option is ignored, if **`--quiet`** is in effect.
+//!
+//!   In verification mode, i.e., with **`--check`**, the summary line is of the form `Summary: <N> checksum(s) read, <A> matched, <B> failed, <C> errored` instead. Here, `<A>` is the number of target files that matched the expected checksum, `<B>` is the number of target files that did *not* match, and `<C>` is the number of target files (or checksum files) that could not be read. Improperly formatted lines are not counted.
+//!
+//! - **Progress indicator**
+//!
+//!   The **`--progress`** option causes the number of bytes processed so far, as well as the current throughput, to be printed to the standard error stream periodically (at most once per second), while a large input is being hashed. The digest output on the standard output stream is *not* affected. This option is ignored, if **`--quiet`** is in effect, and it can *not* be combined with **`--multi-threading`**.
+//!
+//! - **Timing**
+//!
+//!   The **`--time`** option causes the time that was spent on hashing each input file, as well as the resulting throughput, to be printed to the standard error stream, in the form `Time: <FILE> [<T> seconds, <R> MiB/s]`. In multi-threaded mode, the number of the worker thread that hashed the file is included too. The digest output on the standard output stream is *not* affected. This option is ignored, if **`--quiet`** is in effect.
+//!
 //! - **Snail mode**
 //!
 //!   The **`--snail`** option can be passed to the program, optionally more than once, to slow down the hash computation.
@@ -142,35 +351,56 @@
 //!   **×3** | 4093                         |                 25.82
 //!   **×4** | 65521                        |                  1.61
 //!
+//!   Alternatively, the **`--snail-target <MILLIS>`** option calibrates the number of permutation rounds at startup: A short message is hashed with an increasing number of rounds, until the time per hash can be measured reliably, then the number of rounds is scaled, such that the time per hash is about the given number of milliseconds. The result is limited to 65521 rounds. The selected number of rounds is printed to the standard error stream. Because the measured times depend on the machine, the selected number of rounds should be recorded, e.g., by using the `--show-rounds` option, so that the ***same*** number of rounds is used for verification.
+//!
 //! - **Text mode**
 //!
 //!   The **`--text`** option enables “text” mode. In this mode, the input file is read as a *text* file, line by line.
 //!
 //!   Unlike in “binary” mode (the default), platform-specific line endings will be normalized to a single `\n` character.
 //!
+//!   The input does *not* need to be valid UTF-8; lines are processed as raw bytes. Only a `\r` character that is *immediately* followed by a `\n` character is removed, whereas a lone `\r` character is preserved.
+//!
+//!   The **`--cr-newline`** option can be combined with **`--text`** to *also* treat a lone `\r` character as a line break, e.g., for files that use the “classic” Mac OS line endings.
+//!
 //! ## Environment
 //!
 //! The following environment variables are recognized:
 //!
 //! - **`SPONGE256SUM_THREAD_COUNT`**:  
 //!   Specifies the number of threads to be used in `--multi-threading` mode.  
-//!   If set to **0**, which is the default, the number of CPU cores is detected automatically at runtime.  
+//!   If set to **0**, which is the default, the number of CPU cores is detected automatically at runtime, and the number of threads is derived as `max(1, floor(2 * log2(cores)))`. If the number of CPU cores can not be detected, 4 threads are used.  
 //!   Please note that the number of threads is currently limited to the range from 1 to 32.
 //!
 //! - **`SPONGE256SUM_DIRWALK_STRATEGY`**:  
 //!   Selects the search strategy to be used for walking the directory tree in `--recursive` mode.  
-//!   This can be `BFS` (breadth-first search) or `DFS` (depath-first search). Default is `BFS`.
+//!   This can be `BFS` (breadth-first search), `DFS` (depath-first search) or `AUTO`. Default is `AUTO`.  
+//!   In `AUTO` mode, the fan-out of the top-level directories is probed: Wide trees are walked breadth-first, narrow trees depth-first.
+//!
+//! - **`SPONGE256SUM_DIRWALK_QUEUE_LIMIT`**:  
+//!   Specifies the maximum number of sub-directories that are queued per directory, when walking the directory tree breadth-first. Default is **4096**.  
+//!   Any further sub-directories are walked immediately (depth-first), so that the memory usage remains bounded, even for very wide trees.
+//!
+//! - **`SPONGE256SUM_PARSE_CHUNK_SIZE`**:  
+//!   Specifies the size of the chunks, in bytes, in which a large checksum file is split, in `--check` mode, in order to parse the chunks in parallel. Default is **16777216** (16 MiB).  
+//!   This applies only in `--multi-threading` mode, and not to the standard input stream or to NULL-terminated checksum files. The number of chunks is limited to the number of threads.
 //!
 //! - **`SPONGE256SUM_SELFTEST_PASSES`**:  
 //!   Specifies the number of passes to be executed in `--self-test` mode. Default is **3**.
 //!
+//! - **`SPONGE256SUM_SELFTEST_SAMPLES`**:  
+//!   Enables an additional *statistical* test in `--self-test` mode, which checks the avalanche effect and the absence of collisions for the specified number of random samples. Disabled by default.
+//!
 //! ## Exit status
 //!
 //! The process returns one of the following exit status codes:
 //!
-//! - **0** &ndash; The process completed successfully.
-//! - **1** &ndash; The process completed, but one or more files were skipped due to errors (only when `--keep-going` is used).
-//! - **2** &ndash; The process failed due to an error (for example, an I/O error).
+//! - **0** &ndash; The process completed successfully.  
+//!   This includes the case where the reader closes the 'stdout' pipe early (e.g., `sponge256sum * | head -n 1`).
+//! - **1** &ndash; The process completed, but one or more files were skipped due to errors (only when `--keep-going` is used).  
+//!   In `--check` mode: One or more computed checksums did *not* match, but all files could be read.
+//! - **2** &ndash; The process failed due to an error (for example, an I/O error).  
+//!   In `--check` mode: One or more files (checksum files or target files) could *not* be read, regardless of `--keep-going`.
 //! - **3** &ndash; The process has been interrupted by the user; generated output may be incomplete.
 //!
 //! ## Platform support
@@ -186,6 +416,8 @@
 //! - Haiku OS
 //! - Solaris / Illumos
 //!
+//! The AES implementation that is used, i.e., hardware-accelerated (AES-NI or ARMv8 Crypto Extensions) or software, is detected at runtime. Run `sponge256sum --version` in order to show the detected AES backend and the enabled SIMD features.
+//!
 //! ## License
 //!
 //! Copyright (C) 2025-2026 by LoRd_MuldeR &lt;mulder2@gmx.de&gt;
@@ -199,10 +431,13 @@
 //! &#x1F517; <https://crates.io/crates/sponge-hash-aes256>  
 //! &#x1F517; <https://github.com/lordmulder/sponge-hash-aes256>
 
+#[cfg(feature = "with-tar")]
+mod archive;
 mod arguments;
 mod common;
 mod digest;
 mod environment;
+mod error;
 mod io;
 mod os;
 mod process;
@@ -221,12 +456,13 @@ use std::{
 use crate::{
     arguments::{parse_command_line, Args},
     common::{Aborted, ExitStatus, Flag},
-    common::{MAX_DIGEST_SIZE, MAX_SNAIL_LEVEL},
+    common::{MAX_BUFFER_SIZE, MAX_DIGEST_SIZE, MAX_SNAIL_LEVEL},
+    digest::calibrate_rounds,
     environment::Env,
     io::OutStream,
     process::process_files,
     self_test::self_test,
-    verify::verify_files,
+    verify::{compare_input, verify_files},
 };
 
 // Enable MiMalloc, if the "with-mimalloc" feature is enabled
@@ -248,20 +484,36 @@ fn sponge256sum_main(output: &mut OutStream, args: &'static Args) -> Result<Exit
     simple_logger::SimpleLogger::new().init().unwrap();
 
     // Compute the digest size, in bytes (falling back to the default, it unspecified)
-    let (digest_size, digest_rem) = match args.length {
-        Some(digest_bits) => digest_bits.get().div_rem(&(u8::BITS as usize)),
-        None => (DEFAULT_DIGEST_SIZE, 0usize),
+    let (digest_size, digest_rem) = match (args.length, args.bytes) {
+        (Some(digest_bits), _) => digest_bits.div_rem(&(u8::BITS as usize)),
+        (None, Some(digest_bytes)) => (digest_bytes.get(), 0usize),
+        (None, None) => (DEFAULT_DIGEST_SIZE, 0usize),
     };
 
+    // Make sure that the digest size is at least one byte
+    if digest_size == 0usize {
+        print_error!(output, args, "Error: Digest output size must be at least 8 bits! (given value: {})", args.length.unwrap());
+        return Ok(ExitStatus::Failure);
+    }
+
     // Make sure that the digest size is divisble by eight
     if digest_rem != 0usize {
-        print_error!(output, args, "Error: Digest output size must be divisible by eight! (value: {}, remainder: {})", args.length.unwrap().get(), digest_rem);
+        print_error!(output, args, "Error: Digest output size must be divisible by eight! (value: {}, remainder: {})", args.length.unwrap(), digest_rem);
         return Ok(ExitStatus::Failure);
     }
 
     // Make sure that the digest size doesn't exceed the allowable maximum
     if digest_size > MAX_DIGEST_SIZE {
-        print_error!(output, args, "Error: Digest output size exceeds the allowable maximum! (given value: {})", digest_size * 8usize);
+        match args.bytes {
+            Some(digest_bytes) => print_error!(output, args, "Error: Digest output size exceeds the allowable maximum! (given value: {} bytes)", digest_bytes),
+            None => print_error!(output, args, "Error: Digest output size exceeds the allowable maximum! (given value: {})", digest_size * 8usize),
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Make sure that the read buffer size doesn't exceed the allowable maximum
+    if args.buffer_size.is_some_and(|size_kib| size_kib.get() > MAX_BUFFER_SIZE) {
+        print_error!(output, args, "Error: Read buffer size exceeds the allowable maximum! (given value: {} KiB)", args.buffer_size.unwrap());
         return Ok(ExitStatus::Failure);
     }
 
@@ -271,12 +523,23 @@ fn sponge256sum_main(output: &mut OutStream, args: &'static Args) -> Result<Exit
         return Ok(ExitStatus::Failure);
     }
 
-    // Check the maximum allowable info length
+    // Calibrate the number of permutation rounds to the given target time per hash
+    if let Some(snail_target) = args.snail_target {
+        let rounds = *args.calibrated_rounds.get_or_init(|| calibrate_rounds(Duration::from_millis(snail_target.get())));
+        print_info!(output, args, "Snail target: {} ms, selected {} rounds", snail_target, rounds);
+    }
+
+    // Check the maximum allowable info length (in bytes, not characters)
     if args.info.as_ref().is_some_and(|str| str.len() > u8::MAX as usize) {
-        print_error!(output, args, "Error: Length of context info must not exceed 255 characters! (given length: {})", args.info.as_ref().unwrap().len());
+        print_error!(output, args, "Error: Length of context info must not exceed 255 bytes! (given length: {} bytes)", args.info.as_ref().unwrap().len());
         return Ok(ExitStatus::Failure);
     }
 
+    // Report the salt, so that the digests can be reproduced later
+    if let Some(salt) = args.salt.as_ref() {
+        print_info!(output, args, "Salt: {}", salt);
+    }
+
     // Parse additional options from environment variables
     let env = match Env::from_env() {
         Ok(options) => options,
@@ -292,11 +555,14 @@ fn sponge256sum_main(output: &mut OutStream, args: &'static Args) -> Result<Exit
     // Run built-in self-test, if it was requested by the user
     if args.self_test {
         self_test(output, args, &env, &HALT_FLAG)
-    } else if !args.check {
+    } else if args.compare.is_some() || args.verify_one.is_some() {
+        // Compare a single input to the digest that was given on the command-line
+        compare_input(output, args, &HALT_FLAG)
+    } else if !(args.check || args.check_xattr) {
         // Process all input files/directories that were given on the command-line
         process_files(output, digest_size, args, &env, &HALT_FLAG)
     } else {
-        // Verify all checksum files that were given on the command-line
+        // Verify all checksum files (or extended attributes) that were given on the command-line
         verify_files(output, args, &env, &HALT_FLAG)
     }
 }
@@ -2,15 +2,23 @@
 // sponge256sum
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
-use libc::{fstat, stat};
+use cfg_if::cfg_if;
+use libc::{c_int, fstat, stat};
 use std::{
-    fs::Metadata,
+    borrow::Cow,
+    ffi::{CStr, CString, OsString},
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::{File, Metadata},
+    io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult},
     mem::zeroed,
     os::{
         fd::{AsRawFd, RawFd},
-        unix::fs::MetadataExt,
+        unix::{
+            ffi::{OsStrExt, OsStringExt},
+            fs::MetadataExt,
+        },
     },
-    path::Path,
+    path::{Path, PathBuf},
     sync::LazyLock,
 };
 
@@ -32,12 +40,26 @@ pub fn is_pipe(data_source: &DataSource) -> bool {
     matches!(info.st_mode & libc::S_IFMT, libc::S_IFIFO | libc::S_IFSOCK)
 }
 
+/// Check whether the given file refers to the same pipe (or socket) as the standard input stream, e.g., when it was opened via `/dev/fd/0`
+pub fn is_stdin_alias(file: &File) -> bool {
+    let (mut info_stdin, mut info_file): (stat, stat) = unsafe { (zeroed(), zeroed()) };
+
+    if unsafe { fstat(libc::STDIN_FILENO, &mut info_stdin) } != 0 || unsafe { fstat(file.as_raw_fd(), &mut info_file) } != 0 {
+        return false; /*failure!*/
+    }
+
+    matches!(info_stdin.st_mode & libc::S_IFMT, libc::S_IFIFO | libc::S_IFSOCK)
+        && (info_stdin.st_dev, info_stdin.st_ino) == (info_file.st_dev, info_file.st_ino)
+}
+
 impl AsRawFd for DataSource<'_> {
     #[inline(always)]
     fn as_raw_fd(&self) -> RawFd {
         match self {
             DataSource::File(file) => file.as_raw_fd(),
-            DataSource::Stream(stream) => stream.0.as_raw_fd(),
+            DataSource::Stream(stream) => stream.as_raw_fd(),
+            #[cfg(feature = "with-tar")]
+            DataSource::Member(member, _) => member.get_ref().as_raw_fd(),
         }
     }
 }
@@ -49,7 +71,7 @@ impl AsRawFd for DataSource<'_> {
 pub type DevId = u64;
 pub type InoId = u64;
 
-#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct FileId {
     dev: DevId,
     ino: InoId,
@@ -72,7 +94,117 @@ impl FileId {
     }
 }
 
+impl Display for FileId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.dev, self.ino)
+    }
+}
+
 #[inline]
 pub fn file_id(meta: Metadata) -> Option<FileId> {
     Some(FileId::new(meta.dev(), meta.ino()))
 }
+
+// ---------------------------------------------------------------------------
+// Path functions
+// ---------------------------------------------------------------------------
+
+/// Convert raw bytes to a path, arbitrary byte sequences are preserved
+#[inline]
+pub fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(OsString::from_vec(bytes))
+}
+
+/// Convert a path to raw bytes, arbitrary byte sequences are preserved
+#[inline]
+pub fn path_to_bytes(path: &Path) -> Cow<'_, [u8]> {
+    Cow::Borrowed(path.as_os_str().as_bytes())
+}
+
+// ---------------------------------------------------------------------------
+// Extended attribute functions
+// ---------------------------------------------------------------------------
+
+/// Maximum size of an extended attribute value that can be read, in bytes
+const MAX_XATTR_SIZE: usize = 1024usize;
+
+cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        const ERR_NO_ATTR: c_int = libc::ENODATA;
+
+        fn sys_getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> IoResult<usize> {
+            let result = unsafe { libc::getxattr(path.as_ptr(), name.as_ptr(), value.as_mut_ptr().cast(), value.len()) };
+            usize::try_from(result).map_err(|_| xattr_error())
+        }
+
+        fn sys_setxattr(path: &CStr, name: &CStr, value: &[u8]) -> IoResult<()> {
+            match unsafe { libc::setxattr(path.as_ptr(), name.as_ptr(), value.as_ptr().cast(), value.len(), 0) } {
+                0 => Ok(()),
+                _ => Err(xattr_error()),
+            }
+        }
+    } else if #[cfg(target_vendor = "apple")] {
+        const ERR_NO_ATTR: c_int = libc::ENOATTR;
+
+        fn sys_getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> IoResult<usize> {
+            let result = unsafe { libc::getxattr(path.as_ptr(), name.as_ptr(), value.as_mut_ptr().cast(), value.len(), 0u32, 0) };
+            usize::try_from(result).map_err(|_| xattr_error())
+        }
+
+        fn sys_setxattr(path: &CStr, name: &CStr, value: &[u8]) -> IoResult<()> {
+            match unsafe { libc::setxattr(path.as_ptr(), name.as_ptr(), value.as_ptr().cast(), value.len(), 0u32, 0) } {
+                0 => Ok(()),
+                _ => Err(xattr_error()),
+            }
+        }
+    } else {
+        const ERR_NO_ATTR: c_int = 0;
+
+        fn sys_getxattr(_path: &CStr, _name: &CStr, _value: &mut [u8]) -> IoResult<usize> {
+            Err(IoErrorKind::Unsupported.into())
+        }
+
+        fn sys_setxattr(_path: &CStr, _name: &CStr, _value: &[u8]) -> IoResult<()> {
+            Err(IoErrorKind::Unsupported.into())
+        }
+    }
+}
+
+/// Convert the last OS error, mapping "no such attribute" to `NotFound` and "not supported" to `Unsupported`
+fn xattr_error() -> IoError {
+    let error = IoError::last_os_error();
+    match error.raw_os_error() {
+        Some(code) if code == ERR_NO_ATTR => IoErrorKind::NotFound.into(),
+        Some(libc::ENOTSUP) => IoErrorKind::Unsupported.into(),
+        Some(libc::ERANGE) => IoErrorKind::InvalidData.into(),
+        _ => error,
+    }
+}
+
+/// Convert a path and an attribute name to C strings
+fn xattr_args(path: &Path, name: &str) -> IoResult<(CString, CString)> {
+    match (CString::new(path.as_os_str().as_bytes()), CString::new(name)) {
+        (Ok(path), Ok(name)) => Ok((path, name)),
+        _ => Err(IoErrorKind::InvalidInput.into()),
+    }
+}
+
+/// Read the value of the given extended attribute, returns `None` if the attribute does not exist
+pub fn read_xattr(path: &Path, name: &str) -> IoResult<Option<Vec<u8>>> {
+    let (path, name) = xattr_args(path, name)?;
+    let mut buffer = vec![0u8; MAX_XATTR_SIZE];
+    match sys_getxattr(&path, &name, &mut buffer) {
+        Ok(length) => {
+            buffer.truncate(length);
+            Ok(Some(buffer))
+        }
+        Err(error) if error.kind() == IoErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Write the value of the given extended attribute, the attribute is created or replaced
+pub fn write_xattr(path: &Path, name: &str, value: &[u8]) -> IoResult<()> {
+    let (path, name) = xattr_args(path, name)?;
+    sys_setxattr(&path, &name, value)
+}
@@ -3,9 +3,12 @@
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
 use std::{
-    fs::Metadata,
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::{File, Metadata},
+    io::{ErrorKind as IoErrorKind, Result as IoResult},
     os::windows::io::{AsRawHandle, RawHandle},
-    path::Path,
+    path::{Path, PathBuf},
     sync::LazyLock,
 };
 use windows_sys::Win32::Storage::FileSystem::{GetFileType, FILE_TYPE_PIPE};
@@ -23,12 +26,20 @@ pub fn is_pipe(data_source: &DataSource) -> bool {
     file_type == FILE_TYPE_PIPE
 }
 
+/// Check whether the given file refers to the standard input stream (not supported on Windows)
+#[inline(always)]
+pub fn is_stdin_alias(_file: &File) -> bool {
+    false
+}
+
 impl AsRawHandle for DataSource<'_> {
     #[inline(always)]
     fn as_raw_handle(&self) -> RawHandle {
         match self {
             DataSource::File(file) => file.as_raw_handle(),
-            DataSource::Stream(stream) => stream.0.as_raw_handle(),
+            DataSource::Stream(stream) => stream.as_raw_handle(),
+            #[cfg(feature = "with-tar")]
+            DataSource::Member(member, _) => member.get_ref().as_raw_handle(),
         }
     }
 }
@@ -40,7 +51,7 @@ impl AsRawHandle for DataSource<'_> {
 #[derive(Clone, Copy)]
 pub struct DevId;
 
-#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct FileId;
 
 impl FileId {
@@ -55,7 +66,48 @@ impl FileId {
     }
 }
 
+impl Display for FileId {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> FmtResult {
+        unreachable!()
+    }
+}
+
 #[inline]
 pub fn file_id(_meta: Metadata) -> Option<FileId> {
     None
 }
+
+// ---------------------------------------------------------------------------
+// Path functions
+// ---------------------------------------------------------------------------
+
+/// Convert raw bytes to a path, the bytes are expected to be UTF-8 encoded
+#[inline]
+pub fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Convert a path to UTF-8 encoded bytes, unpaired surrogates are replaced by `U+FFFD`
+#[inline]
+pub fn path_to_bytes(path: &Path) -> Cow<'_, [u8]> {
+    match path.to_string_lossy() {
+        Cow::Borrowed(str) => Cow::Borrowed(str.as_bytes()),
+        Cow::Owned(string) => Cow::Owned(string.into_bytes()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Extended attribute functions
+// ---------------------------------------------------------------------------
+
+/// Extended attributes are not currently supported on this platform
+#[inline]
+pub fn read_xattr(_path: &Path, _name: &str) -> IoResult<Option<Vec<u8>>> {
+    Err(IoErrorKind::Unsupported.into())
+}
+
+/// Extended attributes are not currently supported on this platform
+#[inline]
+pub fn write_xattr(_path: &Path, _name: &str, _value: &[u8]) -> IoResult<()> {
+    Err(IoErrorKind::Unsupported.into())
+}
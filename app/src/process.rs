@@ -3,72 +3,59 @@
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
 use crossbeam_channel::{bounded, Receiver, Sender};
-use hex::encode_to_slice;
+use hex::{encode as encode_hex, encode_to_slice};
 use imbl::{ordset, OrdSet};
 use sponge_hash_aes256::DEFAULT_DIGEST_SIZE;
 use std::{
     borrow::Cow,
+    collections::BTreeSet,
     fs::{self, DirEntry, Metadata},
-    io::{Result as IoResult, Write},
+    io::{BufRead, BufReader, Read, Result as IoResult, Write},
     iter,
     num::NonZeroUsize,
     path::{Component, Path, PathBuf},
     str::from_utf8_unchecked,
-    sync::LazyLock,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        LazyLock, Mutex, OnceLock,
+    },
     thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 use tinyvec::TinyVec;
 
 use crate::{
-    arguments::Args,
-    common::{get_capacity, increment, Aborted, Digest, ExitStatus, Flag, TinyVecEx},
-    digest::{compute_digest, Error as DigestError},
-    environment::Env,
-    io::{DataSource, Error as IoError, OutStream},
-    os::{file_id, DevId, FileId, STDIN_NAME},
-    print_error, print_warn,
-    thread_pool::{detect_thread_count, Cancelled, TaskResult, ThreadPool},
+    arguments::{Args, PlainField},
+    common::{
+        escape_name, format_bytes, get_capacity, increment, needs_escape, write_failed, Aborted, Digest, ExitStatus, FileMeta, Flag, JsonStr, MetaField,
+        TinyVecEx, ALGORITHM_TAG, HEADER_PREFIX, ROUNDS_SEPARATOR, XATTR_NAME,
+    },
+    digest::{compute_digest, compute_listing_digest, compute_record_digest, compute_tree_digest, describe_parameters, Error as DigestError},
+    environment::{DirWalk, Env},
+    error::{Error, ErrorKind, XattrError},
+    io::{DataSource, OutStream},
+    os::{file_id, path_from_bytes, path_to_bytes, write_xattr, DevId, FileId, STDIN_NAME},
+    print_error, print_info, print_warn,
+    thread_pool::{detect_thread_count, limit_thread_count, Cancelled, TaskResult, ThreadPool},
+    verify::read_file_names,
 };
 
+#[cfg(feature = "with-tar")]
+use crate::archive::{is_archive, list_members};
+
 type FsId = Option<DevId>;
 type IdSet = OrdSet<FileId>;
 type Count = NonZeroUsize;
 
-// ---------------------------------------------------------------------------
-// Error Type
-// ---------------------------------------------------------------------------
-
-/// Error type for processing file tasks
-#[derive(Debug)]
-enum Error {
-    NotFound(PathBuf),
-    WalkOpen(PathBuf),
-    WalkRead(PathBuf),
-    ObjIsDir(PathBuf),
-    FileOpen(PathBuf),
-    FileRead(PathBuf),
-}
-
-impl Error {
-    #[inline]
-    fn from_io_error(error: IoError, path: PathBuf) -> Self {
-        match error {
-            IoError::AccessDenied => Error::FileOpen(path),
-            IoError::FileNotFound => Error::NotFound(path),
-            IoError::IsADirectory => Error::ObjIsDir(path),
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Utility functions
 // ---------------------------------------------------------------------------
 
-/// Get file type from a directory entry (will resolve symlinks, if necessary)
+/// Get file type from a directory entry (will resolve symlinks, if `follow_symlinks` is set)
 #[inline]
-fn get_metadata(dir_entry: &DirEntry) -> Option<Metadata> {
+fn get_metadata(dir_entry: &DirEntry, follow_symlinks: bool) -> Option<Metadata> {
     match dir_entry.metadata() {
-        Ok(meta_data) => match meta_data.is_symlink() {
+        Ok(meta_data) => match meta_data.is_symlink() && follow_symlinks {
             false => Some(meta_data),
             true => fs::metadata(dir_entry.path()).ok(),
         },
@@ -76,6 +63,17 @@ fn get_metadata(dir_entry: &DirEntry) -> Option<Metadata> {
     }
 }
 
+/// Check if the given directory entry matches any of the exclusion patterns
+#[inline]
+fn is_excluded(dir_entry: &DirEntry, args: &Args) -> bool {
+    if args.exclude.is_empty() {
+        return false;
+    }
+
+    let (file_name, full_path) = (dir_entry.file_name(), dir_entry.path());
+    args.exclude.iter().any(|pattern| pattern.matches_path(Path::new(&file_name)) || pattern.matches_path(&full_path))
+}
+
 /// Appends a directory id to the set of visited directories
 #[inline]
 fn append(visited: &'_ IdSet, file_id: Option<FileId>) -> Cow<'_, IdSet> {
@@ -92,6 +90,48 @@ fn path(dir_entry: &DirEntry, fname_only: bool) -> PathBuf {
     }
 }
 
+/// Key that identifies a file for the purpose of detecting duplicate inputs
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum FileKey {
+    Id(FileId),
+    Path(PathBuf),
+}
+
+/// The set of files that have been processed so far (only used in `--dedup` mode)
+static PROCESSED_FILES: Mutex<BTreeSet<FileKey>> = Mutex::new(BTreeSet::new());
+
+/// Record the file as processed, returns `false` if the file has already been processed before
+///
+/// The file is identified by its unique file id, if available, so that hard links to the same file are detected; otherwise, falls back to the canonicalized path.
+fn record_file(file_name: &Path) -> bool {
+    let file_key = match fs::metadata(file_name).ok().and_then(file_id) {
+        Some(unique_id) => FileKey::Id(unique_id),
+        None => FileKey::Path(fs::canonicalize(file_name).unwrap_or_else(|_| file_name.to_owned())),
+    };
+    PROCESSED_FILES.lock().unwrap().insert(file_key)
+}
+
+/// Check whether the file is a duplicate of a file that has been queued before (only in `--dedup` mode)
+///
+/// This is done while the input files are being queued, i.e., in the order in which they were specified or found, so that the *first* path referring to a file is always the one that is processed, even in multi-threaded mode.
+#[inline]
+fn check_duplicate(file_name: PathBuf, args: &Args) -> PathResult {
+    if args.dedup && !record_file(&file_name) {
+        Err(Error::Duplicate(file_name))
+    } else {
+        Ok(file_name)
+    }
+}
+
+/// The set of files that have already been processed in a previous run (only used in `--continue-from` mode)
+static COMPLETED_FILES: OnceLock<BTreeSet<PathBuf>> = OnceLock::new();
+
+/// Check if the file has already been processed in a previous run, so that it can be skipped
+#[inline]
+fn is_completed(file_name: &Path) -> bool {
+    COMPLETED_FILES.get().is_some_and(|file_names| file_names.contains(file_name))
+}
+
 /// Check if the computation has been cancelled
 macro_rules! check_cancelled {
     ($halt:ident) => {
@@ -126,27 +166,150 @@ fn exit_status(file_errors: u64, args: &Args) -> ExitStatus {
 // Print results
 // ---------------------------------------------------------------------------
 
+/// Check whether the file name can be printed safely, i.e., without producing a line that can not be parsed again
+///
+/// A file name that contains a line break is *only* safe, if it is escaped (`--quote`), if lines are separated by NULL characters (`--null`), or if the name is not printed at all.
+fn is_name_printable(file_name: &Path, args: &Args) -> bool {
+    let prints_name = !(args.ndjson || args.tree_digest || (args.plain && !args.plain_fields.contains(&PlainField::Name)));
+    args.null || args.quote || !prints_name || !path_to_bytes(file_name).contains(&b'\n')
+}
+
+/// Compute the path of `path` relative to `base_dir`, both of which must be absolute; returns `None` if they have no common root
+fn relative_path(path: &Path, base_dir: &Path) -> Option<PathBuf> {
+    let (mut path_iter, mut base_iter) = (path.components().peekable(), base_dir.components().peekable());
+    let mut common = 0usize;
+
+    while path_iter.next_if(|component| base_iter.next_if_eq(component).is_some()).is_some() {
+        common += 1usize;
+    }
+
+    (common > 0usize).then(|| base_iter.map(|_| Component::ParentDir).chain(path_iter).collect())
+}
+
+/// Rewrite the file name relative to the base directory, if '--relative-to' is in effect
+///
+/// The parent directory is canonicalized, like the base directory, but the file name itself is *not*, so that symbolic links are retained. If the parent directory can not be resolved, the original file name is kept.
+fn relative_name<'a>(file_name: &'a Path, args: &Args) -> Cow<'a, Path> {
+    let Some(base_dir) = args.relative_to.as_deref().filter(|_| !STDIN_NAME.eq(file_name)) else {
+        return Cow::Borrowed(file_name);
+    };
+    let (Some(parent), Some(name)) = (file_name.parent(), file_name.file_name()) else {
+        return Cow::Borrowed(file_name);
+    };
+    let parent = if parent.as_os_str().is_empty() { *CURRENT_DIR } else { parent };
+    match fs::canonicalize(parent).ok().and_then(|parent| relative_path(&parent.join(name), base_dir)) {
+        Some(relative_name) => Cow::Owned(relative_name),
+        None => Cow::Borrowed(file_name),
+    }
+}
+
 /// Print a single digest
 #[inline]
-fn print_digest(output: &mut dyn Write, file_name: &Path, digest: &Digest, args: &Args) -> IoResult<()> {
+fn print_digest(output: &mut dyn Write, file_name: &Path, digest: &Digest, total_bytes: u64, file_meta: &FileMeta, args: &Args) -> IoResult<()> {
+    let file_name = &*relative_name(file_name, args);
     let hex_length = digest.len().checked_mul(2usize).unwrap();
     let mut hex_buffer: TinyVec<[u8; 2usize * DEFAULT_DIGEST_SIZE]> = TinyVec::with_length(hex_length);
 
     encode_to_slice(digest.as_slice(), hex_buffer.as_mut_slice()).unwrap();
     let hex_string = unsafe { from_utf8_unchecked(hex_buffer.as_slice()) };
 
-    if args.null {
-        if args.plain {
-            write!(output, "{}\0", hex_string)?;
+    if args.ndjson {
+        write!(output, r#"{{"file":"{}","digest":"{}""#, JsonStr(&file_name.to_string_lossy()), hex_string)?;
+        if args.with_meta {
+            for (key, value) in [("size", file_meta.size), ("mtime", file_meta.mtime)] {
+                match value {
+                    Some(value) => write!(output, r#","{}":{}"#, key, value)?,
+                    None => write!(output, r#","{}":null"#, key)?,
+                }
+            }
+        }
+        if args.show_rounds {
+            write!(output, r#","rounds":{}"#, args.rounds())?;
+        }
+        writeln!(output, "}}")?;
+    } else {
+        let line_end = if args.null { b"\0" } else { b"\n" };
+        let name_bytes = path_to_bytes(file_name);
+        let name_bytes = if args.quote && needs_escape(&name_bytes) {
+            output.write_all(b"\\")?;
+            Cow::Owned(escape_name(&name_bytes))
+        } else {
+            name_bytes
+        };
+        if args.tag {
+            let digest_bits = digest.len().checked_mul(u8::BITS as usize).unwrap();
+            write!(output, "{}-{} (", ALGORITHM_TAG, digest_bits)?;
+            output.write_all(&name_bytes)?;
+            write!(output, ") = {}", hex_string)?;
+            write_rounds(output, args)?;
+        } else if args.plain {
+            if args.plain_fields.is_empty() {
+                output.write_all(hex_string.as_bytes())?;
+            } else {
+                for (index, field) in args.plain_fields.iter().enumerate() {
+                    if index > 0usize {
+                        output.write_all(b" ")?;
+                    }
+                    match field {
+                        PlainField::Hex => output.write_all(hex_string.as_bytes())?,
+                        PlainField::Size => write!(output, "{}", total_bytes)?,
+                        PlainField::Name => output.write_all(&name_bytes)?,
+                    }
+                }
+            }
+        } else if args.with_meta {
+            output.write_all(hex_string.as_bytes())?;
+            write_rounds(output, args)?;
+            write!(output, " {} {} ", MetaField(file_meta.size), MetaField(file_meta.mtime))?;
+            output.write_all(&name_bytes)?;
         } else {
-            write!(output, "{} {}\0", hex_string, file_name.to_string_lossy())?;
+            output.write_all(hex_string.as_bytes())?;
+            write_rounds(output, args)?;
+            output.write_all(b" ")?;
+            output.write_all(&name_bytes)?;
         }
-    } else if args.plain {
-        writeln!(output, "{}", hex_string)?;
+        output.write_all(line_end)?;
+    }
+
+    if args.flush || args.ndjson {
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Append the number of permutation rounds to the digest, if the `--show-rounds` option is in effect
+#[inline]
+fn write_rounds(output: &mut dyn Write, args: &Args) -> IoResult<()> {
+    if args.show_rounds {
+        write!(output, "{}{}", char::from(ROUNDS_SEPARATOR), args.rounds())?;
+    }
+    Ok(())
+}
+
+/// Print a single path, as is done in "dry run" mode
+#[inline]
+fn print_path(output: &mut dyn Write, file_name: &Path, args: &Args) -> IoResult<()> {
+    let file_name = &*relative_name(file_name, args);
+    if args.ndjson {
+        writeln!(output, r#"{{"file":"{}"}}"#, JsonStr(&file_name.to_string_lossy()))?;
     } else {
-        writeln!(output, "{} {}", hex_string, file_name.to_string_lossy())?;
+        output.write_all(&path_to_bytes(file_name))?;
+        output.write_all(if args.null { b"\0" } else { b"\n" })?;
+    }
+
+    if args.flush || args.ndjson {
+        output.flush()?;
     }
 
+    Ok(())
+}
+
+/// Print the header line that describes the algorithm and its parameters
+fn print_header(output: &mut dyn Write, args: &Args) -> IoResult<()> {
+    write!(output, "{}{}", HEADER_PREFIX, describe_parameters(args))?;
+    output.write_all(if args.null { b"\0" } else { b"\n" })?;
+
     if args.flush {
         output.flush()?;
     }
@@ -156,19 +319,68 @@ fn print_digest(output: &mut dyn Write, file_name: &Path, digest: &Digest, args:
 
 /// Print result to output
 #[inline]
-fn print_result(output: &mut OutStream, digest_result: &DigestResult, args: &Args) -> bool {
+fn print_result(output: &mut OutStream, digest_result: &DigestResult, args: &Args) -> IoResult<()> {
     match digest_result {
-        Ok(digest) => print_digest(output.out(), &digest.1, &digest.0, args).is_ok(),
+        Ok(digest) => print_digest(output.out(), &digest.1, &digest.0, digest.2, &digest.3, args),
         Err(error) => {
             match error {
-                Error::FileOpen(path) => print_error!(output, args, "Failed to open input file: {:?}", path),
-                Error::FileRead(path) => print_error!(output, args, "Failed to read input file: {:?}", path),
-                Error::NotFound(path) => print_error!(output, args, "Input file not found: {:?}", path),
-                Error::ObjIsDir(path) => print_error!(output, args, "Input file is a directory: {:?}", path),
-                Error::WalkOpen(path) => print_error!(output, args, "Failed to open directory: {:?}", path),
-                Error::WalkRead(path) => print_error!(output, args, "Failed to read directory: {:?}", path),
+                Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) => {
+                    if args.warn {
+                        print_warn!(output, args, "{}", error)
+                    }
+                }
+                Error::LimitReached(_) => print_info!(output, args, "{}", error),
+                _ => print_error!(output, args, "{}", error),
             }
-            true
+            Ok(())
+        }
+    }
+}
+
+/// Print result to output, or defer the digest for the "tree digest", if that mode is enabled
+#[inline]
+fn print_or_defer(output: &mut OutStream, digest_result: &DigestResult, deferred: &mut Vec<(Vec<u8>, Digest)>, args: &Args) -> IoResult<()> {
+    match digest_result {
+        Ok((digest, file_name, _, _, _)) if args.tree_digest => {
+            deferred.push((path_to_bytes(file_name).into_owned(), digest.clone()));
+            Ok(())
+        }
+        _ => print_result(output, digest_result, args),
+    }
+}
+
+/// Print the single "tree digest" that was computed from all deferred (path, digest) pairs, in sorted order
+fn print_tree_digest(output: &mut OutStream, mut deferred: Vec<(Vec<u8>, Digest)>, digest_size: usize, file_errors: u64, args: &Args) -> IoResult<()> {
+    if file_errors > u64::MIN {
+        print_error!(output, args, "Error: The tree digest was not computed, because some file(s) could not be processed!");
+        return Ok(());
+    }
+
+    deferred.sort_unstable_by(|entry_a, entry_b| entry_a.0.cmp(&entry_b.0));
+
+    let mut digest: Digest = TinyVec::with_length(digest_size);
+    compute_listing_digest(deferred.iter().map(|(path, digest)| (path.as_slice(), digest.as_slice())), digest.as_mut_slice(), args);
+
+    let mut hex_buffer: TinyVec<[u8; 2usize * DEFAULT_DIGEST_SIZE]> = TinyVec::with_length(digest_size.checked_mul(2usize).unwrap());
+    encode_to_slice(digest.as_slice(), hex_buffer.as_mut_slice()).unwrap();
+
+    let out = output.out();
+    out.write_all(hex_buffer.as_slice())?;
+    out.write_all(if args.null { b"\0" } else { b"\n" })?;
+    out.flush()
+}
+
+/// Print the time that was spent on hashing a single file, if it has been measured
+#[inline]
+fn print_timing(output: &mut OutStream, digest_result: &DigestResult, args: &Args) {
+    if let Ok((_, file_name, total_bytes, _, Some(timing))) = digest_result {
+        let elapsed = timing.elapsed.as_secs_f64();
+        let (rate_value, rate_unit) = format_bytes(*total_bytes as f64 / elapsed.max(f64::EPSILON));
+        match timing.worker_id {
+            Some(worker_id) => {
+                print_info!(output, args, "Time: {:?} [{:.6} seconds, {:.1} {}/s, thread #{}]", file_name, elapsed, rate_value, rate_unit, worker_id)
+            }
+            None => print_info!(output, args, "Time: {:?} [{:.6} seconds, {:.1} {}/s]", file_name, elapsed, rate_value, rate_unit),
         }
     }
 }
@@ -185,32 +397,79 @@ fn print_summary(output: &mut OutStream, file_errors: u64, args: &Args) {
     }
 }
 
+/// Print the total number of files and bytes processed
+#[inline]
+fn print_totals(output: &mut OutStream, file_count: u64, byte_count: u64, start_time: Instant, args: &Args) {
+    if args.summary {
+        print_info!(output, args, "Summary: {} file(s), {} bytes, {:.3} seconds", file_count, byte_count, start_time.elapsed().as_secs_f64());
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Compute file digest
 // ---------------------------------------------------------------------------
 
-type DigestResult = Result<(Digest, PathBuf), Error>;
+type DigestResult = Result<(Digest, PathBuf, u64, FileMeta, Option<Timing>), Error>;
+
+/// The time that was spent on hashing a single file, and the worker thread that did the work
+struct Timing {
+    elapsed: Duration,
+    worker_id: Option<usize>,
+}
+
+/// Counter for assigning a unique id to each worker thread
+static NEXT_WORKER_ID: AtomicUsize = AtomicUsize::new(1usize);
+
+fn compute_source_digest(source: &mut DataSource, digest_out: &mut [u8], n_threads: Count, args: &Args, halt: &Flag) -> Result<u64, DigestError> {
+    if args.tree {
+        compute_tree_digest(source, digest_out, n_threads, args, halt)
+    } else {
+        compute_digest(source, digest_out, args.rounds(), args, halt)
+    }
+}
 
-fn compute_file_digest(file_name: PathBuf, digest_size: usize, args: &Args, halt: &Flag) -> Result<DigestResult, Cancelled> {
-    match DataSource::from_path(&file_name) {
+fn compute_file_digest(
+    file_name: PathBuf,
+    digest_size: usize,
+    n_threads: Count,
+    worker_id: Option<usize>,
+    args: &Args,
+    halt: &Flag,
+) -> Result<DigestResult, Cancelled> {
+    if !is_name_printable(&file_name, args) {
+        return Ok(Err(Error::LineBreak(file_name)));
+    }
+    let start_time = args.time.then(Instant::now);
+    match DataSource::from_input(&file_name, args) {
         Ok(mut source) => {
             let mut digest = TinyVec::with_length(digest_size);
-            match compute_digest(&mut source, digest.as_mut_slice(), args, halt) {
-                Ok(_) => Ok(Ok((digest, file_name))),
-                Err(DigestError::IoError) => Ok(Err(Error::FileRead(file_name))),
+            match compute_source_digest(&mut source, digest.as_mut_slice(), n_threads, args, halt) {
+                Ok(total_bytes) => {
+                    if args.write_xattr {
+                        if let Err(error) = write_xattr(&file_name, XATTR_NAME, encode_hex(&digest).as_bytes()) {
+                            return Ok(Err(Error::Xattr(XattrError::from_io_error(&error, XattrError::WriteErr), file_name)));
+                        }
+                    }
+                    let file_meta = if args.with_meta { source.file_meta() } else { FileMeta::default() };
+                    let timing = start_time.map(|start_time| Timing { elapsed: start_time.elapsed(), worker_id });
+                    Ok(Ok((digest, file_name, total_bytes, file_meta, timing)))
+                }
+                Err(DigestError::IoError) => Ok(Err(Error::InputFile(ErrorKind::FileRead(file_name)))),
+                Err(DigestError::TooLarge) => Ok(Err(Error::InputFile(ErrorKind::TooLarge(file_name, args.max_bytes.unwrap())))),
                 Err(DigestError::Cancelled) => Err(Cancelled),
             }
         }
-        Err(error) => Ok(Err(Error::from_io_error(error, file_name))),
+        Err(error) => Ok(Err(Error::InputFile(ErrorKind::from_io_error(error, file_name)))),
     }
 }
 
 fn compute_thread(path_rx: &Receiver<PathResult>, digest_tx: &Sender<DigestResult>, digest_size: usize, args: &Args, halt: &Flag) -> TaskResult {
+    let worker_id = NEXT_WORKER_ID.fetch_add(1usize, Ordering::Relaxed);
     while let Ok(path_result) = path_rx.recv() {
         check_cancelled!(halt);
         match path_result {
             Ok(path) => {
-                let digest_result = compute_file_digest(path, digest_size, args, halt).or(Err(Cancelled))?;
+                let digest_result = compute_file_digest(path, digest_size, Count::MIN, Some(worker_id), args, halt).or(Err(Cancelled))?;
                 let is_success = digest_result.is_ok();
                 digest_tx.send(digest_result)?;
                 if !(is_success || args.keep_going) {
@@ -231,11 +490,56 @@ fn compute_thread(path_rx: &Receiver<PathResult>, digest_tx: &Sender<DigestResul
 /// Path result type
 type PathResult = Result<PathBuf, Error>;
 
+/// The number of files that have been sent to the processing queue (only used in `--limit-files` mode)
+static ENQUEUED_FILES: AtomicUsize = AtomicUsize::new(0usize);
+
+/// The default maximum number of sub-directories that are queued per directory in breadth-first mode
+const DEFAULT_QUEUE_LIMIT: usize = 4096usize;
+
 /// The "current" directory
 static CURRENT_DIR: LazyLock<&Path> = LazyLock::new(|| Path::new(&Component::CurDir));
 
+/// Send the given file to the processing queue, unless the maximum number of files has been reached
+///
+/// If the limit has been reached, a note is sent *instead* and the walk is stopped, by returning `Cancelled`.
+fn enqueue(path_tx: &Sender<PathResult>, file_name: PathBuf, args: &Args) -> Result<(), Cancelled> {
+    if let Some(limit) = args.limit_files {
+        if ENQUEUED_FILES.fetch_add(1usize, Ordering::Relaxed) >= limit.get() {
+            path_tx.send(Err(Error::LimitReached(limit)))?;
+            return Err(Cancelled);
+        }
+    }
+    #[cfg(feature = "with-tar")]
+    if args.into_archives && is_archive(&file_name) && file_name.is_file() {
+        return enqueue_members(path_tx, file_name, args);
+    }
+    path_tx.send(check_duplicate(file_name, args))?;
+    Ok(())
+}
+
+/// Send all members of the given archive to the processing queue, *instead* of the archive itself
+#[cfg(feature = "with-tar")]
+fn enqueue_members(path_tx: &Sender<PathResult>, file_name: PathBuf, args: &Args) -> Result<(), Cancelled> {
+    match check_duplicate(file_name, args).map(|archive_path| (list_members(&archive_path), archive_path)) {
+        Ok((Ok(members), _)) => members.into_iter().try_for_each(|member| path_tx.send(Ok(member)))?,
+        Ok((Err(_), archive_path)) => path_tx.send(Err(Error::InputFile(ErrorKind::FileRead(archive_path))))?,
+        Err(error) => path_tx.send(Err(error))?,
+    }
+    Ok(())
+}
+
 /// Iterate all files and sub-directories in a directory
-fn do_iterate(path_tx: &Sender<PathResult>, dir_name: &Path, fs_id: FsId, visited: &IdSet, bfs: bool, args: &Args, halt: &Flag) -> Result<bool, Cancelled> {
+///
+/// Up to `queue_limit` sub-directories are queued, in order to be walked *after* all files in the directory (breadth-first). Any further sub-directories are walked immediately (depth-first), so that the memory usage remains bounded. A `queue_limit` of zero results in a pure depth-first search.
+fn do_iterate(
+    path_tx: &Sender<PathResult>,
+    dir_name: &Path,
+    fs_id: FsId,
+    visited: &IdSet,
+    queue_limit: usize,
+    args: &Args,
+    halt: &Flag,
+) -> Result<bool, Cancelled> {
     let cwd = CURRENT_DIR.eq(dir_name);
 
     let dir_iter = match fs::read_dir(dir_name) {
@@ -246,26 +550,49 @@ fn do_iterate(path_tx: &Sender<PathResult>, dir_name: &Path, fs_id: FsId, visite
         }
     };
 
+    let dir_iter: Box<dyn Iterator<Item = IoResult<DirEntry>>> = if args.sorted {
+        let mut entries: Vec<_> = dir_iter.collect();
+        entries.sort_by_cached_key(|element| element.as_ref().ok().map(DirEntry::file_name));
+        Box::new(entries.into_iter())
+    } else {
+        Box::new(dir_iter)
+    };
+
     let mut dir_queue: TinyVec<[_; 96usize]> = TinyVec::new();
 
     for element in dir_iter {
         match element {
             Ok(dir_entry) => {
                 check_cancelled!(halt);
-                let meta_data = get_metadata(&dir_entry);
-                if meta_data.as_ref().is_some_and(|meta| meta.is_dir()) {
+                if is_excluded(&dir_entry, args) {
+                    continue;
+                }
+                let meta_data = get_metadata(&dir_entry, args.follow_symlinks);
+                if meta_data.as_ref().is_some_and(|meta| meta.is_symlink()) {
+                    continue; /* do not follow symlinks, unless requested */
+                } else if meta_data.as_ref().is_some_and(|meta| meta.is_dir()) {
                     if args.recursive {
                         let unique_id = file_id(unsafe { meta_data.unwrap_unchecked() });
-                        if unique_id.is_none_or(|uid| (args.cross_dev || fs_id.is_none_or(|dev| uid.same_dev(dev))) && !visited.contains(&uid)) {
-                            if bfs {
+                        if let Some(uid) = unique_id.filter(|uid| visited.contains(uid)) {
+                            path_tx.send(Err(Error::IsCycle(path(&dir_entry, cwd), uid)))?;
+                        /* directory was already visited */
+                        } else if unique_id.is_none_or(|uid| args.cross_dev || fs_id.is_none_or(|dev| uid.same_dev(dev))) {
+                            if dir_queue.len() < queue_limit {
                                 dir_queue.push((unique_id, path(&dir_entry, cwd)));
-                            } else if !(do_iterate(path_tx, &path(&dir_entry, cwd), fs_id, &append(visited, unique_id), bfs, args, halt)? || args.keep_going) {
+                            } else if !(do_iterate(path_tx, &path(&dir_entry, cwd), fs_id, &append(visited, unique_id), queue_limit, args, halt)?
+                                || args.keep_going)
+                            {
                                 return Ok(false);
                             }
                         }
                     }
-                } else if args.all || meta_data.is_none_or(|meta| meta.is_file()) {
-                    path_tx.send(Ok(path(&dir_entry, cwd)))?;
+                } else if args.all || meta_data.as_ref().is_none_or(|meta| meta.is_file()) {
+                    let file_name = path(&dir_entry, cwd);
+                    if let Some(size) = meta_data.map(|meta| meta.len()).filter(|size| args.max_size.is_some_and(|limit| *size > limit)) {
+                        path_tx.send(Err(Error::Oversized(file_name, size)))?;
+                    } else if !is_completed(&file_name) {
+                        enqueue(path_tx, file_name, args)?;
+                    }
                 }
             }
             Err(_) => {
@@ -277,7 +604,7 @@ fn do_iterate(path_tx: &Sender<PathResult>, dir_name: &Path, fs_id: FsId, visite
 
     for (unique_id, dir_name) in dir_queue.into_iter() {
         check_cancelled!(halt);
-        if !(do_iterate(path_tx, &dir_name, fs_id, &append(visited, unique_id), bfs, args, halt)? || args.keep_going) {
+        if !(do_iterate(path_tx, &dir_name, fs_id, &append(visited, unique_id), queue_limit, args, halt)? || args.keep_going) {
             return Ok(false);
         }
     }
@@ -286,29 +613,60 @@ fn do_iterate(path_tx: &Sender<PathResult>, dir_name: &Path, fs_id: FsId, visite
 }
 
 /// Iterate a list of input files
-fn iterate_loop(input_files: impl Iterator<Item = PathBuf>, path_tx: &Sender<PathResult>, bfs: bool, args: &Args, halt: &Flag) -> TaskResult {
+fn iterate_loop(input_files: impl Iterator<Item = PathBuf>, path_tx: &Sender<PathResult>, queue_limit: usize, args: &Args, halt: &Flag) -> TaskResult {
     for file_name in input_files {
         check_cancelled!(halt);
         let directory = if args.dirs { fs::metadata(&file_name).ok().filter(|meta| meta.is_dir()) } else { None };
         if let Some(meta_data) = directory {
             let (visited, fs_id) = file_id(meta_data).map_or_else(Default::default, |uid| (ordset![uid], Some(uid.dev())));
-            if !(do_iterate(path_tx, &file_name, fs_id, &visited, bfs, args, halt)? || args.keep_going) {
+            if !(do_iterate(path_tx, &file_name, fs_id, &visited, queue_limit, args, halt)? || args.keep_going) {
                 break;
             }
-        } else {
-            path_tx.send(Ok(file_name))?;
+        } else if !is_completed(&file_name) {
+            enqueue(path_tx, file_name, args)?;
         }
     }
 
     Ok(())
 }
 
+/// Iterate the list of input files that is read from a file
+fn iterate_list(list_file: &Path, path_tx: &Sender<PathResult>, queue_limit: usize, args: &Args, halt: &Flag) -> TaskResult {
+    let source = if list_file.as_os_str() == "-" { DataSource::from_stdin() } else { DataSource::from_path(list_file) };
+    let mut source = match source {
+        Ok(source) => source,
+        Err(_) => {
+            path_tx.send(Err(Error::ListOpen(list_file.to_owned())))?;
+            return Ok(());
+        }
+    };
+
+    let (delimiter, mut read_error) = (if args.null { b'\0' } else { b'\n' }, false);
+    let records = BufReader::new(&mut source).split(delimiter).map_while(|record| record.inspect_err(|_| read_error = true).ok());
+    let file_names = records.map(|mut record| {
+        if (delimiter == b'\n') && record.ends_with(b"\r") {
+            record.pop();
+        }
+        record
+    });
+
+    iterate_loop(file_names.filter(|record| !record.is_empty()).map(path_from_bytes), path_tx, queue_limit, args, halt)?;
+
+    if read_error {
+        path_tx.send(Err(Error::ListRead(list_file.to_owned())))?;
+    }
+
+    Ok(())
+}
+
 /// Iterate thread entry point
-fn iterate_thread(path_tx: &Sender<PathResult>, bfs: bool, args: &Args, halt: &Flag) -> TaskResult {
-    if !args.files.is_empty() {
-        iterate_loop(args.files.iter().cloned(), path_tx, bfs, args, halt)
+fn iterate_thread(path_tx: &Sender<PathResult>, queue_limit: usize, args: &Args, halt: &Flag) -> TaskResult {
+    if let Some(list_file) = args.files_from.as_deref() {
+        iterate_list(list_file, path_tx, queue_limit, args, halt)
+    } else if !args.files.is_empty() {
+        iterate_loop(args.files.iter().cloned(), path_tx, queue_limit, args, halt)
     } else {
-        iterate_loop(iter::once(CURRENT_DIR.to_owned()), path_tx, bfs, args, halt)
+        iterate_loop(iter::once(CURRENT_DIR.to_owned()), path_tx, queue_limit, args, halt)
     }
 }
 
@@ -317,41 +675,56 @@ fn iterate_thread(path_tx: &Sender<PathResult>, bfs: bool, args: &Args, halt: &F
 // ---------------------------------------------------------------------------
 
 /// Start the file iteration thread, if it is needed
-fn start_iteration(bfs: bool, args: &'static Args, halt: &'static Flag) -> (Receiver<PathResult>, Option<JoinHandle<TaskResult>>) {
-    if args.dirs || (args.files.len() > 1024usize) {
+fn start_iteration(queue_limit: usize, args: &'static Args, halt: &'static Flag) -> (Receiver<PathResult>, Option<JoinHandle<TaskResult>>) {
+    if args.dirs || args.files_from.is_some() || args.expand_archives() || (args.files.len() > 1024usize) {
         let (path_tx, path_rx) = bounded::<PathResult>(256usize);
-        (path_rx, Some(thread::spawn(move || iterate_thread(&path_tx, bfs, args, halt))))
+        (path_rx, Some(thread::spawn(move || iterate_thread(&path_tx, queue_limit, args, halt))))
     } else {
         let (path_tx, path_rx) = bounded::<PathResult>(args.files.len());
-        args.files.iter().for_each(|path| path_tx.try_send(Ok(path.clone())).unwrap());
+        args.files.iter().filter(|path| !is_completed(path)).for_each(|path| path_tx.try_send(check_duplicate(path.clone(), args)).unwrap());
         (path_rx, None)
     }
 }
 
-fn process_mt(output: &mut OutStream, n_threads: Count, out_size: usize, bfs: bool, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+fn process_mt(
+    output: &mut OutStream,
+    n_threads: Count,
+    out_size: usize,
+    queue_limit: usize,
+    args: &'static Args,
+    halt: &'static Flag,
+) -> Result<ExitStatus, Aborted> {
     // Initialize channel
     let (digest_tx, digest_rx) = bounded::<DigestResult>(get_capacity(&n_threads));
 
     // Start the file iteration thread
-    let (path_rx, thread_handle) = start_iteration(bfs, args, halt);
+    let (path_rx, thread_handle) = start_iteration(queue_limit, args, halt);
 
     // Start the worker threads
     let thread_pool = ThreadPool::new(n_threads, move || compute_thread(&path_rx, &digest_tx, out_size, args, halt));
 
     // Initialize counters
-    let (mut file_errors, mut write_errors) = (u64::MIN, false);
+    let (mut file_errors, mut write_error) = (u64::MIN, None);
+    let (mut file_count, mut byte_count, start_time) = (u64::MIN, u64::MIN, Instant::now());
+    let mut deferred = Vec::new();
 
-    // Process all digest results
+    // Process all digest results (on cancellation, results that have already been computed are still printed)
     while let Ok(digest_result) = digest_rx.recv() {
-        break_cancelled!(halt);
-        if digest_result.is_err() {
-            increment(&mut file_errors);
+        let is_skipped = matches!(digest_result, Err(Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) | Error::LimitReached(_)));
+        match &digest_result {
+            Ok((_, _, total_bytes, _, _)) => {
+                increment(&mut file_count);
+                byte_count = byte_count.saturating_add(*total_bytes);
+            }
+            Err(Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) | Error::LimitReached(_)) => (),
+            Err(_) => increment(&mut file_errors),
         }
 
-        if !print_result(output, &digest_result, args) {
-            write_errors = true;
+        print_timing(output, &digest_result, args);
+        if let Err(error) = print_or_defer(output, &digest_result, &mut deferred, args) {
+            write_error = Some(error.kind());
             break;
-        } else if !(digest_result.is_ok() || args.keep_going) {
+        } else if !(digest_result.is_ok() || is_skipped || args.keep_going) {
             break;
         }
     }
@@ -370,50 +743,138 @@ fn process_mt(output: &mut OutStream, n_threads: Count, out_size: usize, bfs: bo
         panic!("Failed to join the worker thread: {error:?}")
     }
 
-    // Has the process been aborted?
+    // Has the process been aborted? Make sure that the partial results are written out!
     if is_aborted {
+        let _ = output.out().flush();
         return Err(Aborted);
     }
 
     // Have write any errors been encountered?
-    if write_errors {
-        print_error!(output, args, "Error: Failed to write to standard output stream!");
-        return Ok(ExitStatus::Failure);
+    if let Some(error_kind) = write_error {
+        return Ok(write_failed(output, error_kind, args));
+    }
+
+    // Print the "tree digest", if that mode is enabled
+    if args.tree_digest {
+        if let Err(error) = print_tree_digest(output, deferred, out_size, file_errors, args) {
+            return Ok(write_failed(output, error.kind(), args));
+        }
     }
 
     // Print warning if any file(s) have been skipped
     print_summary(output, file_errors, args);
+    print_totals(output, file_count, byte_count, start_time, args);
 
     // Check for errors
     Ok(exit_status(file_errors, args))
 }
 
-fn process_st(output: &mut OutStream, out_size: usize, bfs: bool, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+fn process_st(
+    output: &mut OutStream,
+    n_threads: Count,
+    out_size: usize,
+    queue_limit: usize,
+    args: &'static Args,
+    halt: &'static Flag,
+) -> Result<ExitStatus, Aborted> {
     // Start the file iteration thread
-    let (path_rx, thread_handle) = start_iteration(bfs, args, halt);
+    let (path_rx, thread_handle) = start_iteration(queue_limit, args, halt);
 
     // Initialize counters
-    let (mut file_errors, mut write_errors) = (u64::MIN, false);
+    let (mut file_errors, mut write_error) = (u64::MIN, None);
+    let (mut file_count, mut byte_count, start_time) = (u64::MIN, u64::MIN, Instant::now());
+    let mut deferred = Vec::new();
 
     // Process all files in the queue
     while let Ok(path_result) = path_rx.recv() {
         break_cancelled!(halt);
         let digest_result = match path_result {
-            Ok(path) => match compute_file_digest(path, out_size, args, halt) {
+            Ok(path) => match compute_file_digest(path, out_size, n_threads, None, args, halt) {
                 Ok(result) => result,
                 Err(Cancelled) => break, /* cancelled */
             },
             Err(error) => Err(error),
         };
 
-        if digest_result.is_err() {
-            increment(&mut file_errors);
+        let is_skipped = matches!(digest_result, Err(Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) | Error::LimitReached(_)));
+        match &digest_result {
+            Ok((_, _, total_bytes, _, _)) => {
+                increment(&mut file_count);
+                byte_count = byte_count.saturating_add(*total_bytes);
+            }
+            Err(Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) | Error::LimitReached(_)) => (),
+            Err(_) => increment(&mut file_errors),
+        }
+
+        print_timing(output, &digest_result, args);
+        if let Err(error) = print_or_defer(output, &digest_result, &mut deferred, args) {
+            write_error = Some(error.kind());
+            break;
+        } else if !(digest_result.is_ok() || is_skipped || args.keep_going) {
+            break;
         }
+    }
+
+    // Send shutdown signal to still running threads
+    drop(path_rx);
+    let is_aborted = halt.stop_process().is_err();
+
+    // Wait until the thread has completed
+    if let Some(Err(error)) = thread_handle.map(|handle| handle.join()) {
+        panic!("Failed to join the worker thread: {error:?}")
+    }
+
+    // Has the process been aborted? Make sure that the partial results are written out!
+    if is_aborted {
+        let _ = output.out().flush();
+        return Err(Aborted);
+    }
+
+    // Have write any errors been encountered?
+    if let Some(error_kind) = write_error {
+        return Ok(write_failed(output, error_kind, args));
+    }
+
+    // Print the "tree digest", if that mode is enabled
+    if args.tree_digest {
+        if let Err(error) = print_tree_digest(output, deferred, out_size, file_errors, args) {
+            return Ok(write_failed(output, error.kind(), args));
+        }
+    }
+
+    // Print warning if any file(s) have been skipped
+    print_summary(output, file_errors, args);
+    print_totals(output, file_count, byte_count, start_time, args);
+
+    // Check for errors
+    Ok(exit_status(file_errors, args))
+}
+
+fn process_dry(output: &mut OutStream, queue_limit: usize, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+    // Start the file iteration thread
+    let (path_rx, thread_handle) = start_iteration(queue_limit, args, halt);
+
+    // Initialize counters
+    let (mut file_errors, mut write_error) = (u64::MIN, None);
+
+    // Print all files in the queue, without computing any digests
+    while let Ok(path_result) = path_rx.recv() {
+        break_cancelled!(halt);
+        let (is_success, print_result) = match path_result {
+            Ok(path) => (true, print_path(output.out(), &path, args)),
+            Err(error) => {
+                let is_skipped = matches!(error, Error::Duplicate(_) | Error::IsCycle(_, _) | Error::Oversized(_, _) | Error::LimitReached(_));
+                if !is_skipped {
+                    increment(&mut file_errors);
+                }
+                (is_skipped, print_result(output, &Err(error), args))
+            }
+        };
 
-        if !print_result(output, &digest_result, args) {
-            write_errors = true;
+        if let Err(error) = print_result {
+            write_error = Some(error.kind());
             break;
-        } else if !(digest_result.is_ok() || args.keep_going) {
+        } else if !(is_success || args.keep_going) {
             break;
         }
     }
@@ -427,15 +888,15 @@ fn process_st(output: &mut OutStream, out_size: usize, bfs: bool, args: &'static
         panic!("Failed to join the worker thread: {error:?}")
     }
 
-    // Has the process been aborted?
+    // Has the process been aborted? Make sure that the partial results are written out!
     if is_aborted {
+        let _ = output.out().flush();
         return Err(Aborted);
     }
 
     // Have write any errors been encountered?
-    if write_errors {
-        print_error!(output, args, "Error: Failed to write to standard output stream!");
-        return Ok(ExitStatus::Failure);
+    if let Some(error_kind) = write_error {
+        return Ok(write_failed(output, error_kind, args));
     }
 
     // Print warning if any file(s) have been skipped
@@ -450,47 +911,184 @@ fn process_st(output: &mut OutStream, out_size: usize, bfs: bool, args: &'static
 // ---------------------------------------------------------------------------
 
 /// Process data from 'stdin' stream
-fn process_stdin(output: &mut OutStream, digest_size: usize, args: &Args, halt: &Flag) -> Result<ExitStatus, Cancelled> {
-    let mut stdin = DataSource::from_stdin();
+fn process_stdin(output: &mut OutStream, n_threads: Count, digest_size: usize, args: &Args, halt: &Flag) -> Result<ExitStatus, Cancelled> {
+    let mut stdin = match DataSource::from_stdin() {
+        Ok(stdin) => stdin,
+        Err(error) => {
+            print_error!(output, args, "{}", Error::InputFile(ErrorKind::from_io_error(error, STDIN_NAME.to_owned())));
+            return Ok(ExitStatus::Failure);
+        }
+    };
     let mut digest = TinyVec::with_length(digest_size);
+    let start_time = Instant::now();
 
-    match compute_digest(&mut stdin, digest.as_mut_slice(), args, halt) {
-        Ok(_) => match print_digest(output.out(), *STDIN_NAME, &digest, args) {
-            Ok(_) => Ok(ExitStatus::Success),
-            Err(_) => {
-                print_error!(output, args, "Error: Failed to write to standard output stream!");
-                Ok(ExitStatus::Failure)
+    match compute_source_digest(&mut stdin, digest.as_mut_slice(), n_threads, args, halt) {
+        Ok(total_bytes) => match print_digest(output.out(), *STDIN_NAME, &digest, total_bytes, &FileMeta { size: Some(total_bytes), mtime: None }, args) {
+            Ok(_) => {
+                print_totals(output, 1u64, total_bytes, start_time, args);
+                Ok(ExitStatus::Success)
             }
+            Err(error) => Ok(write_failed(output, error.kind(), args)),
         },
         Err(DigestError::IoError) => {
             print_error!(output, args, "Failed to read data from the standard input stream!");
             Ok(ExitStatus::Failure)
         }
+        Err(DigestError::TooLarge) => {
+            print_error!(output, args, "Input data from the standard input stream exceeds the limit of {} bytes!", args.max_bytes.unwrap());
+            Ok(ExitStatus::Failure)
+        }
         Err(DigestError::Cancelled) => Err(Cancelled),
     }
 }
 
+/// Read the next record, either of the given fixed size or up to the next newline, returns `false` at the end of the input
+///
+/// The terminating newline is *not* included in the record. The final record may be shorter than the given size.
+fn read_record<T: BufRead>(reader: &mut T, record: &mut Vec<u8>, record_size: Option<Count>) -> IoResult<bool> {
+    record.clear();
+    match record_size {
+        Some(record_size) => Ok(reader.by_ref().take(record_size.get() as u64).read_to_end(record)? > 0usize),
+        None => match reader.read_until(b'\n', record)? {
+            0usize => Ok(false),
+            _ => {
+                if record.last() == Some(&b'\n') {
+                    record.pop();
+                }
+                Ok(true)
+            }
+        },
+    }
+}
+
+/// Process the standard input stream as a sequence of records, printing a separate digest for each record
+fn process_stdin_records(output: &mut OutStream, digest_size: usize, args: &Args, halt: &Flag) -> Result<ExitStatus, Cancelled> {
+    let mut reader = match DataSource::from_stdin() {
+        Ok(stdin) => BufReader::new(stdin),
+        Err(error) => {
+            print_error!(output, args, "{}", Error::InputFile(ErrorKind::from_io_error(error, STDIN_NAME.to_owned())));
+            return Ok(ExitStatus::Failure);
+        }
+    };
+    let (mut record, mut digest) = (Vec::new(), TinyVec::with_length(digest_size));
+    let (mut record_count, mut total_bytes) = (0u64, 0u64);
+    let start_time = Instant::now();
+
+    loop {
+        if !halt.running() {
+            return Err(Cancelled);
+        }
+        match read_record(&mut reader, &mut record, args.record_size) {
+            Ok(true) => {
+                let record_bytes = record.len() as u64;
+                compute_record_digest(&record, digest.as_mut_slice(), args);
+                if let Err(error) = print_digest(output.out(), *STDIN_NAME, &digest, record_bytes, &FileMeta { size: Some(record_bytes), mtime: None }, args) {
+                    return Ok(write_failed(output, error.kind(), args));
+                }
+                increment(&mut record_count);
+                total_bytes = total_bytes.saturating_add(record_bytes);
+            }
+            Ok(false) => break,
+            Err(_) => {
+                print_error!(output, args, "Failed to read data from the standard input stream!");
+                return Ok(ExitStatus::Failure);
+            }
+        }
+    }
+
+    print_totals(output, record_count, total_bytes, start_time, args);
+    Ok(ExitStatus::Success)
+}
+
+/// Maximum number of directory entries to be examined by the fan-out probe
+const PROBE_MAX_ENTRIES: usize = 1024usize;
+
+/// Minimum average number of sub-directories per top-level directory to select breadth-first search
+const PROBE_MIN_FAN_OUT: usize = 8usize;
+
+/// Probe the fan-out of the top-level directories, returns `true` if breadth-first search should be used
+///
+/// Wide (shallow) trees are walked breadth-first, whereas narrow (deep) trees are walked depth-first.
+fn probe_fan_out(args: &Args) -> bool {
+    if !args.recursive || args.files_from.is_some() {
+        return true;
+    }
+
+    let top_level: Vec<&Path> = match args.files.is_empty() {
+        true => vec![*CURRENT_DIR],
+        false => args.files.iter().map(PathBuf::as_path).filter(|path| path.is_dir()).collect(),
+    };
+
+    if top_level.is_empty() {
+        return true;
+    }
+
+    let sub_dirs = top_level
+        .iter()
+        .filter_map(|path| fs::read_dir(path).ok())
+        .flat_map(|entries| entries.take(PROBE_MAX_ENTRIES))
+        .filter(|entry| entry.as_ref().is_ok_and(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir())))
+        .count();
+
+    sub_dirs >= top_level.len().saturating_mul(PROBE_MIN_FAN_OUT)
+}
+
 /// Process all input files
 pub fn process_files(output: &mut OutStream, digest_size: usize, args: &'static Args, env: &Env, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+    // Determine number of threads
+    let thread_count = detect_thread_count(args, env);
+
+    // Print the header line first, if requested
+    if args.self_describing {
+        if let Err(error) = print_header(output.out(), args) {
+            return Ok(write_failed(output, error.kind(), args));
+        }
+    }
+
     // Read input datat from the standard input stream?
-    if !args.dirs && args.files.is_empty() {
-        return process_stdin(output, digest_size, args, halt).map_err(|_| Aborted);
+    if !args.dirs && args.files.is_empty() && args.files_from.is_none() {
+        if args.dry_run {
+            return print_path(output.out(), *STDIN_NAME, args).map_or_else(|error| Ok(write_failed(output, error.kind(), args)), |_| Ok(ExitStatus::Success));
+        }
+        if args.block_stdin {
+            return process_stdin_records(output, digest_size, args, halt).map_err(|_| Aborted);
+        }
+        return process_stdin(output, thread_count, digest_size, args, halt).map_err(|_| Aborted);
     }
 
-    // Determine number of threads
-    let thread_count = detect_thread_count(args, env);
+    // Load the list of files that have already been processed in a previous run
+    if let Some(file_name) = args.continue_from.as_deref() {
+        match read_file_names(file_name, args) {
+            Ok(file_names) => COMPLETED_FILES.set(file_names).expect("Completed files already set!"),
+            Err(error) => {
+                print_error!(output, args, "{}", error);
+                return Ok(ExitStatus::Failure);
+            }
+        }
+    }
+
+    // Do not start more threads than there are files to be processed, if the number is known (except in "tree" mode)
+    let thread_count =
+        limit_thread_count(thread_count, (!(args.dirs || args.tree || args.expand_archives()) && args.files_from.is_none()).then_some(args.files.len()));
 
     // Determine directory walking strategy
-    let breadth_first = env.dirwalk_strategy.unwrap_or(true);
+    let breadth_first = env.dirwalk_strategy.unwrap_or(DirWalk::Auto).breadth_first(|| probe_fan_out(args));
+    let queue_limit = if breadth_first { env.dirwalk_queue_limit.map_or(DEFAULT_QUEUE_LIMIT, NonZeroUsize::get) } else { 0usize };
 
     // Check if process has been aborted
     if !halt.running() {
         return Err(Aborted);
     }
 
-    if thread_count > Count::MIN {
-        process_mt(output, thread_count, digest_size, breadth_first, args, halt)
+    // In "dry run" mode, the files are only listed, but not processed
+    if args.dry_run {
+        return process_dry(output, queue_limit, args, halt);
+    }
+
+    // In "tree" mode, the threads are used for hashing the leaves of a single file
+    if (thread_count > Count::MIN) && (!args.tree) {
+        process_mt(output, thread_count, digest_size, queue_limit, args, halt)
     } else {
-        process_st(output, digest_size, breadth_first, args, halt)
+        process_st(output, thread_count, digest_size, queue_limit, args, halt)
     }
 }
@@ -9,20 +9,23 @@ use rand_pcg::{
     Pcg64Mcg,
 };
 use rolling_median::Median;
-use sponge_hash_aes256::{SpongeHash256, DEFAULT_DIGEST_SIZE};
+use sponge_hash_aes256::{compute, SpongeHash256, DEFAULT_DIGEST_SIZE};
 use std::{
+    collections::HashSet,
     io::{Error as IoError, Write},
     num::NonZeroUsize,
+    thread,
     time::Instant,
 };
 
 use crate::{
     arguments::{Args, HEADER_LINE},
-    common::{Aborted, ExitStatus, Flag},
+    common::{format_bytes, Aborted, ExitStatus, Flag},
     digest::digest_equal,
     environment::Env,
     io::OutStream,
     print_error,
+    thread_pool::detect_thread_count,
 };
 
 // ---------------------------------------------------------------------------
@@ -45,20 +48,6 @@ impl From<IoError> for Error {
 // Utility functions
 // ---------------------------------------------------------------------------
 
-/// Convert the given "raw" number of bytes to the proper binary units
-fn format_bytes(mut value: f64) -> (f64, &'static str) {
-    const BIN_UNITS: [&str; 5usize] = ["Byte", "KiB", "MiB", "GiB", "TiB"];
-    const MAX_INDEX: usize = BIN_UNITS.len() - 1usize;
-
-    let mut index = 0usize;
-    while (index < MAX_INDEX) && (value + f64::EPSILON > 999.9) {
-        value /= 1024.0;
-        index += 1usize;
-    }
-
-    (value, BIN_UNITS[index])
-}
-
 /// Format the given digest as hex string
 #[cfg(debug_assertions)]
 fn format_digest<T: AsRef<[u8]>>(digest: T, hex_buffer: &mut [u8]) -> &str {
@@ -77,6 +66,70 @@ macro_rules! check_cancelled {
     };
 }
 
+// ---------------------------------------------------------------------------
+// Statistical test
+// ---------------------------------------------------------------------------
+
+// Seed value for the statistical test
+const STATS_SEEDVALUE: u64 = 18446744073709551427u64;
+
+// Message size for the statistical test, in bytes
+const STATS_MESSAGE_SIZE: usize = 64usize;
+
+// Number of bits in a digest
+const DIGEST_BITS: u32 = (DEFAULT_DIGEST_SIZE as u32) * u8::BITS;
+
+/// Count the number of bits that differ between the two digests
+fn bit_difference(digest0: &[u8], digest1: &[u8]) -> u32 {
+    digest0.iter().zip(digest1.iter()).map(|(value0, value1)| (value0 ^ value1).count_ones()).sum()
+}
+
+/// The statistical (avalanche and collision) self-test routine
+///
+/// For each sample, a single bit of a random message is flipped, and about *half* of the output bits are expected to change. In addition, all digests are checked for collisions.
+fn do_statistical_test(output: &mut dyn Write, samples: NonZeroUsize, halt: &Flag) -> Result<bool, Error> {
+    let mut source = Pcg64Mcg::seed_from_u64(STATS_SEEDVALUE);
+    let mut digest_set: HashSet<[u8; DEFAULT_DIGEST_SIZE]> = HashSet::with_capacity(samples.get().saturating_mul(2usize));
+    let mut message = [0u8; STATS_MESSAGE_SIZE];
+    let (mut total_bits, mut min_bits, mut max_bits, mut collisions) = (0u64, u32::MAX, u32::MIN, 0usize);
+
+    for _ in 0usize..samples.get() {
+        check_cancelled!(halt);
+        source.fill_bytes(&mut message);
+        let digest0: [u8; DEFAULT_DIGEST_SIZE] = compute(None, message);
+
+        let bit_index = (source.next_u32() as usize) % (STATS_MESSAGE_SIZE * (u8::BITS as usize));
+        message[bit_index / (u8::BITS as usize)] ^= 1u8 << (bit_index % (u8::BITS as usize));
+        let digest1: [u8; DEFAULT_DIGEST_SIZE] = compute(None, message);
+
+        let diff_bits = bit_difference(&digest0, &digest1);
+        total_bits += diff_bits as u64;
+        (min_bits, max_bits) = (min_bits.min(diff_bits), max_bits.max(diff_bits));
+
+        for digest in [digest0, digest1] {
+            if !digest_set.insert(digest) {
+                collisions += 1usize;
+            }
+        }
+    }
+
+    // The mean is expected to be within six standard deviations of 0.5
+    let mean_ratio = (total_bits as f64) / ((samples.get() as f64) * (DIGEST_BITS as f64));
+    let tolerance = 6.0 * 0.5 / ((samples.get() as f64) * (DIGEST_BITS as f64)).sqrt();
+
+    writeln!(
+        output,
+        "Avalanche: {:.2}% of the output bits changed on average (min: {}, max: {}, samples: {})",
+        mean_ratio * 100.0,
+        min_bits,
+        max_bits,
+        samples
+    )?;
+    writeln!(output, "Collisions: {} found in {} digests", collisions, digest_set.len() + collisions)?;
+
+    Ok(((mean_ratio - 0.5).abs() <= tolerance) && (collisions == 0usize))
+}
+
 // ---------------------------------------------------------------------------
 // Test runner
 // ---------------------------------------------------------------------------
@@ -105,24 +158,42 @@ const BUFFER_SIZE: usize = 4093usize;
 // Total number of bytes
 const TOTAL_BYTES: u64 = (BUFFER_SIZE as u64) * (ITERATIONS as u64) * (PCG64_SEEDVALUE.len() as u64);
 
-/// The actual **SpongeHash256** self-test routine
-fn do_self_test(_output: &mut dyn Write, halt: &Flag) -> Result<bool, Error> {
-    let mut success = true;
+/// Hash the pseudo-random byte stream that is generated from the given seed value, returns the digest and the number of bytes
+fn hash_stream(seed_value: u64, halt: &Flag) -> Result<([u8; DEFAULT_DIGEST_SIZE], u64), Error> {
+    let mut source = Pcg64Mcg::seed_from_u64(seed_value);
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let mut hasher = SpongeHash256::default();
     let mut counter = 0u64;
 
-    for (seed_value, digest_expected) in PCG64_SEEDVALUE.iter().zip(DIGEST_EXPECTED.iter()) {
-        let mut source = Pcg64Mcg::seed_from_u64(*seed_value);
-        let mut buffer = [0u8; BUFFER_SIZE];
-        let mut hasher = SpongeHash256::default();
+    for _ in 0..ITERATIONS {
+        source.fill_bytes(&mut buffer);
+        hasher.update(buffer);
+        counter += buffer.len() as u64;
+        check_cancelled!(halt);
+    }
 
-        for _ in 0..ITERATIONS {
-            source.fill_bytes(&mut buffer);
-            hasher.update(buffer);
-            counter += buffer.len() as u64;
-            check_cancelled!(halt);
-        }
+    Ok((hasher.digest(), counter))
+}
+
+/// The actual **SpongeHash256** self-test routine
+///
+/// If more than one thread is available, the streams for the different seed values are hashed in parallel.
+fn do_self_test(_output: &mut dyn Write, n_threads: NonZeroUsize, halt: &Flag) -> Result<bool, Error> {
+    let results: Vec<Result<([u8; DEFAULT_DIGEST_SIZE], u64), Error>> = if n_threads > NonZeroUsize::MIN {
+        thread::scope(|scope| {
+            let handles: Vec<_> = PCG64_SEEDVALUE.iter().map(|seed_value| scope.spawn(move || hash_stream(*seed_value, halt))).collect();
+            handles.into_iter().map(|handle| handle.join().expect("Failed to join the worker thread!")).collect()
+        })
+    } else {
+        PCG64_SEEDVALUE.iter().map(|seed_value| hash_stream(*seed_value, halt)).collect()
+    };
 
-        let digest_computed: [u8; DEFAULT_DIGEST_SIZE] = hasher.digest();
+    let mut success = true;
+    let mut counter = 0u64;
+
+    for (result, digest_expected) in results.into_iter().zip(DIGEST_EXPECTED.iter()) {
+        let (digest_computed, length) = result?;
+        counter += length;
 
         cfg_if! {
             if #[cfg(debug_assertions)] {
@@ -139,8 +210,8 @@ fn do_self_test(_output: &mut dyn Write, halt: &Flag) -> Result<bool, Error> {
     Ok(success)
 }
 
-/// Runs the self-test routine for `passes` times
-fn test_runner(output: &mut dyn Write, passes: NonZeroUsize, halt: &Flag) -> Result<ExitStatus, Error> {
+/// Runs the self-test routine for `passes` times, followed by the optional statistical test
+fn test_runner(output: &mut dyn Write, passes: NonZeroUsize, samples: Option<NonZeroUsize>, n_threads: NonZeroUsize, halt: &Flag) -> Result<ExitStatus, Error> {
     writeln!(output, "{}", HEADER_LINE)?;
     let mut median = Median::new();
 
@@ -150,7 +221,7 @@ fn test_runner(output: &mut dyn Write, passes: NonZeroUsize, halt: &Flag) -> Res
         check_cancelled!(halt);
 
         let start_time = Instant::now();
-        let success = do_self_test(output, halt)?;
+        let success = do_self_test(output, n_threads, halt)?;
         let elapsed = start_time.elapsed();
 
         writeln!(output, "{}", if success { "Successful." } else { "Failure !!!" })?;
@@ -162,11 +233,23 @@ fn test_runner(output: &mut dyn Write, passes: NonZeroUsize, halt: &Flag) -> Res
         }
     }
 
+    if let Some(samples) = samples {
+        writeln!(output, "\nStatistical self-test is running...")?;
+        output.flush()?;
+
+        let success = do_statistical_test(output, samples, halt)?;
+        writeln!(output, "{}", if success { "Successful." } else { "Failure !!!" })?;
+
+        if !success {
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     let secs_median = median.get().unwrap_or(f64::MAX);
     let (rate_median, rate_unit) = format_bytes((TOTAL_BYTES as f64) / secs_median);
 
     writeln!(output, "\n--------\n")?;
-    writeln!(output, "Median execution time: {:.1} seconds ({:.2} {}/s)", secs_median, rate_median, rate_unit)?;
+    writeln!(output, "Median execution time: {:.1} seconds ({:.2} {}/s, using {} thread(s))", secs_median, rate_median, rate_unit, n_threads)?;
 
     Ok(ExitStatus::Success)
 }
@@ -178,8 +261,9 @@ fn test_runner(output: &mut dyn Write, passes: NonZeroUsize, halt: &Flag) -> Res
 /// The built-in self-test (BIST)
 pub fn self_test(output: &mut OutStream, args: &Args, env: &Env, halt: &Flag) -> Result<ExitStatus, Aborted> {
     let passes = env.sefltest_passes.unwrap_or(NonZeroUsize::new(3usize).unwrap());
+    let n_threads = detect_thread_count(args, env).min(NonZeroUsize::new(PCG64_SEEDVALUE.len()).unwrap());
 
-    match test_runner(output.out(), passes, halt) {
+    match test_runner(output.out(), passes, env.sefltest_samples, n_threads, halt) {
         Ok(result) => Ok(result),
         Err(Error::Cancelled) => Err(Aborted),
         Err(error) => {
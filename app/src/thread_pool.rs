@@ -34,7 +34,10 @@ pub type TaskResult = Result<(), Cancelled>;
 // Detect number of CPU cores
 // ---------------------------------------------------------------------------
 
-/// Map the number of available CPU cores to the number of threads
+/// Number of threads to be used in `--multi-threading` mode, if the number of CPU cores can not be detected
+const FALLBACK_THREADS: NonZeroUsize = NonZeroUsize::new(4usize).unwrap();
+
+/// Map the number of available CPU cores to the number of threads, i.e., `max(1, floor(2 * log2(cores)))`
 ///
 /// **Note:** This avoids running too many parallel threads on systems with a large number of CPU cores!
 fn map_cores_to_threads(cores: NonZeroUsize) -> NonZeroUsize {
@@ -42,18 +45,35 @@ fn map_cores_to_threads(cores: NonZeroUsize) -> NonZeroUsize {
     NonZeroUsize::new(thread_count.max(1usize)).unwrap()
 }
 
+/// Select the number of threads from the explicitly requested number, if any, or from the detected number of CPU cores
+///
+/// An explicit request always takes precedence, so the CPU cores are *only* detected otherwise. If the detection fails, a fixed number of threads is used, rather than falling back to a single thread.
+fn select_thread_count<F: FnOnce() -> Option<NonZeroUsize>>(requested: Option<usize>, detect_cores: F) -> NonZeroUsize {
+    match requested.map(|value| value.min(MAX_THREADS)).and_then(NonZeroUsize::new) {
+        Some(count) => count,
+        None => detect_cores().map_or(FALLBACK_THREADS, map_cores_to_threads),
+    }
+}
+
 /// Determine the number of threads
 pub fn detect_thread_count(args: &Args, env: &Env) -> NonZeroUsize {
     if args.multi_threading {
-        match env.thread_count.map(|value| value.min(MAX_THREADS)).unwrap_or(usize::MIN) {
-            usize::MIN => map_cores_to_threads(available_parallelism().unwrap_or(NonZeroUsize::MIN)),
-            count => NonZeroUsize::new(count).unwrap(),
-        }
+        select_thread_count(env.thread_count, || available_parallelism().ok())
     } else {
         NonZeroUsize::MIN
     }
 }
 
+/// Limit the number of threads to the number of pending inputs, if that number is known in advance
+///
+/// **Note:** This avoids starting idle threads, e.g., when only a few files are to be processed!
+pub fn limit_thread_count(thread_count: NonZeroUsize, pending_inputs: Option<usize>) -> NonZeroUsize {
+    match pending_inputs.and_then(NonZeroUsize::new) {
+        Some(input_count) => thread_count.min(input_count),
+        None => thread_count,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Thread pool
 // ---------------------------------------------------------------------------
@@ -113,6 +133,10 @@ impl Drop for ThreadPool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
 
     #[test]
     fn test_threadpool_1a() {
@@ -140,4 +164,49 @@ mod tests {
     fn test_threadpool_2() {
         let _ = ThreadPool::new(NonZeroUsize::new(8).unwrap(), || Ok(()));
     }
+
+    #[test]
+    fn test_threadpool_3a() {
+        let thread_count = limit_thread_count(NonZeroUsize::new(MAX_THREADS).unwrap(), Some(2usize));
+        assert_eq!(thread_count.get(), 2usize);
+        let counter = Arc::new(AtomicUsize::new(0usize));
+        let counter_clone = Arc::clone(&counter);
+        let pool = ThreadPool::new(thread_count, move || {
+            counter_clone.fetch_add(1usize, Ordering::SeqCst);
+            Ok(())
+        });
+        assert!(matches!(pool.join(), Ok(Ok(()))));
+        assert_eq!(counter.load(Ordering::SeqCst), 2usize);
+    }
+
+    #[test]
+    fn test_threadpool_3b() {
+        assert_eq!(limit_thread_count(NonZeroUsize::new(8).unwrap(), Some(1usize)), NonZeroUsize::MIN);
+        assert_eq!(limit_thread_count(NonZeroUsize::new(8).unwrap(), Some(16usize)).get(), 8usize);
+        assert_eq!(limit_thread_count(NonZeroUsize::new(8).unwrap(), Some(0usize)).get(), 8usize);
+        assert_eq!(limit_thread_count(NonZeroUsize::new(8).unwrap(), None).get(), 8usize);
+    }
+
+    #[test]
+    fn test_thread_count_1a() {
+        assert_eq!(map_cores_to_threads(NonZeroUsize::MIN), NonZeroUsize::MIN);
+        assert_eq!(map_cores_to_threads(NonZeroUsize::new(2usize).unwrap()).get(), 2usize);
+        assert_eq!(map_cores_to_threads(NonZeroUsize::new(16usize).unwrap()).get(), 8usize);
+        assert_eq!(map_cores_to_threads(NonZeroUsize::new(100usize).unwrap()).get(), 13usize);
+    }
+
+    #[test]
+    fn test_thread_count_1b() {
+        assert_eq!(select_thread_count(Some(3usize), || None).get(), 3usize);
+        assert_eq!(select_thread_count(Some(3usize), || unreachable!()).get(), 3usize);
+        assert_eq!(select_thread_count(Some(usize::MAX), || None).get(), MAX_THREADS);
+    }
+
+    #[test]
+    fn test_thread_count_1c() {
+        assert_eq!(select_thread_count(None, || None), FALLBACK_THREADS);
+        assert_eq!(select_thread_count(Some(0usize), || None), FALLBACK_THREADS);
+        assert_eq!(select_thread_count(None, || Some(NonZeroUsize::MIN)), NonZeroUsize::MIN);
+        assert_eq!(select_thread_count(None, || NonZeroUsize::new(16usize)).get(), 8usize);
+    }
 }
@@ -3,61 +3,44 @@
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
 use crossbeam_channel::{bounded, Receiver, Sender};
-use hex::decode_to_slice;
+use hex::{decode_to_slice, encode_to_slice};
 use num::Integer;
 use std::{
-    ffi::OsStr,
-    io::{BufRead, BufReader, Read, Result as IoResult, Write},
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fs::{File, Metadata},
+    io::{BufRead, BufReader, BufWriter, Read, Result as IoResult, Seek, SeekFrom, Write},
+    mem,
     num::NonZeroUsize,
+    ops::Range,
     path::{Path, PathBuf},
     thread,
+    vec::IntoIter,
 };
 use tinyvec::TinyVec;
 
 use crate::{
-    arguments::Args,
-    common::{get_capacity, increment, Aborted, Digest, ExitStatus, Flag, TinyVecEx, MAX_DIGEST_SIZE},
-    digest::{compute_digest, digest_equal, Error as DigestError},
+    arguments::{Args, ColorMode},
+    common::{
+        get_capacity, increment, unescape_name, write_failed, Aborted, Digest, ExitStatus, FileMeta, Flag, JsonStr, TinyVecEx, ALGORITHM_TAG, HEADER_PREFIX,
+        MAX_DIGEST_SIZE, ROUNDS_SEPARATOR, XATTR_NAME,
+    },
+    digest::{compare_digest, compute_digest, describe_parameters, digest_equal, Error as DigestError, MAX_ROUNDS},
     environment::Env,
-    io::{DataSource, Error as IoError, OutStream},
-    os::STDIN_NAME,
-    print_error, print_warn,
+    error::{Error, ErrorKind, XattrError},
+    io::{DataSource, OutStream},
+    os::{path_from_bytes, path_to_bytes, read_xattr, STDIN_NAME},
+    print_error, print_info, print_warn,
     thread_pool::{detect_thread_count, Cancelled, TaskResult, ThreadPool},
 };
 
 type Count = NonZeroUsize;
 
-// ---------------------------------------------------------------------------
-// Error Type
-// ---------------------------------------------------------------------------
+/// Maximum number of failed files to be remembered for the final list
+const MAX_FAILED_LIST: usize = 1024usize;
 
-/// Error type for processing file tasks
-#[derive(Debug)]
-enum Error {
-    ChkSumFile(ErrorKind),
-    TargetFile(ErrorKind),
-}
-
-/// Error category that describes the error in greater detail
-#[derive(Debug)]
-enum ErrorKind {
-    FileOpen(PathBuf),
-    FileRead(PathBuf),
-    NotFound(PathBuf),
-    ObjIsDir(PathBuf),
-    ParseErr(PathBuf, usize),
-}
-
-impl ErrorKind {
-    #[inline]
-    fn from_io_error(error: IoError, path: PathBuf) -> Self {
-        match error {
-            IoError::AccessDenied => ErrorKind::FileOpen(path),
-            IoError::FileNotFound => ErrorKind::NotFound(path),
-            IoError::IsADirectory => ErrorKind::ObjIsDir(path),
-        }
-    }
-}
+/// The default size of the chunks in which a large checksum file is split, in order to be parsed in parallel (only in multi-threaded mode)
+const DEFAULT_PARSE_CHUNK_SIZE: NonZeroUsize = NonZeroUsize::new(16usize * 1024usize * 1024usize).unwrap();
 
 // ---------------------------------------------------------------------------
 // Utility functions
@@ -81,15 +64,15 @@ macro_rules! break_cancelled {
     };
 }
 
-/// Compute the exit status
+/// Compute the exit status, an I/O error takes precedence over a checksum mismatch
 #[inline]
-fn exit_status(chck_errors: u64, file_errors: u64, args: &Args) -> ExitStatus {
-    if (file_errors == u64::MIN) && (chck_errors == u64::MIN) {
-        ExitStatus::Success
-    } else if ((file_errors == u64::MIN) && (chck_errors == u64::MIN)) || args.keep_going {
-        ExitStatus::Warning
-    } else {
+fn exit_status(chck_errors: u64, file_errors: u64) -> ExitStatus {
+    if file_errors > u64::MIN {
         ExitStatus::Failure
+    } else if chck_errors > u64::MIN {
+        ExitStatus::Mismatch
+    } else {
+        ExitStatus::Success
     }
 }
 
@@ -100,52 +83,67 @@ fn exit_status(chck_errors: u64, file_errors: u64, args: &Args) -> ExitStatus {
 // Verification result
 static VERIFICATION: [&str; 2usize] = ["FAILED", "OK"];
 
+// Verification result color (ANSI escape codes)
+static VERIFICATION_COLOR: [&str; 2usize] = ["\x1b[1;31m", "\x1b[1;32m"];
+
 /// Print a single verification result
 #[inline]
 fn print_match(output: &mut dyn Write, is_match: bool, file_name: &Path, args: &Args) -> IoResult<()> {
-    if args.null {
-        write!(output, "{}: {}\0", file_name.to_string_lossy(), VERIFICATION[is_match as usize])?;
+    if is_match && args.fail_only {
+        return Ok(());
+    }
+
+    if args.ndjson {
+        writeln!(output, r#"{{"file":"{}","result":"{}"}}"#, JsonStr(&file_name.to_string_lossy()), VERIFICATION[is_match as usize])?;
     } else {
-        writeln!(output, "{}: {}", file_name.to_string_lossy(), VERIFICATION[is_match as usize])?;
+        output.write_all(&path_to_bytes(file_name))?;
+        match args.color {
+            ColorMode::Always => write!(output, ": {}{}\x1b[0m", VERIFICATION_COLOR[is_match as usize], VERIFICATION[is_match as usize])?,
+            _ => write!(output, ": {}", VERIFICATION[is_match as usize])?,
+        }
+        output.write_all(if args.null { b"\0" } else { b"\n" })?;
     }
 
-    if args.flush {
+    if args.flush || args.ndjson {
         output.flush()?;
     }
 
     Ok(())
 }
 
+/// Check if the result refers to a checksum line that is tolerated, because it is only reported as a warning
+#[inline]
+fn is_line_error(verify_result: &VerifyResult, args: &Args) -> bool {
+    match verify_result {
+        Err(Error::Malformed(_, _) | Error::Anomalous(_, _)) => true,
+        Err(Error::DigestTooLong(_, _)) => args.warn,
+        _ => false,
+    }
+}
+
 /// Print result to output
 #[inline]
-fn print_result(output: &mut OutStream, verify_result: &VerifyResult, args: &Args) -> bool {
+fn print_result(output: &mut OutStream, verify_result: &VerifyResult, args: &Args) -> IoResult<()> {
     match verify_result {
-        Ok((is_match, path)) => print_match(output.out(), *is_match, path, args).is_ok(),
+        Ok((is_match, path, _)) => print_match(output.out(), *is_match, path, args),
         Err(error) => {
             match error {
-                Error::ChkSumFile(kind) => match kind {
-                    ErrorKind::FileOpen(path) => print_error!(output, args, "Failed to open checksum file: {:?}", path),
-                    ErrorKind::FileRead(path) => print_error!(output, args, "Failed to read checksum file: {:?}", path),
-                    ErrorKind::NotFound(path) => print_error!(output, args, "Checksum file not found: {:?}", path),
-                    ErrorKind::ObjIsDir(path) => print_error!(output, args, "Checksum file is a directory: {:?}", path),
-                    ErrorKind::ParseErr(path, line) => print_error!(output, args, "Malformed checksum file: {:?} [line #{}]", path, line),
-                },
-                Error::TargetFile(kind) => match kind {
-                    ErrorKind::FileOpen(path) => print_error!(output, args, "Failed to open target file: {:?}", path),
-                    ErrorKind::FileRead(path) => print_error!(output, args, "Failed to read target file: {:?}", path),
-                    ErrorKind::NotFound(path) => print_error!(output, args, "Target file not found: {:?}", path),
-                    ErrorKind::ObjIsDir(path) => print_error!(output, args, "Target file is a directory: {:?}", path),
-                    ErrorKind::ParseErr(_path, _line) => unreachable!(),
-                },
+                Error::Malformed(_, _) | Error::Anomalous(_, _) => print_warn!(output, args, "{}", error),
+                Error::DigestTooLong(_, _) if args.warn => print_warn!(output, args, "{}", error),
+                _ => print_error!(output, args, "{}", error),
             }
-            true
+            Ok(())
         }
     }
 }
 
 /// Print the summary
 #[inline]
-fn print_summary(output: &mut OutStream, chck_errors: u64, file_errors: u64, args: &Args) {
+fn print_summary(output: &mut OutStream, chck_errors: u64, file_errors: u64, line_errors: u64, args: &Args) {
+    if line_errors > u64::MIN {
+        print_warn!(output, args, "Warning: {} line(s) in the checksum file(s) are improperly formatted!", line_errors);
+    }
+
     if (chck_errors > u64::MIN) || (file_errors > u64::MIN) {
         if args.keep_going {
             if chck_errors > u64::MIN {
@@ -160,27 +158,179 @@ fn print_summary(output: &mut OutStream, chck_errors: u64, file_errors: u64, arg
     }
 }
 
+/// List of the files that failed the verification, capped to avoid unbounded memory usage
+#[derive(Default)]
+struct FailedList {
+    paths: Vec<PathBuf>,
+    overflow: u64,
+}
+
+impl FailedList {
+    /// Remember the failed file, if the verification result is a mismatch
+    #[inline]
+    fn push(&mut self, verify_result: &VerifyResult, args: &Args) {
+        if let Ok((false, path, _)) = verify_result {
+            if args.list_failed {
+                if self.paths.len() < MAX_FAILED_LIST {
+                    self.paths.push(path.clone());
+                } else {
+                    increment(&mut self.overflow);
+                }
+            }
+        }
+    }
+}
+
+/// Print the list of files that failed the verification
+#[inline]
+fn print_failed(output: &mut OutStream, failed_list: &FailedList, args: &Args) {
+    if args.list_failed && (!failed_list.paths.is_empty()) {
+        print_info!(output, args, "The following file(s) did *not* match:");
+        for path in failed_list.paths.iter() {
+            print_info!(output, args, "- {:?}", path);
+        }
+        if failed_list.overflow > u64::MIN {
+            print_info!(output, args, "... and {} more file(s), which have been omitted from the list", failed_list.overflow);
+        }
+    }
+}
+
+/// Updated lines of the regenerated checksum file, by line number, as collected for the `--regenerate` option
+#[derive(Default)]
+struct Regenerated {
+    updated: BTreeMap<usize, Vec<u8>>,
+}
+
+impl Regenerated {
+    /// Take the updated checksum line from the verification result, if the file was read successfully but did *not* match
+    #[inline]
+    fn push(&mut self, verify_result: &mut VerifyResult) {
+        if let Ok((false, _, source_line)) = verify_result {
+            if let Some(source_line) = source_line.take() {
+                self.updated.insert(source_line.line_no, source_line.line);
+            }
+        }
+    }
+}
+
+/// Write the regenerated checksum file, all lines of the original checksum file are retained in their original order, except for the updated lines
+///
+/// The original checksum file is read completely, before the regenerated checksum file is created, so that both may refer to the same file.
+fn write_regenerated(output: &mut OutStream, mut regenerated: Regenerated, checksum_file: &Path, file_name: &Path, args: &Args) -> bool {
+    let read_result = match DataSource::from_path(checksum_file) {
+        Ok(DataSource::File(mut file)) => {
+            read_records(&mut file, args).collect::<IoResult<Vec<_>>>().map_err(|_| ErrorKind::FileRead(checksum_file.to_owned()))
+        }
+        Ok(_) => Err(ErrorKind::FileRead(checksum_file.to_owned())), /* cannot be read again */
+        Err(error) => Err(ErrorKind::from_io_error(error, checksum_file.to_owned())),
+    };
+    let lines = match read_result {
+        Ok(lines) => lines,
+        Err(error_kind) => {
+            print_error!(output, args, "{}", Error::ChkSumFile(error_kind));
+            return false;
+        }
+    };
+
+    let updated = regenerated.updated.len();
+    let result = File::create(file_name).map(BufWriter::new).and_then(|mut writer| {
+        for (line_no, line) in lines.iter().enumerate() {
+            writer.write_all(regenerated.updated.remove(&(line_no + 1usize)).as_deref().unwrap_or(line))?;
+            writer.write_all(if args.null { b"\0" } else { b"\n" })?;
+        }
+        writer.flush()
+    });
+
+    match result {
+        Ok(_) => {
+            print_info!(output, args, "Regenerated checksum file: {:?} [{} line(s), {} updated]", file_name, lines.len(), updated);
+            true
+        }
+        Err(error) => {
+            print_error!(output, args, "Error: Failed to write the regenerated checksum file: {:?} [{}]", file_name, error.kind());
+            false
+        }
+    }
+}
+
+/// Print the total number of checksums read, matched, failed and errored
+#[inline]
+fn print_totals(output: &mut OutStream, file_matches: u64, chck_errors: u64, file_errors: u64, args: &Args) {
+    if args.summary {
+        let total = file_matches.saturating_add(chck_errors).saturating_add(file_errors);
+        print_info!(output, args, "Summary: {} checksum(s) read, {} matched, {} failed, {} errored", total, file_matches, chck_errors, file_errors);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Verify file digest
 // ---------------------------------------------------------------------------
 
-type VerifyResult = Result<(bool, PathBuf), Error>;
+type VerifyResult = Result<(bool, PathBuf, Option<SourceLine>), Error>;
+
+/// Compute checksum and compare to expected value, also compares the file metadata in `--with-meta` mode
+///
+/// If the checksum does not match and the original line is given, the digest in that line is replaced by the computed digest.
+fn verify_checksum(
+    source: &mut DataSource,
+    digest_expected: &[u8],
+    meta_expected: &FileMeta,
+    rounds: NonZeroUsize,
+    source_line: Option<&mut SourceLine>,
+    args: &Args,
+    halt: &Flag,
+) -> Result<bool, DigestError> {
+    let is_match = if args.lazy_compare {
+        compare_digest(source, digest_expected, rounds, args, halt)?.0
+    } else {
+        let mut digest_computed: Digest = TinyVec::with_length(digest_expected.len());
+        compute_digest(source, digest_computed.as_mut_slice(), rounds, args, halt)?;
+        let is_match = digest_equal(digest_computed.as_slice(), digest_expected);
+        if let Some(source_line) = source_line.filter(|_| !is_match) {
+            source_line.replace_digest(digest_computed.as_slice());
+        }
+        is_match
+    };
+    if is_match && args.with_meta {
+        return Ok(meta_expected.matches(&source.file_meta()));
+    }
+    Ok(is_match)
+}
 
-/// Compute checksum and compare to expected value
-fn verify_checksum(source: &mut DataSource, digest_expected: &[u8], args: &Args, halt: &Flag) -> Result<bool, DigestError> {
-    let mut digest_computed: Digest = TinyVec::with_length(digest_expected.len());
-    compute_digest(source, digest_computed.as_mut_slice(), args, halt)?;
-    Ok(digest_equal(digest_computed.as_slice(), digest_expected))
+/// Verify checksum of a single data source
+fn verify_source(
+    source: &mut DataSource,
+    file_name: PathBuf,
+    expected: &Expected,
+    meta_expected: &FileMeta,
+    mut source_line: Option<SourceLine>,
+    args: &Args,
+    halt: &Flag,
+) -> Result<VerifyResult, Cancelled> {
+    let rounds = expected.rounds.unwrap_or_else(|| args.rounds());
+    match verify_checksum(source, expected.digest.as_slice(), meta_expected, rounds, source_line.as_mut(), args, halt) {
+        Ok(is_match) => Ok(Ok((is_match, file_name, source_line))),
+        Err(DigestError::IoError) => Ok(Err(Error::TargetFile(ErrorKind::FileRead(file_name)))),
+        Err(DigestError::TooLarge) => Ok(Err(Error::TargetFile(ErrorKind::TooLarge(file_name, args.max_bytes.unwrap())))),
+        Err(DigestError::Cancelled) => Err(Cancelled),
+    }
 }
 
 /// Verify checksum of a single file
-fn verify_file(file_name: PathBuf, digest_expected: &Digest, args: &Args, halt: &Flag) -> Result<VerifyResult, Cancelled> {
-    match DataSource::from_path(&file_name) {
-        Ok(mut file) => match verify_checksum(&mut file, digest_expected.as_slice(), args, halt) {
-            Ok(is_match) => Ok(Ok((is_match, file_name))),
-            Err(DigestError::IoError) => Ok(Err(Error::TargetFile(ErrorKind::FileRead(file_name)))),
-            Err(DigestError::Cancelled) => Err(Cancelled),
-        },
+fn verify_file(
+    file_name: PathBuf,
+    expected: &Expected,
+    meta_expected: &FileMeta,
+    source_line: Option<SourceLine>,
+    args: &Args,
+    halt: &Flag,
+) -> Result<VerifyResult, Cancelled> {
+    let source = match args.base_dir.as_deref() {
+        Some(base_dir) if !STDIN_NAME.eq(&file_name) => DataSource::from_input(base_dir.join(&file_name), args),
+        _ => DataSource::from_input(&file_name, args),
+    };
+    match source {
+        Ok(mut file) => verify_source(&mut file, file_name, expected, meta_expected, source_line, args, halt),
         Err(error) => Ok(Err(Error::TargetFile(ErrorKind::from_io_error(error, file_name)))),
     }
 }
@@ -190,9 +340,9 @@ fn verify_thread(checksum_rx: &Receiver<ReadResult>, result_tx: &Sender<VerifyRe
     while let Ok(read_result) = checksum_rx.recv() {
         check_cancelled!(halt);
         match read_result {
-            Ok((digest_expected, file_name)) => {
-                let digest_result = verify_file(file_name, &digest_expected, args, halt)?;
-                let is_success = matches!(digest_result, Ok((true, _)));
+            Ok((expected, file_name, meta_expected, source_line)) => {
+                let digest_result = verify_file(file_name, &expected, &meta_expected, source_line, args, halt)?;
+                let is_success = matches!(digest_result, Ok((true, _, _)));
                 result_tx.send(digest_result)?;
                 if !(is_success || args.keep_going) {
                     break;
@@ -209,49 +359,385 @@ fn verify_thread(checksum_rx: &Receiver<ReadResult>, result_tx: &Sender<VerifyRe
 // Read checksums from checksum file
 // ---------------------------------------------------------------------------
 
-type ReadResult = Result<(Digest, PathBuf), Error>;
-struct Malformed;
+type ReadResult = Result<(Expected, PathBuf, FileMeta, Option<SourceLine>), Error>;
 
-/// Parse a single line from checksum file
-#[allow(clippy::collapsible_if)]
-fn parse_checksum_line(line: &str, expected_len: Option<usize>) -> Result<(&OsStr, Digest), Malformed> {
-    if let Some((digest_hex, input_name)) = line.split_once(|c: char| char::is_ascii_whitespace(&c)) {
+/// The expected digest, as read from the checksum file, and the number of permutation rounds, if that was recorded too
+struct Expected {
+    digest: Digest,
+    rounds: Option<NonZeroUsize>,
+}
+
+impl Expected {
+    #[inline]
+    fn new(digest: Digest) -> Self {
+        Self { digest, rounds: None }
+    }
+}
+
+/// The original line from the checksum file, as retained for the `--regenerate` option
+struct SourceLine {
+    line_no: usize,
+    line: Vec<u8>,
+}
+
+impl SourceLine {
+    /// Replace the hex-encoded digest in the line, the new digest must have the same length as the original digest
+    fn replace_digest(&mut self, digest: &[u8]) {
+        if let Some(range) = locate_digest(&self.line).filter(|range| range.len() == 2usize * digest.len()) {
+            encode_to_slice(digest, &mut self.line[range]).expect("Failed to encode the digest!");
+        }
+    }
+}
+
+/// Error that describes why a line from the checksum file could not be parsed
+enum ParseError {
+    Malformed,
+    TooLong,
+}
+
+/// Split the byte slice at the *first* occurrence of the given pattern
+fn split_once<'a>(data: &'a [u8], pattern: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let position = data.windows(pattern.len()).position(|window| window == pattern)?;
+    Some((&data[..position], &data[position + pattern.len()..]))
+}
+
+/// Split the byte slice at the *last* occurrence of the given pattern
+fn rsplit_once<'a>(data: &'a [u8], pattern: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let position = data.windows(pattern.len()).rposition(|window| window == pattern)?;
+    Some((&data[..position], &data[position + pattern.len()..]))
+}
+
+/// Strip the trailing `\r` character from a line, if present
+fn strip_cr(mut line: Vec<u8>) -> Vec<u8> {
+    line.pop_if(|value| *value == b'\r');
+    line
+}
+
+/// Decode the hex-encoded digest, making sure that it has a valid length
+fn decode_digest(digest_hex: &[u8]) -> Result<Digest, ParseError> {
+    let (length, remainder) = digest_hex.len().div_rem(&2usize);
+    if (length > usize::MIN) && (length <= MAX_DIGEST_SIZE) && (remainder == usize::MIN) {
+        let mut digest = TinyVec::with_length(length);
+        if decode_to_slice(digest_hex, digest.as_mut_slice()).is_ok() {
+            return Ok(digest);
+        }
+    } else if (length > MAX_DIGEST_SIZE) && (remainder == usize::MIN) && digest_hex.iter().all(u8::is_ascii_hexdigit) {
+        return Err(ParseError::TooLong);
+    }
+
+    Err(ParseError::Malformed)
+}
+
+/// Decode the hex-encoded digest, which is optionally followed by the number of permutation rounds, as written by `--show-rounds`
+fn decode_digest_rounds(digest_field: &[u8]) -> Result<Expected, ParseError> {
+    match digest_field.iter().position(|value| *value == ROUNDS_SEPARATOR) {
+        Some(position) => {
+            let rounds = str::from_utf8(&digest_field[position + 1usize..]).ok().and_then(|str| str.parse::<NonZeroUsize>().ok());
+            match rounds.filter(|rounds| rounds.get() <= MAX_ROUNDS) {
+                Some(rounds) => decode_digest(&digest_field[..position]).map(|digest| Expected { digest, rounds: Some(rounds) }),
+                None => Err(ParseError::Malformed),
+            }
+        }
+        None => decode_digest(digest_field).map(Expected::new),
+    }
+}
+
+/// Parse a single line from checksum file, in the BSD-style "tagged" format
+fn parse_tagged_line(line: &[u8]) -> Result<(&[u8], Expected), ParseError> {
+    let remainder = line.strip_prefix(ALGORITHM_TAG.as_bytes()).and_then(|data| data.strip_prefix(b"-")).ok_or(ParseError::Malformed)?;
+    let (digest_bits, remainder) = split_once(remainder, b" (").ok_or(ParseError::Malformed)?;
+    let (input_name, digest_hex) = rsplit_once(remainder, b") = ").ok_or(ParseError::Malformed)?;
+
+    if !input_name.is_empty() {
+        let expected = decode_digest_rounds(digest_hex.trim_ascii_end())?;
+        let digest_bits = str::from_utf8(digest_bits).ok().and_then(|str| str.parse::<usize>().ok());
+        if digest_bits.is_some() && (digest_bits == expected.digest.len().checked_mul(u8::BITS as usize)) {
+            return Ok((input_name, expected));
+        }
+    }
+
+    Err(ParseError::Malformed)
+}
+
+/// Parse a single metadata field, where `-` indicates that the field is not available
+fn parse_meta_field(field: &[u8]) -> Result<Option<u64>, ParseError> {
+    match field {
+        b"-" => Ok(None),
+        _ => str::from_utf8(field).ok().and_then(|str| str.parse::<u64>().ok()).map(Some).ok_or(ParseError::Malformed),
+    }
+}
+
+/// Parse the metadata fields, i.e., file size and modification time, that precede the file name
+fn parse_meta_fields(data: &[u8]) -> Result<(&[u8], FileMeta), ParseError> {
+    let (size, remainder) = split_once(data, b" ").ok_or(ParseError::Malformed)?;
+    let (mtime, input_name) = split_once(remainder, b" ").ok_or(ParseError::Malformed)?;
+
+    if !input_name.is_empty() {
+        return Ok((input_name, FileMeta { size: parse_meta_field(size)?, mtime: parse_meta_field(mtime)? }));
+    }
+
+    Err(ParseError::Malformed)
+}
+
+/// Parse the fields of a single line from checksum file, including the metadata fields in `--with-meta` mode
+fn parse_checksum_fields(line: &[u8], with_meta: bool) -> Result<(&[u8], Expected, FileMeta), ParseError> {
+    if line.starts_with(ALGORITHM_TAG.as_bytes()) {
+        return parse_tagged_line(line).map(|(input_name, expected)| (input_name, expected, FileMeta::default()));
+    }
+
+    if let Some(position) = line.iter().position(u8::is_ascii_whitespace) {
+        let (digest_hex, remainder) = (&line[..position], &line[position + 1usize..]);
+        let (input_name, file_meta) = if with_meta { parse_meta_fields(remainder)? } else { (remainder, FileMeta::default()) };
         if (!digest_hex.is_empty()) && (!input_name.is_empty()) {
-            let (length, remainder) = digest_hex.len().div_rem(&2usize);
-            if (length > usize::MIN) && (length <= MAX_DIGEST_SIZE) && (remainder == usize::MIN) && expected_len.is_none_or(|val| val == length) {
-                let mut digest = TinyVec::with_length(length);
-                if decode_to_slice(digest_hex, digest.as_mut_slice()).is_ok() {
-                    return Ok((OsStr::new(input_name), digest));
+            return decode_digest_rounds(digest_hex).map(|expected| (input_name, expected, file_meta));
+        }
+    }
+
+    Err(ParseError::Malformed)
+}
+
+/// Parse a single line from checksum file, a leading backslash indicates that the file name has been escaped
+fn parse_checksum_line(line: &[u8], with_meta: bool) -> Result<(Cow<'_, [u8]>, Expected, FileMeta), ParseError> {
+    match line.strip_prefix(b"\\") {
+        Some(remainder) => {
+            let (input_name, expected, file_meta) = parse_checksum_fields(remainder, with_meta)?;
+            unescape_name(input_name).map(|input_name| (Cow::Owned(input_name), expected, file_meta)).ok_or(ParseError::Malformed)
+        }
+        None => parse_checksum_fields(line, with_meta).map(|(input_name, expected, file_meta)| (Cow::Borrowed(input_name), expected, file_meta)),
+    }
+}
+
+/// Locate the hex-encoded digest in a (well-formed) line from checksum file, returns the range of the digest within the line
+fn locate_digest(line: &[u8]) -> Option<Range<usize>> {
+    let trimmed = line.trim_ascii_start();
+    let data = trimmed.strip_prefix(b"\\").unwrap_or(trimmed);
+    let offset = line.len() - data.len();
+    if data.starts_with(ALGORITHM_TAG.as_bytes()) {
+        let (_, digest_hex) = rsplit_once(data, b") = ")?;
+        let start = offset + (data.len() - digest_hex.len());
+        let digest_hex = digest_hex.trim_ascii_end();
+        Some(start..(start + digest_hex.iter().position(|value| *value == ROUNDS_SEPARATOR).unwrap_or(digest_hex.len())))
+    } else {
+        Some(offset..(offset + data.iter().position(|value| value.is_ascii_whitespace() || (*value == ROUNDS_SEPARATOR)).unwrap_or(data.len())))
+    }
+}
+
+/// Check if a (well-formed) line from checksum file is in the canonical form, as it is generated by this program
+///
+/// A line is *not* canonical, if it has leading or trailing whitespace, or if the digest contains uppercase hex digits.
+fn is_canonical(line: &[u8]) -> bool {
+    if line.first().is_some_and(u8::is_ascii_whitespace) || line.last().is_some_and(u8::is_ascii_whitespace) {
+        return false;
+    }
+
+    let digest_hex = locate_digest(line).map(|range| &line[range]);
+    !digest_hex.unwrap_or_default().iter().any(u8::is_ascii_uppercase)
+}
+
+/// Iterator over NULL-terminated records, falls back to lines, if the input does not contain any NULL character at all
+struct NullRecords<R: BufRead> {
+    reader: R,
+    is_first: bool,
+    fallback: Option<IntoIter<Vec<u8>>>,
+}
+
+impl<R: BufRead> NullRecords<R> {
+    fn new(reader: R) -> Self {
+        Self { reader, is_first: true, fallback: None }
+    }
+}
+
+impl<R: BufRead> Iterator for NullRecords<R> {
+    type Item = IoResult<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(lines) = self.fallback.as_mut() {
+            return lines.next().map(Ok);
+        }
+
+        let mut record = Vec::new();
+        match self.reader.read_until(b'\0', &mut record) {
+            Ok(0usize) => None,
+            Ok(_) => {
+                let is_terminated = record.pop_if(|value| *value == b'\0').is_some();
+                if is_terminated || !mem::replace(&mut self.is_first, false) {
+                    Some(Ok(record))
+                } else {
+                    self.fallback = Some(record.split(|value| *value == b'\n').map(|line| strip_cr(line.to_vec())).collect::<Vec<_>>().into_iter());
+                    self.next()
                 }
             }
+            Err(error) => Some(Err(error)),
         }
     }
+}
 
-    Err(Malformed)
+/// Check whether the given line is a comment line, i.e., it starts with a `#` character
+#[inline(always)]
+fn is_comment(line: &[u8]) -> bool {
+    line.first().is_some_and(|first| *first == b'#')
+}
+
+/// Split the source into records, i.e., lines or NULL-terminated records
+fn read_records<'a>(input: &'a mut dyn Read, args: &Args) -> Box<dyn Iterator<Item = IoResult<Vec<u8>>> + 'a> {
+    if args.null {
+        Box::new(NullRecords::new(BufReader::new(input)))
+    } else {
+        Box::new(BufReader::new(input).split(b'\n').map(|line| line.map(strip_cr)))
+    }
+}
+
+/// A single record from the checksum file, after it has been parsed
+enum Record {
+    Skip,
+    Header(bool),
+    Checksum(Expected, PathBuf, FileMeta, bool, Option<Vec<u8>>),
+    TooLong,
+    Malformed,
+}
+
+/// Parse a single record (line) from the checksum file
+///
+/// This does *not* depend on the position of the record in the file, so that records can be parsed in any order, e.g., on multiple threads.
+fn parse_record(line: Vec<u8>, parameters: &str, args: &Args) -> Record {
+    let line_trimmed = line.trim_ascii_start();
+    if let Some(descriptor) = line_trimmed.strip_prefix(HEADER_PREFIX.as_bytes()) {
+        return Record::Header(descriptor.trim_ascii_end() == parameters.as_bytes());
+    }
+
+    if line_trimmed.is_empty() || (!args.strict && is_comment(line_trimmed)) {
+        return Record::Skip;
+    }
+
+    match parse_checksum_line(line_trimmed, args.with_meta) {
+        Ok((file_name, expected, file_meta)) => {
+            let file_name = path_from_bytes(file_name.into_owned());
+            let is_anomalous = args.strict && !is_canonical(&line);
+            Record::Checksum(expected, file_name, file_meta, is_anomalous, args.regenerate.is_some().then_some(line))
+        }
+        Err(ParseError::TooLong) => Record::TooLong,
+        Err(ParseError::Malformed) => Record::Malformed,
+    }
+}
+
+/// Submit a parsed record to the verification queue, returns `false` if reading the checksum file should be stopped
+fn submit_record(checksum_tx: &Sender<ReadResult>, record: Record, input_name: &Path, line_no: usize, args: &Args) -> Result<bool, Cancelled> {
+    match record {
+        Record::Skip | Record::Header(true) => Ok(true),
+        Record::Header(false) => {
+            checksum_tx.send(Err(Error::Incompatible(input_name.to_owned(), line_no)))?;
+            Ok(false)
+        }
+        Record::Checksum(expected, file_name, file_meta, is_anomalous, line) => {
+            if is_anomalous {
+                checksum_tx.send(Err(Error::Anomalous(input_name.to_owned(), line_no)))?;
+            }
+            let source_line = line.map(|line| SourceLine { line_no, line });
+            checksum_tx.send(Ok((expected, file_name, file_meta, source_line)))?;
+            Ok(true)
+        }
+        Record::TooLong => {
+            checksum_tx.send(Err(Error::DigestTooLong(input_name.to_owned(), line_no)))?;
+            Ok(args.warn || args.keep_going)
+        }
+        Record::Malformed if args.warn => {
+            checksum_tx.send(Err(Error::Malformed(input_name.to_owned(), line_no)))?;
+            Ok(true)
+        }
+        Record::Malformed => {
+            checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::ParseErr(input_name.to_owned(), line_no))))?;
+            Ok(args.keep_going)
+        }
+    }
 }
 
 /// Read all checksums from source
 fn read_checksum_data(checksum_tx: &Sender<ReadResult>, input: &mut dyn Read, input_name: PathBuf, args: &Args, halt: &Flag) -> Result<bool, Cancelled> {
-    let mut expected_len = None;
-    for (line_no, line) in BufReader::new(input).lines().enumerate() {
+    let parameters = describe_parameters(args);
+    for (line_no, line) in read_records(input, args).enumerate() {
+        check_cancelled!(halt);
+        let Ok(line) = line else {
+            checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::FileRead(input_name))))?;
+            return Ok(false);
+        };
+        if !submit_record(checksum_tx, parse_record(line, &parameters, args), &input_name, line_no + 1usize, args)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Parameters for parsing a (large) checksum file in multiple chunks, on multiple threads
+#[derive(Clone, Copy)]
+struct Chunking {
+    max_chunks: Count,
+    chunk_size: NonZeroUsize,
+}
+
+impl Chunking {
+    /// Returns the number of chunks that a checksum file of the given size is split into
+    fn chunk_count(&self, file_size: u64) -> usize {
+        usize::try_from(file_size.div_ceil(self.chunk_size.get() as u64)).unwrap_or(usize::MAX).min(self.max_chunks.get())
+    }
+}
+
+/// Split the file into byte ranges, such that each range starts at the beginning of a line
+fn chunk_boundaries(file: &mut File, file_size: u64, chunk_count: usize) -> IoResult<Vec<u64>> {
+    let mut reader = BufReader::new(file);
+    let mut boundaries = Vec::with_capacity(chunk_count + 1usize);
+    boundaries.push(u64::MIN);
+
+    for index in 1usize..chunk_count {
+        let position = (file_size / chunk_count as u64).saturating_mul(index as u64).max(*boundaries.last().unwrap());
+        reader.seek(SeekFrom::Start(position))?;
+        boundaries.push(position.saturating_add(reader.skip_until(b'\n')? as u64).min(file_size));
+    }
+
+    boundaries.push(file_size);
+    Ok(boundaries)
+}
+
+/// Parse all records in the given byte range of the checksum file, the records are returned in the original order
+///
+/// Parsing stops early, if either the `halt` flag or the `stop` flag has been triggered.
+fn parse_chunk(file_name: &Path, range: Range<u64>, parameters: &str, args: &Args, halt: &Flag, stop: &Flag) -> Result<IoResult<Vec<Record>>, Cancelled> {
+    let mut file = match File::open(file_name).and_then(|mut file| file.seek(SeekFrom::Start(range.start)).map(|_| file)) {
+        Ok(file) => file,
+        Err(error) => return Ok(Err(error)),
+    };
+
+    let mut records = Vec::new();
+    for line in BufReader::new(Read::by_ref(&mut file).take(range.end - range.start)).split(b'\n') {
         check_cancelled!(halt);
+        check_cancelled!(stop);
         match line {
-            Ok(line) => {
-                let line_trimmed = line.trim_start();
-                if !line_trimmed.is_empty() {
-                    if let Ok((file_name, digest)) = parse_checksum_line(line_trimmed, expected_len) {
-                        expected_len.get_or_insert_with(|| digest.len());
-                        checksum_tx.send(Ok((digest, PathBuf::from(file_name))))?;
-                    } else {
-                        checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::ParseErr(input_name.clone(), line_no + 1usize))))?;
-                        if !args.keep_going {
-                            return Ok(false);
-                        }
-                    }
-                };
-            }
-            Err(_) => {
-                checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::FileRead(input_name))))?;
+            Ok(line) => records.push(parse_record(strip_cr(line), parameters, args)),
+            Err(error) => return Ok(Err(error)),
+        }
+    }
+
+    Ok(Ok(records))
+}
+
+/// Submit the parsed records of all chunks, strictly in the original order
+fn submit_chunks(
+    checksum_tx: &Sender<ReadResult>,
+    chunks: impl Iterator<Item = Result<IoResult<Vec<Record>>, Cancelled>>,
+    input_name: &Path,
+    args: &Args,
+    halt: &Flag,
+) -> Result<bool, Cancelled> {
+    let mut line_no = 0usize;
+    for records in chunks {
+        let Ok(records) = records? else {
+            checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::FileRead(input_name.to_owned()))))?;
+            return Ok(false);
+        };
+        for record in records {
+            check_cancelled!(halt);
+            line_no += 1usize;
+            if !submit_record(checksum_tx, record, input_name, line_no, args)? {
                 return Ok(false);
             }
         }
@@ -260,10 +746,52 @@ fn read_checksum_data(checksum_tx: &Sender<ReadResult>, input: &mut dyn Read, in
     Ok(true)
 }
 
+/// Read all checksums from a (large) checksum file, which is split into chunks that are parsed in parallel
+///
+/// Each chunk is parsed into its own buffer, so that all chunks are parsed concurrently, regardless of how fast the records are consumed. The parsed
+/// records of each chunk are then submitted strictly in the original order, so that the line numbers are the same as with sequential parsing.
+fn read_checksum_chunked(
+    checksum_tx: &Sender<ReadResult>,
+    file: &mut File,
+    input_name: PathBuf,
+    chunk_count: usize,
+    args: &Args,
+    halt: &Flag,
+) -> Result<bool, Cancelled> {
+    let parameters = describe_parameters(args);
+    let Ok(boundaries) = file.metadata().and_then(|meta| chunk_boundaries(file, meta.len(), chunk_count)) else {
+        checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::FileRead(input_name))))?;
+        return Ok(false);
+    };
+
+    let stop = Flag::default();
+    thread::scope(|scope| {
+        let workers: Vec<_> = boundaries
+            .windows(2usize)
+            .map(|range| {
+                let (input_name, parameters, stop) = (&input_name, &parameters, &stop);
+                scope.spawn(move || parse_chunk(input_name, range[0usize]..range[1usize], parameters, args, halt, stop))
+            })
+            .collect();
+
+        let chunks = workers.into_iter().map(|worker| worker.join().expect("Failed to join the parser thread!"));
+        let result = submit_chunks(checksum_tx, chunks, &input_name, args, halt);
+        let _ = stop.stop_process();
+        result
+    })
+}
+
 /// Read checksums from a file
-fn read_checksum_file(checksum_tx: &Sender<ReadResult>, file_name: PathBuf, args: &Args, halt: &Flag) -> Result<bool, Cancelled> {
+fn read_checksum_file(checksum_tx: &Sender<ReadResult>, file_name: PathBuf, chunking: Option<Chunking>, args: &Args, halt: &Flag) -> Result<bool, Cancelled> {
     match DataSource::from_path(&file_name) {
-        Ok(mut file) => read_checksum_data(checksum_tx, &mut file, file_name, args, halt),
+        Ok(DataSource::File(mut file)) if !args.null => {
+            let file_size = file.metadata().ok().filter(Metadata::is_file).map(|meta| meta.len());
+            match chunking.zip(file_size).map(|(chunking, file_size)| chunking.chunk_count(file_size)).filter(|count| *count > 1usize) {
+                Some(chunk_count) => read_checksum_chunked(checksum_tx, &mut file, file_name, chunk_count, args, halt),
+                None => read_checksum_data(checksum_tx, &mut file, file_name, args, halt),
+            }
+        }
+        Ok(mut source) => read_checksum_data(checksum_tx, &mut source, file_name, args, halt),
         Err(error) => {
             checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::from_io_error(error, file_name))))?;
             Ok(false)
@@ -271,18 +799,61 @@ fn read_checksum_file(checksum_tx: &Sender<ReadResult>, file_name: PathBuf, args
     }
 }
 
+/// Read the names of all files that are listed in the given checksum file, improperly formatted lines are ignored
+pub fn read_file_names(file_name: &Path, args: &Args) -> Result<BTreeSet<PathBuf>, Error> {
+    let mut source = DataSource::from_path(file_name).map_err(|error| Error::ChkSumFile(ErrorKind::from_io_error(error, file_name.to_owned())))?;
+    let mut file_names = BTreeSet::new();
+
+    for line in read_records(&mut source, args) {
+        let line = line.map_err(|_| Error::ChkSumFile(ErrorKind::FileRead(file_name.to_owned())))?;
+        if let Ok((input_name, _expected, _file_meta)) = parse_checksum_line(line.trim_ascii_start(), args.with_meta) {
+            file_names.insert(path_from_bytes(input_name.into_owned()));
+        }
+    }
+
+    Ok(file_names)
+}
+
+/// Read the checksums that are stored in the extended attributes of the given input files
+fn read_xattrs(checksum_tx: &Sender<ReadResult>, args: &Args, halt: &Flag) -> TaskResult {
+    for file_name in args.files.iter().cloned() {
+        check_cancelled!(halt);
+        let read_result = match read_xattr(&file_name, XATTR_NAME) {
+            Ok(Some(value)) => match decode_digest(value.trim_ascii()) {
+                Ok(digest) => Ok((Expected::new(digest), file_name, FileMeta::default(), None)),
+                Err(_) => Err(Error::Xattr(XattrError::Invalid, file_name)),
+            },
+            Ok(None) => Err(Error::Xattr(XattrError::NotFound, file_name)),
+            Err(error) => Err(Error::Xattr(XattrError::from_io_error(&error, XattrError::ReadErr), file_name)),
+        };
+        let is_success = read_result.is_ok();
+        checksum_tx.send(read_result)?;
+        if !(is_success || args.keep_going) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
 /// Iterate a list of checksum files
-fn reader_thread(checksum_tx: &Sender<ReadResult>, args: &Args, halt: &Flag) -> TaskResult {
-    if !args.files.is_empty() {
+fn reader_thread(checksum_tx: &Sender<ReadResult>, chunking: Option<Chunking>, args: &Args, halt: &Flag) -> TaskResult {
+    if args.check_xattr {
+        read_xattrs(checksum_tx, args, halt)?;
+    } else if !args.files.is_empty() {
         for file_name in args.files.iter().cloned() {
             check_cancelled!(halt);
-            if !(read_checksum_file(checksum_tx, file_name, args, halt)? || args.keep_going) {
+            if !(read_checksum_file(checksum_tx, file_name, chunking, args, halt)? || args.keep_going) {
                 break;
             }
         }
     } else {
-        let mut stdin_stream = DataSource::from_stdin();
-        read_checksum_data(checksum_tx, &mut stdin_stream, STDIN_NAME.to_owned(), args, halt)?;
+        match DataSource::from_stdin() {
+            Ok(mut stdin_stream) => {
+                read_checksum_data(checksum_tx, &mut stdin_stream, STDIN_NAME.to_owned(), args, halt)?;
+            }
+            Err(error) => checksum_tx.send(Err(Error::ChkSumFile(ErrorKind::from_io_error(error, STDIN_NAME.to_owned()))))?,
+        }
     }
 
     Ok(())
@@ -292,36 +863,48 @@ fn reader_thread(checksum_tx: &Sender<ReadResult>, args: &Args, halt: &Flag) ->
 // Verify implementation
 // ---------------------------------------------------------------------------
 
-fn verify_mt(output: &mut OutStream, n_threads: Count, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+fn verify_mt(output: &mut OutStream, n_threads: Count, chunk_size: NonZeroUsize, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
     // Initialize channels
     let (checksum_tx, checksum_rx) = bounded::<ReadResult>(256usize);
     let (result_tx, result_rx) = bounded::<VerifyResult>(get_capacity(&n_threads));
 
     // Start the checksum reader thread
-    let thread_handle = thread::spawn(move || reader_thread(&checksum_tx, args, halt));
+    let chunking = Chunking { max_chunks: n_threads, chunk_size };
+    let thread_handle = thread::spawn(move || reader_thread(&checksum_tx, Some(chunking), args, halt));
 
     // Start the worker threads
     let thread_pool = ThreadPool::new(n_threads, move || verify_thread(&checksum_rx, &result_tx, args, halt));
 
     // Initialize counters
-    let (mut chck_errors, mut file_errors, mut write_errors) = (u64::MIN, u64::MIN, false);
+    let (mut chck_errors, mut file_errors, mut line_errors, mut write_error) = (u64::MIN, u64::MIN, u64::MIN, None);
+    let mut file_matches = u64::MIN;
+    let mut failed_list = FailedList::default();
+    let mut regenerated = Regenerated::default();
 
     // Process all verification results
-    while let Ok(verify_result) = result_rx.recv() {
+    while let Ok(mut verify_result) = result_rx.recv() {
         break_cancelled!(halt);
-        let is_success = matches!(verify_result, Ok((true, _)));
-        if verify_result.is_err() {
+        let is_success = matches!(verify_result, Ok((true, _, _)));
+        let is_malformed = is_line_error(&verify_result, args);
+        if is_malformed {
+            increment(&mut line_errors)
+        } else if verify_result.is_err() {
             increment(&mut file_errors)
-        } else if !is_success {
-            increment(&mut chck_errors)
+        } else if is_success {
+            increment(&mut file_matches)
+        } else {
+            increment(&mut chck_errors);
+            failed_list.push(&verify_result, args);
         }
 
-        if !print_result(output, &verify_result, args) {
-            write_errors = true;
+        if let Err(error) = print_result(output, &verify_result, args) {
+            write_error = Some(error.kind());
             break;
-        } else if !(is_success || args.keep_going) {
+        } else if !(is_success || is_malformed || args.keep_going) {
             break;
         }
+
+        regenerated.push(&mut verify_result);
     }
 
     // Send shutdown signal to still running threads
@@ -344,16 +927,24 @@ fn verify_mt(output: &mut OutStream, n_threads: Count, args: &'static Args, halt
     }
 
     // Have write any errors been encountered?
-    if write_errors {
-        print_error!(output, args, "Error: Failed to write to standard output stream!");
-        return Ok(ExitStatus::Failure);
+    if let Some(error_kind) = write_error {
+        return Ok(write_failed(output, error_kind, args));
     }
 
     // Print warning if any file(s) did not match the expected checksum
-    print_summary(output, chck_errors, file_errors, args);
+    print_summary(output, chck_errors, file_errors, line_errors, args);
+    print_failed(output, &failed_list, args);
+    print_totals(output, file_matches, chck_errors, file_errors, args);
+
+    // Write the regenerated checksum file, if requested
+    if let Some(file_name) = args.regenerate.as_deref() {
+        if !write_regenerated(output, regenerated, &args.files[0usize], file_name, args) {
+            return Ok(ExitStatus::Failure);
+        }
+    }
 
     // Check for errors
-    Ok(exit_status(chck_errors, file_errors, args))
+    Ok(exit_status(chck_errors, file_errors))
 }
 
 fn verify_st(output: &mut OutStream, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
@@ -361,35 +952,48 @@ fn verify_st(output: &mut OutStream, args: &'static Args, halt: &'static Flag) -
     let (checksum_tx, checksum_rx) = bounded::<ReadResult>(256usize);
 
     // Start the checksum reader thread
-    let thread_handle = thread::spawn(move || reader_thread(&checksum_tx, args, halt));
+    let thread_handle = thread::spawn(move || reader_thread(&checksum_tx, None, args, halt));
 
     // Initialize counters
-    let (mut chck_errors, mut file_errors, mut write_errors) = (u64::MIN, u64::MIN, false);
+    let (mut chck_errors, mut file_errors, mut line_errors, mut write_error) = (u64::MIN, u64::MIN, u64::MIN, None);
+    let mut file_matches = u64::MIN;
+    let mut failed_list = FailedList::default();
+    let mut regenerated = Regenerated::default();
 
     // Process all verification results
     while let Ok(checksum_result) = checksum_rx.recv() {
         break_cancelled!(halt);
-        let verify_result = match checksum_result {
-            Ok((digest_expected, file_name)) => match verify_file(file_name, &digest_expected, args, halt) {
-                Ok(result) => result,
-                Err(Cancelled) => break, /* cancelled */
-            },
+        let mut verify_result = match checksum_result {
+            Ok((expected, file_name, meta_expected, source_line)) => {
+                match verify_file(file_name, &expected, &meta_expected, source_line, args, halt) {
+                    Ok(result) => result,
+                    Err(Cancelled) => break, /* cancelled */
+                }
+            }
             Err(error) => Err(error),
         };
 
-        let is_success = matches!(verify_result, Ok((true, _)));
-        if verify_result.is_err() {
+        let is_success = matches!(verify_result, Ok((true, _, _)));
+        let is_malformed = is_line_error(&verify_result, args);
+        if is_malformed {
+            increment(&mut line_errors)
+        } else if verify_result.is_err() {
             increment(&mut file_errors)
-        } else if !is_success {
-            increment(&mut chck_errors)
+        } else if is_success {
+            increment(&mut file_matches)
+        } else {
+            increment(&mut chck_errors);
+            failed_list.push(&verify_result, args);
         }
 
-        if !print_result(output, &verify_result, args) {
-            write_errors = true;
+        if let Err(error) = print_result(output, &verify_result, args) {
+            write_error = Some(error.kind());
             break;
-        } else if !(is_success || args.keep_going) {
+        } else if !(is_success || is_malformed || args.keep_going) {
             break;
         }
+
+        regenerated.push(&mut verify_result);
     }
 
     // Send shutdown signal to still running threads
@@ -407,16 +1011,24 @@ fn verify_st(output: &mut OutStream, args: &'static Args, halt: &'static Flag) -
     }
 
     // Have write any errors been encountered?
-    if write_errors {
-        print_error!(output, args, "Error: Failed to write to standard output stream!");
-        return Ok(ExitStatus::Failure);
+    if let Some(error_kind) = write_error {
+        return Ok(write_failed(output, error_kind, args));
     }
 
     // Print warning if any file(s) did not match the expected checksum
-    print_summary(output, chck_errors, file_errors, args);
+    print_summary(output, chck_errors, file_errors, line_errors, args);
+    print_failed(output, &failed_list, args);
+    print_totals(output, file_matches, chck_errors, file_errors, args);
+
+    // Write the regenerated checksum file, if requested
+    if let Some(file_name) = args.regenerate.as_deref() {
+        if !write_regenerated(output, regenerated, &args.files[0usize], file_name, args) {
+            return Ok(ExitStatus::Failure);
+        }
+    }
 
     // Check for errors
-    Ok(exit_status(chck_errors, file_errors, args))
+    Ok(exit_status(chck_errors, file_errors))
 }
 
 // ---------------------------------------------------------------------------
@@ -434,8 +1046,99 @@ pub fn verify_files(output: &mut OutStream, args: &'static Args, env: &Env, halt
     }
 
     if thread_count > Count::MIN {
-        verify_mt(output, thread_count, args, halt)
+        verify_mt(output, thread_count, env.parse_chunk_size.unwrap_or(DEFAULT_PARSE_CHUNK_SIZE), args, halt)
     } else {
         verify_st(output, args, halt)
     }
 }
+
+// ---------------------------------------------------------------------------
+// Compare single input
+// ---------------------------------------------------------------------------
+
+/// Compute the digest of a single input (file or 'stdin') and compare it to the expected digest that was given on the command-line
+pub fn compare_input(output: &mut OutStream, args: &'static Args, halt: &'static Flag) -> Result<ExitStatus, Aborted> {
+    // Decode the expected digest, the digest size is inferred from the length of the hex string
+    let digest_hex = args.compare.as_deref().or(args.verify_one.as_deref()).unwrap_or_default();
+    let Ok(digest_expected) = decode_digest(digest_hex.as_bytes()).map(Expected::new) else {
+        print_error!(output, args, "Error: The expected digest is not a valid hex string! (given value: {:?})", digest_hex);
+        return Ok(ExitStatus::Failure);
+    };
+
+    // Make sure that at most one input file was specified
+    if args.files.len() > 1usize {
+        print_error!(output, args, "Error: Only a single input can be compared to the expected digest! (given inputs: {})", args.files.len());
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Compute the digest and compare it, in '--verify-one' mode the file name '-' denotes 'stdin'
+    let verify_result = match args.files.first().filter(|file_name| args.verify_one.is_none() || file_name.as_os_str() != "-") {
+        Some(file_name) => verify_file(file_name.clone(), &digest_expected, &FileMeta::default(), None, args, halt),
+        None => match DataSource::from_stdin() {
+            Ok(mut stdin) => verify_source(&mut stdin, STDIN_NAME.to_owned(), &digest_expected, &FileMeta::default(), None, args, halt),
+            Err(error) => Ok(Err(Error::TargetFile(ErrorKind::from_io_error(error, STDIN_NAME.to_owned())))),
+        },
+    };
+
+    // Has the process been aborted?
+    let Ok(verify_result) = verify_result else {
+        return Err(Aborted);
+    };
+
+    // Print the result
+    if let Err(error) = print_result(output, &verify_result, args).and_then(|_| output.out().flush()) {
+        return Ok(write_failed(output, error.kind(), args));
+    }
+
+    // Check for errors
+    Ok(match verify_result {
+        Ok((is_match, _, _)) => exit_status(u64::from(!is_match), u64::MIN),
+        Err(_) => exit_status(u64::MIN, 1u64),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::{
+        env, fs, process,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn test_chunks_parsed_concurrently() {
+        let file_name = env::temp_dir().join(format!("sponge256sum_chunks_{:08X}.txt", process::id()));
+        let lines: String = (0usize..4096usize).map(|index| format!("{:064x}  file_{index:04}.txt\n", index)).collect();
+        fs::write(&file_name, lines.as_bytes()).unwrap();
+
+        let args = Args::parse_from(["sponge256sum", "--check"]);
+        let parameters = describe_parameters(&args);
+        let (halt, stop) = (Flag::default(), Flag::default());
+        let boundaries = chunk_boundaries(&mut File::open(&file_name).unwrap(), lines.len() as u64, 4usize).unwrap();
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = boundaries
+                .windows(2usize)
+                .map(|range| {
+                    let (file_name, parameters, args, halt, stop) = (&file_name, &parameters, &args, &halt, &stop);
+                    scope.spawn(move || parse_chunk(file_name, range[0usize]..range[1usize], parameters, args, halt, stop))
+                })
+                .collect();
+
+            // The last chunk must complete, even though none of the preceding chunks has been consumed yet
+            let deadline = Instant::now() + Duration::from_secs(30u64);
+            while !workers.last().unwrap().is_finished() {
+                assert!(Instant::now() < deadline, "The last chunk was stalled!");
+                thread::sleep(Duration::from_millis(1u64));
+            }
+
+            let counts: Vec<usize> = workers.into_iter().map(|worker| worker.join().unwrap().ok().unwrap().unwrap().len()).collect();
+            assert_eq!(counts.len(), 4usize);
+            assert!(counts.iter().all(|count| *count > 256usize));
+            assert_eq!(counts.iter().sum::<usize>(), 4096usize);
+        });
+
+        fs::remove_file(&file_name).unwrap();
+    }
+}
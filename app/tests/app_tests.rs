@@ -6,35 +6,45 @@ mod common;
 
 use crate::common::{
     random::random_u64,
-    utils::{digest_eq, get_file_name, run_binary, run_binary_and_exit, run_binary_to_file, run_binary_with_cwd, run_binary_with_data, run_binary_with_env},
+    utils::{
+        digest_eq, get_file_name, run_binary, run_binary_and_exit, run_binary_to_file, run_binary_with_cwd, run_binary_with_data, run_binary_with_env,
+        run_binary_with_stderr,
+    },
 };
 
 use cfg_if::cfg_if;
 use regex::Regex;
+use sponge_hash_aes256::{compute, compute_tree, SpongeHash256Builder, DEFAULT_DIGEST_SIZE, TREE_LEAF_SIZE};
 use std::{
     collections::{HashMap, HashSet},
     ffi::{OsStr, OsString},
-    fs::File,
+    fs::{create_dir_all, read_to_string, File},
     hint::black_box,
     io::{BufRead, BufReader, BufWriter, Write},
     iter,
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::LazyLock,
+    time::{Duration, UNIX_EPOCH},
 };
 
 cfg_if! {
     if #[cfg(unix)] {
-        use crate::common::utils::{run_binary_from_file, run_binary_with_signal};
+        use crate::common::utils::{run_binary_and_close, run_binary_from_file, run_binary_with_signal};
+        use nix::{sys::stat::Mode, unistd::mkfifo};
         use std::{
-            fs::{set_permissions, Permissions},
-            os::unix::fs::PermissionsExt,
+            fs::{create_dir, set_permissions, Permissions},
+            os::unix::fs::{symlink, PermissionsExt},
+            thread,
         };
     }
 }
 
 #[cfg(target_os = "linux")]
-use std::fs;
+use crate::common::utils::run_binary_with_pipe;
+
+#[cfg(target_os = "linux")]
+use std::{fs, os::unix::ffi::OsStringExt};
 
 #[cfg(windows)]
 use std::os::windows::ffi::OsStringExt;
@@ -125,11 +135,21 @@ static REGEX_LINE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^([0-9a-f
 static REGEX_PLAIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^([0-9a-fA-F]+)$").unwrap());
 static REGEX_ZERO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([0-9a-fA-F]+)\s([\x20-\x7E]+)\x00").unwrap());
 static REGEX_PLAIN_ZERO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([0-9a-fA-F]+)\x00").unwrap());
+static REGEX_TAGGED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^SpongeHash256-(\d+)\s\(([\x20-\x7E]+)\)\s=\s([0-9a-fA-F]+)$").unwrap());
+static REGEX_SNAIL_TARGET: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Snail target:\s(\d+)\sms,\sselected\s(\d+)\srounds").unwrap());
+static REGEX_SUMMARY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Summary:\s(\d+)\sfile\(s\),\s(\d+)\sbytes,\s(\d+\.\d+)\sseconds").unwrap());
+static REGEX_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?m)^\[sponge256sum\] Time: (".+") \[(\d+\.\d+) seconds, \d+\.\d+ \w+/s(, thread #\d+)?\]$"#).unwrap());
+static REGEX_VERIFY_SUMMARY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"Summary:\s(\d+)\schecksum\(s\)\sread,\s(\d+)\smatched,\s(\d+)\sfailed,\s(\d+)\serrored").unwrap());
+static REGEX_META: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^([0-9a-fA-F]+)\s(\d+)\s(\d+)\s([\x20-\x7E]+)$").unwrap());
 static REGEX_CHECK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^([\x20-\x7E]+):\s(\w+)$").unwrap());
 static REGEX_CHECK_ZERO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([\x20-\x7E]+):\s(\w+)\x00").unwrap());
 static REGEX_VERSION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^sponge256sum\s+v(\d+\.\d+\.\d+)[\s$]").unwrap());
 static REGEX_HELP: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^Usage:\s+sponge256sum(\.exe)?[\s$]").unwrap());
 static REGEX_SELFTEST: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^Successful.").unwrap());
+static REGEX_SELFTEST_STATS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^Avalanche:\s(\d+\.\d+)%[^\n]*\nCollisions:\s0\sfound\sin\s2000\sdigests\nSuccessful.").unwrap());
 static REGEX_UNKNOWN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: unexpected argument '([^']+)' found"#).unwrap());
 static REGEX_MUTEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: the argument '([^']+)' cannot be used with '([^']+)'"#).unwrap());
 static REGEX_MULTIPLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: the argument '([^']+)' cannot be used multiple times"#).unwrap());
@@ -137,22 +157,33 @@ static REGEX_MISSING_VAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error
 static REGEX_MISSING_ARG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: the following required arguments were not provided:"#).unwrap());
 static REGEX_INVALID_UTF: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: invalid UTF-8 was detected in one or more arguments"#).unwrap());
 static REGEX_INVALID_VAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"error: invalid value '([^']+)' for '([^']+)':"#).unwrap());
+static REGEX_LEN_MIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Digest output size must be at least 8 bits!").unwrap());
 static REGEX_LEN_DIV: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Digest output size must be divisible by eight!").unwrap());
+static REGEX_BUF_MAX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Read buffer size exceeds the allowable maximum!").unwrap());
 static REGEX_LEN_MAX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Digest output size exceeds the allowable maximum!").unwrap());
-static REGEX_INFO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Length of context info must not exceed 255 characters!").unwrap());
+static REGEX_INFO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Error: Length of context info must not exceed 255 bytes!").unwrap());
 static REGEX_FILE_NOENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Input file not found: "([^"]+)""#).unwrap());
 static REGEX_FILE_FOPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Failed to open input file: "([^"]+)""#).unwrap());
 static REGEX_CHECK_NOENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Checksum file not found: "([^"]+)""#).unwrap());
 static REGEX_CHECK_FOPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Failed to open checksum file: "([^"]+)""#).unwrap());
+static REGEX_DIGEST_LONG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Digest too long: "([^"]+)" \[line #(\d+), maximum is (\d+) bits\]"#).unwrap());
+static REGEX_ANOMALOUS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Non-canonical checksum line: "([^"]+)" \[line #(\d+)\]"#).unwrap());
 static REGEX_MALFORMED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Malformed checksum file: "([^"]+)" \[line #(\d+)\]"#).unwrap());
+static REGEX_IMPROPER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Improperly formatted checksum line: "([^"]+)" \[line #(\d+)\]"#).unwrap());
 static REGEX_TARGET_NOENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Target file not found: "([^"]+)"#).unwrap());
 static REGEX_TARGET_FOPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Failed to open target file: "([^"]+)"#).unwrap());
+static REGEX_DUPLICATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Skipped duplicate input file: "([^"]+)""#).unwrap());
+static REGEX_OVERSIZED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Skipped oversized input file: "([^"]+)" \[size: (\d+) bytes\]"#).unwrap());
+static REGEX_CYCLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Skipped directory cycle: "([^"]+)" \[already visited: (\d+):(\d+)\]"#).unwrap());
+static REGEX_STDIN_ONCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(\w+) file refers to the standard input stream, which can only be read once: "([^"]+)""#).unwrap());
 static REGEX_ENVIRON: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Error: Value "([^"]+)" for environment variable "([^"]+)" is invalid!"#).unwrap());
 
 cfg_if! {
     if #[cfg(unix)] {
         static REGEX_ABORTED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)\bAborted: The process has been interrupted").unwrap());
         static REGEX_CHECK_ISDIR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Checksum file is a directory: "([^"]+)""#).unwrap());
+        static REGEX_FILE_LIMIT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Input file exceeds the limit of (\d+) bytes: "([^"]+)""#).unwrap());
         static REGEX_FILE_ISDIR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Input file is a directory: "([^"]+)""#).unwrap());
         static REGEX_STDIN_READ: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Failed to read data from the standard input stream!"#).unwrap());
         static REGEX_TARGET_ISDIR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Target file is a directory: "([^"]+)"#).unwrap());
@@ -253,14 +284,69 @@ fn do_test_file(expected: &str, file_name: &str, text_mode: bool, snail_level: u
     assert!(digest_eq(caps.get(1).unwrap().as_str(), expected));
 }
 
-fn do_test_file_with_length(expected: &str, file_name: &str, length: u32) {
+fn do_test_file_with_length(expected: &str, file_name: &str, length: u32, in_bytes: bool) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
+    let option = if in_bytes { "--bytes" } else { "--length" };
+    let output = run_binary([OsStr::new(option), OsStr::new(&format!("{}", length)), path.as_os_str()], true, false);
+    let caps = REGEX_LINE.captures(&output).expect("Regex did not match!");
+
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), expected));
+}
+
+fn do_test_file_with_buffer(expected: &str, file_name: &str, buffer_size: &str) {
     let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
-    let output = run_binary([OsStr::new("--length"), OsStr::new(&format!("{}", length)), path.as_os_str()], true, false);
+    let output = run_binary([OsStr::new("--buffer-size"), OsStr::new(buffer_size), path.as_os_str()], true, false);
     let caps = REGEX_LINE.captures(&output).expect("Regex did not match!");
 
     assert!(digest_eq(caps.get(1).unwrap().as_str(), expected));
 }
 
+fn do_test_file_with_info_file(file_name: &str, info: &str, line_break: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
+    let info_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("info_{:016X}.txt", random_u64()));
+    write!(File::create_new(&info_file).unwrap(), "{}{}", info, line_break).unwrap();
+
+    let output_1 = run_binary([OsStr::new("--info"), OsStr::new(info), path.as_os_str()], true, false);
+    let output_2 = run_binary([OsStr::new("--info-file"), info_file.as_os_str(), path.as_os_str()], true, false);
+
+    let caps_1 = REGEX_LINE.captures(&output_1).expect("Regex did not match!");
+    let caps_2 = REGEX_LINE.captures(&output_2).expect("Regex did not match!");
+
+    assert!(digest_eq(caps_1.get(1).unwrap().as_str(), caps_2.get(1).unwrap().as_str()));
+}
+
+fn do_test_file_with_info_hex(file_name: &str, info: &str, info_hex: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
+
+    let output_1 = run_binary([OsStr::new("--info"), OsStr::new(info), path.as_os_str()], true, false);
+    let output_2 = run_binary([OsStr::new("--info-hex"), OsStr::new(info_hex), path.as_os_str()], true, false);
+
+    let caps_1 = REGEX_LINE.captures(&output_1).expect("Regex did not match!");
+    let caps_2 = REGEX_LINE.captures(&output_2).expect("Regex did not match!");
+
+    assert!(digest_eq(caps_1.get(1).unwrap().as_str(), caps_2.get(1).unwrap().as_str()));
+}
+
+fn do_test_file_with_salt(file_name: &str, salt_1: &str, salt_2: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
+
+    let (output_1, stderr_1) = run_binary_with_stderr([OsStr::new("--salt"), OsStr::new(salt_1), path.as_os_str()]);
+    let (output_2, stderr_2) = run_binary_with_stderr([OsStr::new("--salt"), OsStr::new(salt_2), path.as_os_str()]);
+    let output_3 = run_binary([OsStr::new("--salt"), OsStr::new(salt_1), path.as_os_str()], true, false);
+    let output_4 = run_binary([path.as_os_str()], true, false);
+
+    let caps_1 = REGEX_LINE.captures(&output_1).expect("Regex did not match!");
+    let caps_2 = REGEX_LINE.captures(&output_2).expect("Regex did not match!");
+    let caps_3 = REGEX_LINE.captures(&output_3).expect("Regex did not match!");
+    let caps_4 = REGEX_LINE.captures(&output_4).expect("Regex did not match!");
+
+    assert!(stderr_1.contains(&format!("Salt: {}", salt_1.to_ascii_lowercase())));
+    assert!(stderr_2.contains(&format!("Salt: {}", salt_2.to_ascii_lowercase())));
+    assert!(!digest_eq(caps_1.get(1).unwrap().as_str(), caps_2.get(1).unwrap().as_str()));
+    assert!(digest_eq(caps_1.get(1).unwrap().as_str(), caps_3.get(1).unwrap().as_str()));
+    assert!(!digest_eq(caps_1.get(1).unwrap().as_str(), caps_4.get(1).unwrap().as_str()));
+}
+
 fn do_test_file_with_info(expected: &str, file_name: &str, info: &str, snail_level: usize) {
     let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name);
 
@@ -279,6 +365,20 @@ fn do_test_file_with_info(expected: &str, file_name: &str, info: &str, snail_lev
     assert!(digest_eq(caps.get(1).unwrap().as_str(), expected));
 }
 
+fn do_test_dir_strategy(strategy: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+    let parameters = [OsStr::new("--recursive"), path.as_os_str()];
+
+    let output_1 = run_binary_with_env(parameters, HashMap::from([("SPONGE256SUM_DIRWALK_STRATEGY", "BFS".to_owned())]), true, false);
+    let output_2 = run_binary_with_env(parameters, HashMap::from([("SPONGE256SUM_DIRWALK_STRATEGY", strategy.to_owned())]), true, false);
+
+    let lines_1: HashSet<&str> = output_1.lines().collect();
+    let lines_2: HashSet<&str> = output_2.lines().collect();
+
+    assert!(!lines_1.is_empty());
+    assert_eq!(lines_1, lines_2);
+}
+
 fn do_test_multi_file(expected_map: &HashMap<&str, &str>, thread_count: NonZeroUsize) {
     let base_directory = Path::new(env!("CARGO_MANIFEST_DIR"));
     let paths: Vec<PathBuf> = expected_map.values().map(|file_name| base_directory.join("tests").join("data").join("binary").join(file_name)).collect();
@@ -508,612 +608,3020 @@ fn do_test_exit_code(files: &[&str], verify_mode: bool, modify: bool, keep_going
     }
 }
 
-// ---------------------------------------------------------------------------
-// Test cases
-// ---------------------------------------------------------------------------
+fn do_test_exit_code_verify(mismatch: bool, missing: bool, keep_going: bool, expected_code: i32) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// File tests
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    run_binary_to_file(paths.iter().map(|path| path.as_os_str()), &check_file, true, true);
+    let mut writer = File::options().append(true).open(&check_file).unwrap();
 
-#[test]
-fn test_file_1a() {
-    do_test_file(EXPECTED[0usize], "frank.pdf", false, 0usize, false);
-}
+    if mismatch {
+        writeln!(writer, "{} {}", "0".repeat(64usize), paths[0usize].to_str().unwrap()).unwrap();
+    }
 
-#[test]
-fn test_file_1b() {
-    do_test_file(EXPECTED[1usize], "frank.pdf", false, 1usize, false);
-}
+    if missing {
+        writeln!(writer, "{} {}", EXPECTED[0usize], base_directory.join(format!("missing_{:016X}.pdf", random_u64())).to_str().unwrap()).unwrap();
+    }
 
-#[test]
-fn test_file_1c() {
-    do_test_file(EXPECTED[2usize], "frank.pdf", false, 2usize, false);
-}
+    let mut parameters = Vec::with_capacity(3usize);
+    parameters.push(OsStr::new("--check"));
 
-#[test]
-#[ignore]
-fn test_file_1d() {
-    do_test_file(EXPECTED[3usize], "frank.pdf", false, 3usize, false);
-}
+    if keep_going {
+        parameters.push(OsStr::new("--keep-going"));
+    }
 
-#[test]
-#[ignore]
-fn test_file_1e() {
-    do_test_file(EXPECTED[4usize], "frank.pdf", false, 4usize, false);
+    parameters.push(check_file.as_os_str());
+    assert_eq!(run_binary_and_exit(parameters), expected_code);
 }
 
-#[test]
-fn test_file_2a() {
-    do_test_file(EXPECTED[5usize], "dracula.pdf", false, 0usize, false);
-}
+#[cfg(unix)]
+fn do_test_interrupt_partial(multi_threading: bool) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
 
-#[test]
-fn test_file_2b() {
-    do_test_file(EXPECTED[6usize], "dracula.pdf", false, 1usize, false);
-}
+    let mut parameters = vec![OsStr::new("--null")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.extend_from_slice(&[path.as_os_str(), OsStr::new("/dev/zero")]);
 
-#[test]
-fn test_file_2c() {
-    do_test_file(EXPECTED[7usize], "dracula.pdf", false, 2usize, false);
-}
+    let output = run_binary_with_signal(parameters, 3u64, 2i32, 3i32, false);
+    let caps = REGEX_ZERO.captures(&output).expect("Regex did not match!");
 
-#[test]
-#[ignore]
-fn test_file_2d() {
-    do_test_file(EXPECTED[8usize], "dracula.pdf", false, 3usize, false);
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[0usize]));
+    assert_eq!(get_file_name(caps.get(2).unwrap().as_str()), "frank.pdf");
 }
 
-#[test]
-#[ignore]
-fn test_file_2e() {
-    do_test_file(EXPECTED[9usize], "dracula.pdf", false, 4usize, false);
-}
+#[cfg(unix)]
+fn do_test_broken_pipe(multi_threading: bool) {
+    let input_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("input_{:016X}.txt", random_u64()));
+    File::create_new(&input_file).unwrap().write_all(b"The quick brown fox jumps over the lazy dog").unwrap();
 
-#[test]
-fn test_file_3a() {
-    do_test_file(EXPECTED[0usize], "frank.pdf", false, 0usize, true);
-}
+    let mut parameters = Vec::with_capacity(4097usize);
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.extend(iter::repeat_n(input_file.as_os_str(), 4096usize));
 
-#[test]
-fn test_file_3b() {
-    do_test_file(EXPECTED[5usize], "dracula.pdf", false, 0usize, true);
+    let (first_line, exit_code) = run_binary_and_close(parameters);
+    assert!(first_line.starts_with("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+    assert_eq!(exit_code, 0i32);
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// File tests with info
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+fn do_verify_fail_only(tampered: bool, multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
 
-#[test]
-fn test_file_with_len_1a() {
-    do_test_file_with_length(EXPECTED[10usize], "frank.pdf", 512u32);
-}
+    run_binary_to_file(paths.iter().map(|path| path.as_os_str()), &check_file, true, true);
 
-#[test]
-fn test_file_with_len_1b() {
-    do_test_file_with_length(EXPECTED[11usize], "frank.pdf", 192u32);
-}
+    if tampered {
+        let mut writer = File::options().append(true).open(&check_file).unwrap();
+        writeln!(writer, "{} {}", "0".repeat(64usize), paths[0usize].to_str().unwrap()).unwrap();
+    }
 
-#[test]
-fn test_file_with_len_2a() {
-    do_test_file_with_length(EXPECTED[12usize], "dracula.pdf", 512u32);
-}
+    let mut parameters = Vec::with_capacity(4usize);
+    parameters.extend_from_slice(&[OsStr::new("--check"), OsStr::new("--fail-only"), OsStr::new("--keep-going")]);
 
-#[test]
-fn test_file_with_len_2b() {
-    do_test_file_with_length(EXPECTED[13usize], "dracula.pdf", 192u32);
-}
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
 
-#[test]
-fn test_file_with_info_1a() {
-    do_test_file_with_info(EXPECTED[14usize], "frank.pdf", "whatchamacallit", 0usize);
-}
+    parameters.push(check_file.as_os_str());
+    let output = run_binary(parameters, !tampered, false);
 
-#[test]
-fn test_file_with_info_1b() {
-    do_test_file_with_info(EXPECTED[15usize], "frank.pdf", "thingamabob", 0usize);
+    if tampered {
+        let results: Vec<(&str, &str)> =
+            REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+        assert_eq!(results, vec![(paths[0usize].to_str().unwrap(), "FAILED")]);
+    } else {
+        assert!(output.is_empty());
+    }
 }
 
-#[test]
-fn test_file_with_info_1c() {
-    do_test_file_with_info(EXPECTED[16usize], "frank.pdf", "whatchamacallit", 1usize);
-}
+fn do_verify_warn(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
 
-#[test]
-fn test_file_with_info_1d() {
-    do_test_file_with_info(EXPECTED[17usize], "frank.pdf", "thingamabob", 2usize);
-}
+    run_binary_to_file(paths.iter().map(|path| path.as_os_str()), &check_file, true, true);
+    File::options().append(true).open(&check_file).unwrap().write_all(b"invalidchecksumfile\nxyz this-is-garbage\n").unwrap();
 
-#[test]
-#[ignore]
-fn test_file_with_info_1e() {
-    do_test_file_with_info(EXPECTED[18usize], "frank.pdf", "whatchamacallit", 3usize);
-}
+    let mut parameters = Vec::with_capacity(4usize);
+    parameters.extend_from_slice(&[OsStr::new("--check"), OsStr::new("--warn")]);
 
-#[test]
-#[ignore]
-fn test_file_with_info_1f() {
-    do_test_file_with_info(EXPECTED[19usize], "frank.pdf", "thingamabob", 4usize);
-}
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
 
-#[test]
-fn test_file_with_info_2a() {
-    do_test_file_with_info(EXPECTED[20usize], "dracula.pdf", "whatchamacallit", 0usize);
-}
+    parameters.push(check_file.as_os_str());
 
-#[test]
-fn test_file_with_info_2b() {
-    do_test_file_with_info(EXPECTED[21usize], "dracula.pdf", "thingamabob", 0usize);
-}
+    let output = run_binary(parameters.as_slice(), true, false);
+    let mut result_set = HashSet::with_capacity(paths.len());
 
-#[test]
-fn test_file_with_info_2c() {
-    do_test_file_with_info(EXPECTED[22usize], "dracula.pdf", "whatchamacallit", 1usize);
-}
+    for caps in REGEX_CHECK.captures_iter(&output) {
+        let (file_name, result) = (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str());
+        assert_eq!(result, "OK");
+        assert!(result_set.insert(get_file_name(file_name).to_owned()));
+    }
 
-#[test]
-fn test_file_with_info_2d() {
-    do_test_file_with_info(EXPECTED[23usize], "dracula.pdf", "thingamabob", 2usize);
-}
+    assert_eq!(result_set.len(), paths.len());
 
-#[test]
-#[ignore]
-fn test_file_with_info_2e() {
-    do_test_file_with_info(EXPECTED[24usize], "dracula.pdf", "whatchamacallit", 3usize);
+    let output = run_binary(parameters.as_slice(), true, true);
+    let line_numbers: HashSet<&str> = REGEX_IMPROPER.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(line_numbers, HashSet::from(["3", "4"]));
 }
 
-#[test]
-#[ignore]
-fn test_file_with_info_2f() {
-    do_test_file_with_info(EXPECTED[25usize], "dracula.pdf", "thingamabob", 4usize);
-}
+#[cfg(target_os = "linux")]
+fn do_verify_raw_name(tagged: bool, multi_threading: bool) {
+    let data_file = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join(OsString::from_vec([b"raw_\xFF\xFE_".as_slice(), format!("{:016X}.bin", random_u64()).as_bytes()].concat()));
+    File::create_new(&data_file).unwrap().write_all(b"justsomearbitrarydatainthefile\n").unwrap();
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Text file tests
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let parameters = if tagged { vec![OsStr::new("--tag"), data_file.as_os_str()] } else { vec![data_file.as_os_str()] };
+    run_binary_to_file(parameters, &check_file, true, true);
 
-#[test]
-fn test_text_file_1a() {
-    do_test_file(EXPECTED[26usize], "alice29.txt", true, 0usize, false);
-}
+    let checksum_data = fs::read(&check_file).unwrap();
+    assert!(checksum_data.windows(2usize).any(|window| window == b"\xFF\xFE"));
 
-#[test]
-fn test_text_file_1b() {
-    do_test_file(EXPECTED[27usize], "alice29.txt", true, 1usize, false);
-}
+    let output_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("output_{:016X}.txt", random_u64()));
+    let mut parameters = vec![OsStr::new("--check"), check_file.as_os_str()];
 
-#[test]
-fn test_text_file_1c() {
-    do_test_file(EXPECTED[28usize], "alice29.txt", true, 2usize, false);
-}
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
 
-#[test]
-#[ignore]
-fn test_text_file_1d() {
-    do_test_file(EXPECTED[29usize], "alice29.txt", true, 3usize, false);
+    run_binary_to_file(parameters, &output_file, true, true);
+    let expected = [data_file.as_os_str().as_encoded_bytes(), b": OK\n"].concat();
+    assert_eq!(fs::read(&output_file).unwrap(), expected);
 }
 
-#[test]
-#[ignore]
-fn test_text_file_1e() {
-    do_test_file(EXPECTED[30usize], "alice29.txt", true, 4usize, false);
-}
+fn do_test_ndjson(multi_threading: bool, verify_mode: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let expected_map = HashMap::from([("frank.pdf", EXPECTED[0usize]), ("dracula.pdf", EXPECTED[5usize])]);
+    let paths: Vec<PathBuf> = expected_map.keys().map(|file_name| base_directory.join(file_name)).collect();
 
-#[test]
-fn test_text_file_2a() {
-    do_test_file(EXPECTED[31usize], "asyoulik.txt", true, 0usize, false);
-}
+    let mut parameters = Vec::with_capacity(paths.len() + 3usize);
+    parameters.push(OsStr::new("--ndjson"));
 
-#[test]
-fn test_text_file_2b() {
-    do_test_file(EXPECTED[32usize], "asyoulik.txt", true, 1usize, false);
-}
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
 
-#[test]
-fn test_text_file_2c() {
-    do_test_file(EXPECTED[33usize], "asyoulik.txt", true, 2usize, false);
-}
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    if verify_mode {
+        run_binary_to_file(paths.iter().map(|path| path.as_os_str()), &check_file, true, true);
+        parameters.extend_from_slice(&[OsStr::new("--check"), check_file.as_os_str()]);
+    } else {
+        paths.iter().for_each(|path| parameters.push(path.as_os_str()));
+    }
 
-#[test]
-#[ignore]
-fn test_text_file_2d() {
-    do_test_file(EXPECTED[34usize], "asyoulik.txt", true, 3usize, false);
-}
+    let output = run_binary(parameters, true, false);
+    let mut name_set = HashSet::with_capacity(expected_map.len());
+
+    for line in output.lines() {
+        let object: serde_json::Value = serde_json::from_str(line).expect("Failed to parse JSON!");
+        let file_name = get_file_name(object["file"].as_str().unwrap());
+        let expected = expected_map.get(file_name).expect("Unknown file name!");
+        if verify_mode {
+            assert_eq!(object["result"].as_str().unwrap(), "OK");
+        } else {
+            assert!(digest_eq(object["digest"].as_str().unwrap(), expected));
+        }
+        assert!(name_set.insert(file_name.to_owned()));
+    }
 
-#[test]
-#[ignore]
-fn test_text_file_2e() {
-    do_test_file(EXPECTED[35usize], "asyoulik.txt", true, 4usize, false);
+    assert_eq!(name_set.len(), expected_map.len());
 }
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Multi file tests
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+fn do_test_plain_fields(spec: &str, force_null: bool) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let file_size = path.metadata().unwrap().len().to_string();
 
-#[test]
-fn test_multi_file_1a() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_multi_file(&expected, NonZeroUsize::MIN);
-}
+    let mut parameters = vec![OsStr::new("--plain"), OsStr::new("--plain-fields"), OsStr::new(spec)];
+    if force_null {
+        parameters.push(OsStr::new("--null"));
+    }
+    parameters.push(path.as_os_str());
 
-#[test]
-fn test_multi_file_1b() {
+    let output = run_binary(parameters, true, false);
+    let record = output.strip_suffix(if force_null { '\0' } else { '\n' }).expect("Missing record terminator!");
+    let fields: Vec<&str> = record.splitn(spec.split(',').count(), ' ').collect();
+
+    for (field, value) in spec.split(',').zip(fields.iter()) {
+        match field {
+            "hex" => assert!(digest_eq(value, EXPECTED[0usize])),
+            "size" => assert_eq!(*value, file_size),
+            "name" => assert_eq!(get_file_name(value), "frank.pdf"),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn do_test_with_meta(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let expected_map = HashMap::from([("frank.pdf", EXPECTED[0usize]), ("dracula.pdf", EXPECTED[5usize])]);
+    let paths: Vec<PathBuf> = expected_map.keys().map(|file_name| base_directory.join(file_name)).collect();
+
+    let mut parameters = vec![OsStr::new("--with-meta")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    paths.iter().for_each(|path| parameters.push(path.as_os_str()));
+
+    let output = run_binary(parameters, true, false);
+    let mut name_set = HashSet::with_capacity(expected_map.len());
+
+    for caps in REGEX_META.captures_iter(&output) {
+        let file_path = Path::new(caps.get(4usize).unwrap().as_str());
+        let file_name = get_file_name(file_path.to_str().unwrap());
+        let meta_data = file_path.metadata().unwrap();
+        assert!(digest_eq(caps.get(1usize).unwrap().as_str(), expected_map.get(file_name).expect("Unknown file name!")));
+        assert_eq!(caps.get(2usize).unwrap().as_str().parse::<u64>().unwrap(), meta_data.len());
+        assert_eq!(caps.get(3usize).unwrap().as_str().parse::<u64>().unwrap(), meta_data.modified().unwrap().duration_since(UNIX_EPOCH).unwrap().as_secs());
+        assert!(name_set.insert(file_name.to_owned()));
+    }
+
+    assert_eq!(name_set.len(), expected_map.len());
+}
+
+fn do_verify_with_meta(touch: bool, multi_threading: bool) {
+    let source_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let target_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("frank_{:016X}.pdf", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    {
+        let mut writer = File::create_new(&target_file).unwrap();
+        std::io::copy(&mut File::open(&source_file).unwrap(), &mut writer).unwrap();
+        writer.set_modified(UNIX_EPOCH + Duration::from_secs(1000000000u64)).unwrap();
+    }
+
+    run_binary_to_file([OsStr::new("--with-meta"), target_file.as_os_str()], &check_file, true, true);
+
+    if touch {
+        File::options().write(true).open(&target_file).unwrap().set_modified(UNIX_EPOCH + Duration::from_secs(1500000000u64)).unwrap();
+    }
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--with-meta")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, !touch, false);
+    let results: Vec<(&str, &str)> = REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(results, vec![(target_file.to_str().unwrap(), if touch { "FAILED" } else { "OK" })]);
+}
+
+fn do_test_compare(digest_hex: &str, use_stdin: bool, expected_match: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+
+    let output = if use_stdin {
+        assert!(expected_match);
+        run_binary_with_data([OsStr::new("--compare"), OsStr::new(digest_hex)], &std::fs::read(&target_file).unwrap())
+    } else {
+        run_binary([OsStr::new("--compare"), OsStr::new(digest_hex), target_file.as_os_str()], expected_match, false)
+    };
+
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec![if expected_match { "OK" } else { "FAILED" }]);
+}
+
+fn do_test_verify_one(digest_hex: &str, use_stdin: bool, expected_match: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+
+    let output = if use_stdin {
+        assert!(expected_match);
+        run_binary_with_data([OsStr::new("--verify-one"), OsStr::new(digest_hex), OsStr::new("-")], &std::fs::read(&target_file).unwrap())
+    } else {
+        run_binary([OsStr::new("--verify-one"), OsStr::new(digest_hex), target_file.as_os_str()], expected_match, false)
+    };
+
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec![if expected_match { "OK" } else { "FAILED" }]);
+}
+
+fn do_verify_mixed_len(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let (path_1, path_2) = (base_directory.join("frank.pdf"), base_directory.join("dracula.pdf"));
+
+    let mut check_data = String::new();
+    for digest_bits in ["192", "256", "512"] {
+        check_data.push_str(&run_binary([OsStr::new("--length"), OsStr::new(digest_bits), path_1.as_os_str(), path_2.as_os_str()], true, false));
+    }
+
+    let digest_lengths: HashSet<usize> = REGEX_LINE.captures_iter(&check_data).map(|caps| caps.get(1).unwrap().len()).collect();
+    assert_eq!(digest_lengths, HashSet::from([48usize, 64usize, 128usize]));
+    File::create_new(&check_file).unwrap().write_all(check_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["OK"; 6usize]);
+}
+
+fn do_verify_truncated(lazy_compare: bool, corrupt: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let mut digest_hex = EXPECTED[0usize][..24usize].to_owned();
+    if corrupt {
+        let last_char = digest_hex.pop().unwrap();
+        digest_hex.push(if last_char == '0' { '1' } else { '0' });
+    }
+
+    File::create_new(&check_file).unwrap().write_all(format!("{} {}\n", digest_hex, target_file.to_str().unwrap()).as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if lazy_compare {
+        parameters.push(OsStr::new("--lazy-compare"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, !corrupt, false);
+    let results: Vec<(&str, &str)> = REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(results, vec![(target_file.to_str().unwrap(), if corrupt { "FAILED" } else { "OK" })]);
+}
+
+fn do_verify_strict(strict: bool, tagged: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let (target_name, digest_hex) = (target_file.to_str().unwrap(), EXPECTED[0usize].to_ascii_uppercase());
+    let checksum_data = if tagged {
+        format!("{} {}\nSpongeHash256-256 ({}) = {}\n", EXPECTED[0usize], target_name, target_name, digest_hex)
+    } else {
+        format!("{} {}\n{} {}\n", EXPECTED[0usize], target_name, digest_hex, target_name)
+    };
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if strict {
+        parameters.push(OsStr::new("--strict"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let results: Vec<(&str, &str)> = REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(results, vec![(target_name, "OK"), (target_name, "OK")]);
+
+    let warnings: Vec<&str> = REGEX_ANOMALOUS.captures_iter(&errors).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(warnings, if strict { vec!["2"] } else { vec![] });
+}
+
+fn do_verify_color(color_mode: Option<&str>) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let target_name = target_file.to_str().unwrap();
+    let checksum_data = format!("{} {}\n{} {}\n", EXPECTED[0usize], target_name, EXPECTED[5usize], target_name);
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--keep-going")];
+    if let Some(color_mode) = color_mode {
+        parameters.extend([OsStr::new("--color"), OsStr::new(color_mode)]);
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, false, false);
+    if color_mode == Some("always") {
+        assert_eq!(output, format!("{}: \x1b[1;32mOK\x1b[0m\n{}: \x1b[1;31mFAILED\x1b[0m\n", target_name, target_name));
+    } else {
+        assert!(!output.contains('\x1b'));
+        assert_eq!(output, format!("{}: OK\n{}: FAILED\n", target_name, target_name));
+    }
+}
+
+fn do_test_self_describing(info: Option<&str>, text_mode: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let mut parameters = vec![OsStr::new("--self-describing")];
+    if let Some(info) = info {
+        parameters.extend([OsStr::new("--info"), OsStr::new(info)]);
+    }
+    if text_mode {
+        parameters.push(OsStr::new("--text"));
+    }
+    parameters.push(target_file.as_os_str());
+    run_binary_to_file(parameters, &check_file, true, true);
+
+    let content = read_to_string(&check_file).unwrap();
+    let header = content.lines().next().unwrap();
+    assert!(header.starts_with(&format!("#! SpongeHash256 rounds=1 mode={} context=", if text_mode { "text" } else { "binary" })));
+    assert_eq!(header.ends_with("context=none"), info.is_none());
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if let Some(info) = info {
+        parameters.extend([OsStr::new("--info"), OsStr::new(info)]);
+    }
+    if text_mode {
+        parameters.push(OsStr::new("--text"));
+    }
+    parameters.push(check_file.as_os_str());
+    let output = run_binary(parameters, true, false);
+    assert_eq!(REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str().to_owned()).collect::<Vec<_>>(), vec!["OK"]);
+
+    for mismatch in [&[OsStr::new("--info"), OsStr::new("other")][..], &[OsStr::new("--snail")][..], &[OsStr::new("--text"), OsStr::new("--cr-newline")][..]] {
+        let mut parameters = vec![OsStr::new("--check")];
+        if let Some(info) = info.filter(|_| mismatch[0usize] != "--info") {
+            parameters.extend([OsStr::new("--info"), OsStr::new(info)]);
+        }
+        if text_mode && (mismatch[0usize] != "--text") {
+            parameters.push(OsStr::new("--text"));
+        }
+        parameters.extend(mismatch);
+        parameters.push(check_file.as_os_str());
+        let errors = run_binary(parameters, false, true);
+        assert!(REGEX_CHECK.captures(&errors).is_none());
+        assert!(errors.contains("Checksum file was generated with incompatible parameters"));
+    }
+}
+
+fn do_verify_comments(strict: bool) {
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let target_name = target_file.to_str().unwrap();
+    let checksum_data = format!("# sponge256sum checksums\n  # indented comment\n{} {}\n#\n", EXPECTED[0usize], target_name);
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if strict {
+        parameters.extend([OsStr::new("--strict"), OsStr::new("--warn")]);
+    }
+    parameters.push(check_file.as_os_str());
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let results: Vec<(&str, &str)> = REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(results, vec![(target_name, "OK")]);
+
+    let warnings: Vec<&str> = REGEX_IMPROPER.captures_iter(&errors).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(warnings, if strict { vec!["1", "2", "4"] } else { vec![] });
+}
+
+#[cfg(unix)]
+fn do_test_quote(tagged: bool, multi_threading: bool) {
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("quote_{:016X}", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let file_names = ["regular.txt", "new\nline.txt", "back\\slash.txt"];
+
+    create_dir(&base_dir).unwrap();
+    for file_name in file_names {
+        File::create(base_dir.join(file_name)).unwrap().write_all(file_name.as_bytes()).unwrap();
+    }
+
+    let mut parameters = vec![OsString::from("--quote")];
+    if tagged {
+        parameters.push(OsString::from("--tag"));
+    }
+    parameters.extend(file_names.iter().map(|file_name| base_dir.join(file_name).into_os_string()));
+    run_binary_to_file(parameters, &check_file, true, true);
+
+    let content = read_to_string(&check_file).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), file_names.len());
+    assert!(!lines[0usize].starts_with('\\'));
+    assert!(lines[1usize].starts_with('\\') && lines[1usize].contains("new\\nline.txt"));
+    assert!(lines[2usize].starts_with('\\') && lines[2usize].contains("back\\\\slash.txt"));
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    assert_eq!(output.matches(": OK").count(), file_names.len());
+    assert!(!output.contains("FAILED"));
+}
+
+#[cfg(unix)]
+fn do_test_line_break(option: Option<&str>) {
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("line_break_{:016X}", random_u64()));
+    let file_name = base_dir.join("new\nline.txt");
+
+    create_dir(&base_dir).unwrap();
+    File::create(&file_name).unwrap().write_all(b"line break").unwrap();
+
+    let mut parameters = Vec::new();
+    if let Some(option) = option {
+        parameters.push(OsStr::new(option));
+    }
+    parameters.push(file_name.as_os_str());
+
+    match option {
+        Some(_) => assert!(!run_binary(parameters, true, false).is_empty()),
+        None => assert!(run_binary(parameters, false, true).contains("File name contains a line break")),
+    }
+}
+
+#[cfg(unix)]
+fn do_test_dedup(multi_threading: bool, warn: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let (path_1, path_2) = (base_directory.join("frank.pdf"), base_directory.join("..").join("binary").join("frank.pdf"));
+
+    let mut parameters = vec![OsStr::new("--dedup")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    if warn {
+        parameters.push(OsStr::new("--warn"));
+    }
+    parameters.extend_from_slice(&[path_1.as_os_str(), path_1.as_os_str(), path_2.as_os_str()]);
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let results: Vec<&str> = REGEX_LINE.captures_iter(&output).map(|caps| caps.get(1).unwrap().as_str()).collect();
+
+    assert_eq!(results.len(), 1usize);
+    assert!(digest_eq(results[0usize], EXPECTED[0usize]));
+    assert_eq!(REGEX_DUPLICATE.captures_iter(&errors).count(), if warn { 2usize } else { 0usize });
+}
+
+#[cfg(unix)]
+fn do_test_dedup_hardlink(multi_threading: bool) {
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("hardlink_{:016X}", random_u64()));
+    let (path_1, path_2) = (base_dir.join("original.txt"), base_dir.join("hardlink.txt"));
+
+    create_dir(&base_dir).unwrap();
+    File::create(&path_1).unwrap().write_all(b"The quick brown fox jumps over the lazy dog").unwrap();
+    fs::hard_link(&path_1, &path_2).unwrap();
+
+    let mut parameters = vec![OsStr::new("--dedup"), OsStr::new("--summary"), OsStr::new("--warn")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.extend_from_slice(&[path_1.as_os_str(), path_2.as_os_str(), path_1.as_os_str()]);
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let results: Vec<&str> = REGEX_LINE.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec![path_1.to_str().unwrap()]);
+
+    let duplicates: Vec<&str> = REGEX_DUPLICATE.captures_iter(&errors).map(|caps| caps.get(1).unwrap().as_str()).collect();
+    assert_eq!(duplicates, vec![path_2.to_str().unwrap(), path_1.to_str().unwrap()]);
+
+    let caps = REGEX_SUMMARY.captures(&errors).expect("Summary line not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "1");
+    assert_eq!(caps.get(2).unwrap().as_str(), "43");
+}
+
+fn do_test_dry_run(recursive: bool, ndjson: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let expected_set: HashSet<&str> = if recursive {
+        HashSet::from(["frank.pdf", "dracula.pdf", "SHA512SUMS", "dorian.pdf", "LICENSE"])
+    } else {
+        HashSet::from(["frank.pdf", "dracula.pdf", "SHA512SUMS"])
+    };
+
+    let mut parameters = vec![OsStr::new("--dry-run"), OsStr::new(if recursive { "--recursive" } else { "--dirs" })];
+    if ndjson {
+        parameters.push(OsStr::new("--ndjson"));
+    }
+    parameters.push(base_directory.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let mut name_set = HashSet::with_capacity(expected_set.len());
+
+    for line in output.lines() {
+        let file_path = if ndjson { line.strip_prefix(r#"{"file":""#).and_then(|str| str.strip_suffix(r#""}"#)).expect("Invalid JSON object!") } else { line };
+        assert!(!REGEX_LINE.is_match(line));
+        assert!(name_set.insert(get_file_name(file_path)));
+    }
+
+    assert_eq!(name_set, expected_set);
+}
+
+fn do_test_files_from(from_stdin: bool, multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let expected_map = HashMap::from([("frank.pdf", EXPECTED[0usize]), ("dracula.pdf", EXPECTED[5usize])]);
+    let list_data = format!("{}\r\n\n{}\n", base_directory.join("frank.pdf").to_str().unwrap(), base_directory.join("dracula.pdf").to_str().unwrap());
+
+    let list_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("filelist_{:016X}.txt", random_u64()));
+    let mut parameters = Vec::with_capacity(3usize);
+    parameters.push(OsStr::new("--files-from"));
+
+    if from_stdin {
+        parameters.push(OsStr::new("-"));
+    } else {
+        File::create_new(&list_file).unwrap().write_all(list_data.as_bytes()).unwrap();
+        parameters.push(list_file.as_os_str());
+    }
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    let output = if from_stdin { run_binary_with_data(parameters, list_data.as_bytes()) } else { run_binary(parameters, true, false) };
+    let mut name_set = HashSet::with_capacity(expected_map.len());
+
+    for caps in REGEX_LINE.captures_iter(&output) {
+        let file_name = get_file_name(caps.get(2).unwrap().as_str());
+        let expected = expected_map.get(file_name).expect("Unknown file name!");
+        assert!(digest_eq(caps.get(1).unwrap().as_str(), expected));
+        assert!(name_set.insert(file_name.to_owned()));
+    }
+
+    assert_eq!(name_set.len(), expected_map.len());
+}
+
+fn do_test_continue_from(walk: bool, multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let expected_set: HashSet<&str> = if walk { HashSet::from(["dracula.pdf", "SHA512SUMS"]) } else { HashSet::from(["dracula.pdf"]) };
+    let checksum_data = format!("{} {}\n0d74c2e4", EXPECTED[0usize], base_directory.join("frank.pdf").to_str().unwrap());
+
+    let checksum_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("continue_{:016X}.txt", random_u64()));
+    File::create_new(&checksum_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let (frank_file, dracula_file) = (base_directory.join("frank.pdf"), base_directory.join("dracula.pdf"));
+    let mut parameters = vec![OsStr::new("--continue-from"), checksum_file.as_os_str()];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    if walk {
+        parameters.extend([OsStr::new("--dirs"), base_directory.as_os_str()]);
+    } else {
+        parameters.extend([frank_file.as_os_str(), dracula_file.as_os_str()]);
+    }
+
+    let output = run_binary(parameters, true, false);
+    let mut name_set = HashSet::with_capacity(expected_set.len());
+
+    for caps in REGEX_LINE.captures_iter(&output) {
+        let file_name = get_file_name(caps.get(2).unwrap().as_str());
+        if file_name == "dracula.pdf" {
+            assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[5usize]));
+        }
+        assert!(name_set.insert(file_name));
+    }
+
+    assert_eq!(name_set, expected_set);
+}
+
+fn do_test_null_input(verify_mode: bool, multi_threading: bool) {
+    let data: Vec<u8> = (0u16..4099u16).map(|index| (index % 251u16) as u8).collect();
+    let data_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("hello world {:016X}.bin", random_u64()));
+    File::create_new(&data_file).unwrap().write_all(&data).unwrap();
+
+    let output = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], &data);
+    let expected = REGEX_LINE.captures(&output).unwrap().get(1).unwrap().as_str().to_owned();
+
+    let mut parameters = Vec::with_capacity(4usize);
+    parameters.push(OsStr::new("--null"));
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    if verify_mode {
+        let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+        run_binary_to_file([OsStr::new("--null"), data_file.as_os_str()], &check_file, true, true);
+        parameters.extend_from_slice(&[OsStr::new("--check"), check_file.as_os_str()]);
+        let output = run_binary(parameters, true, false);
+        let caps = REGEX_CHECK_ZERO.captures(&output).expect("Regex did not match!");
+        assert_eq!(caps.get(1).unwrap().as_str(), data_file.to_str().unwrap());
+        assert_eq!(caps.get(2).unwrap().as_str(), "OK");
+    } else {
+        let list_data = [data_file.as_os_str().as_encoded_bytes(), b"\0"].concat();
+        parameters.extend_from_slice(&[OsStr::new("--files-from"), OsStr::new("-")]);
+        let output = run_binary_with_data(parameters, &list_data);
+        let (digest_hex, file_name) = output.strip_suffix('\0').unwrap().split_once(' ').unwrap();
+        assert!(digest_eq(digest_hex, &expected));
+        assert_eq!(file_name, data_file.to_str().unwrap());
+    }
+}
+
+#[cfg(unix)]
+fn do_test_null_newline(multi_threading: bool) {
+    let data_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("hello\nworld {:016X}.bin", random_u64()));
+    File::create_new(&data_file).unwrap().write_all(b"The quick brown fox jumps over the lazy dog").unwrap();
+
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    run_binary_to_file([OsStr::new("-0"), data_file.as_os_str()], &check_file, true, true);
+
+    let mut parameters = vec![OsStr::new("--check"), check_file.as_os_str()];
+    run_binary(&parameters, false, false);
+
+    parameters.push(OsStr::new("--null"));
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    let output = run_binary(&parameters, true, false);
+    assert_eq!(output, format!("{}: OK\0", data_file.to_str().unwrap()));
+}
+
+fn do_test_summary(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+
+    let mut parameters = Vec::with_capacity(paths.len() + 2usize);
+    parameters.push(OsStr::new("--summary"));
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    paths.iter().for_each(|path| parameters.push(path.as_os_str()));
+
+    let output = run_binary(parameters, true, true);
+    let caps = REGEX_SUMMARY.captures(&output).expect("Summary line not found!");
+    let expected_bytes: u64 = paths.iter().map(|path| path.metadata().unwrap().len()).sum();
+
+    assert_eq!(caps.get(1).unwrap().as_str().parse::<u64>().unwrap(), paths.len() as u64);
+    assert_eq!(caps.get(2).unwrap().as_str().parse::<u64>().unwrap(), expected_bytes);
+    assert!(caps.get(3).unwrap().as_str().parse::<f64>().unwrap() >= 0.0);
+}
+
+fn do_test_time(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+
+    let mut parameters = vec![OsStr::new("--time"), OsStr::new("--no-color")];
+    parameters.extend(paths.iter().map(|path| path.as_os_str()));
+
+    let env = match multi_threading {
+        true => {
+            parameters.push(OsStr::new("--multi-threading"));
+            HashMap::from([("SPONGE256SUM_THREAD_COUNT", "2".to_owned())])
+        }
+        false => HashMap::new(),
+    };
+
+    let output = run_binary_with_env(&parameters, env.clone(), true, false);
+    let stderr = run_binary_with_env(&parameters, env, true, true);
+
+    let digests: Vec<&str> = REGEX_LINE.captures_iter(&output).map(|caps| caps.get(1).unwrap().as_str()).collect();
+    assert_eq!(digests.len(), paths.len());
+    assert!(digests.iter().any(|digest| digest_eq(digest, EXPECTED[0usize])));
+    assert!(digests.iter().any(|digest| digest_eq(digest, EXPECTED[5usize])));
+
+    for path in paths.iter() {
+        let caps = REGEX_TIME.captures_iter(&stderr).find(|caps| caps.get(1).unwrap().as_str() == format!("{:?}", path)).expect("Time not found!");
+        assert!(caps.get(2).unwrap().as_str().parse::<f64>().is_ok());
+        assert_eq!(caps.get(3).is_some(), multi_threading);
+    }
+}
+
+fn do_test_verify_list_failed(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let tamper = |digest: &str| {
+        let mut tampered = digest.to_owned();
+        let last_char = tampered.pop().unwrap();
+        tampered.push(if last_char == '0' { '1' } else { '0' });
+        tampered
+    };
+
+    let checksum_data = format!(
+        "{} {}\n{} {}\n{} {}\n",
+        tamper(EXPECTED[0usize]),
+        base_directory.join("frank.pdf").to_str().unwrap(),
+        EXPECTED[5usize],
+        base_directory.join("dracula.pdf").to_str().unwrap(),
+        tamper(EXPECTED[5usize]),
+        base_directory.join("dracula.pdf").to_str().unwrap()
+    );
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--keep-going"), OsStr::new("--list-failed"), OsStr::new("--no-color")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, false, true);
+    let listed: Vec<&str> = output.lines().filter_map(|line| line.strip_prefix("[sponge256sum] - ")).collect();
+
+    assert_eq!(listed.len(), 2usize);
+    assert!(listed.contains(&format!("{:?}", base_directory.join("frank.pdf")).as_str()));
+    assert!(listed.contains(&format!("{:?}", base_directory.join("dracula.pdf")).as_str()));
+}
+
+fn do_test_verify_summary(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let mut tampered = EXPECTED[5usize].to_owned();
+    let last_char = tampered.pop().unwrap();
+    tampered.push(if last_char == '0' { '1' } else { '0' });
+
+    let checksum_data = format!(
+        "{} {}\n{} {}\n{} {}\n",
+        EXPECTED[0usize],
+        base_directory.join("frank.pdf").to_str().unwrap(),
+        tampered,
+        base_directory.join("dracula.pdf").to_str().unwrap(),
+        EXPECTED[0usize],
+        base_directory.join("missing.pdf").to_str().unwrap()
+    );
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--keep-going"), OsStr::new("--summary")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, false, true);
+    let caps = REGEX_VERIFY_SUMMARY.captures(&output).expect("Summary line not found!");
+    let counts: Vec<u64> = (1usize..=4usize).map(|index| caps.get(index).unwrap().as_str().parse::<u64>().unwrap()).collect();
+
+    assert_eq!(counts, vec![3u64, 1u64, 1u64, 1u64]);
+}
+
+fn do_test_tree(thread_count: Option<usize>, use_stdin: bool) {
+    let data: Vec<u8> = (0usize..((11usize * TREE_LEAF_SIZE) / 2usize)).map(|index| (index % 251usize) as u8).collect();
+    let expected: [u8; DEFAULT_DIGEST_SIZE] = compute_tree(&data);
+
+    let output = if use_stdin {
+        run_binary_with_data([OsStr::new("--tree")], &data)
+    } else {
+        let input_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("input_{:016X}.bin", random_u64()));
+        File::create_new(&input_file).unwrap().write_all(&data).unwrap();
+
+        let mut parameters = vec![OsStr::new("--tree"), input_file.as_os_str()];
+        let env = match thread_count {
+            Some(count) => {
+                parameters.push(OsStr::new("--multi-threading"));
+                HashMap::from([("SPONGE256SUM_THREAD_COUNT", count.to_string())])
+            }
+            None => HashMap::new(),
+        };
+        run_binary_with_env(parameters, env, true, false)
+    };
+
+    let caps = REGEX_LINE.captures(&output).expect("Regex did not match!");
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), &hex::encode(expected)));
+}
+
+fn do_test_tree_digest(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("tree_{:016X}", random_u64()));
+    let sub_directory = base_directory.join("foo").join("bar");
+    create_dir_all(&sub_directory).unwrap();
+
+    for (index, file_name) in [base_directory.join("a.txt"), base_directory.join("foo").join("b.txt"), sub_directory.join("c.txt")].iter().enumerate() {
+        write!(File::create_new(file_name).unwrap(), "Hello, world! #{}", index).unwrap();
+    }
+
+    let run_tree_digest = |exclude: Option<&str>| {
+        let mut parameters = vec![OsStr::new("--tree-digest"), base_directory.as_os_str()];
+        if multi_threading {
+            parameters.push(OsStr::new("--multi-threading"));
+        }
+        if let Some(pattern) = exclude {
+            parameters.extend_from_slice(&[OsStr::new("--exclude"), OsStr::new(pattern)]);
+        }
+        let output = run_binary(parameters, true, false);
+        assert_eq!(output.lines().count(), 1usize);
+        let digest = output.trim_end().to_owned();
+        assert!(digest.len() == 2usize * DEFAULT_DIGEST_SIZE && digest.chars().all(|c| c.is_ascii_hexdigit()));
+        digest
+    };
+
+    let digest_1 = run_tree_digest(None);
+    let digest_2 = run_tree_digest(None);
+    assert!(digest_eq(&digest_1, &digest_2));
+
+    write!(File::options().append(true).open(sub_directory.join("c.txt")).unwrap(), "!").unwrap();
+
+    let digest_3 = run_tree_digest(None);
+    assert!(!digest_eq(&digest_1, &digest_3));
+
+    write!(File::create_new(sub_directory.join("d.log")).unwrap(), "Ignored").unwrap();
+
+    let digest_4 = run_tree_digest(Some("*.log"));
+    assert!(digest_eq(&digest_3, &digest_4));
+
+    let digest_5 = run_tree_digest(None);
+    assert!(!digest_eq(&digest_3, &digest_5));
+}
+
+fn do_test_queue_limit(queue_limit: &str) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("queue_limit_{:016X}", random_u64()));
+    let mut expected = HashSet::new();
+
+    for index in 0usize..32usize {
+        let sub_directory = base_directory.join(format!("dir_{:02}", index)).join("sub");
+        create_dir_all(&sub_directory).unwrap();
+        for file_name in [sub_directory.parent().unwrap().join("a.txt"), sub_directory.join("b.txt")] {
+            write!(File::create_new(&file_name).unwrap(), "{}", index).unwrap();
+            expected.insert(file_name);
+        }
+    }
+
+    let env = HashMap::from([("SPONGE256SUM_DIRWALK_STRATEGY", "BFS".to_owned()), ("SPONGE256SUM_DIRWALK_QUEUE_LIMIT", queue_limit.to_owned())]);
+    let output = run_binary_with_env([OsStr::new("--recursive"), base_directory.as_os_str()], env, true, false);
+
+    let names: Vec<PathBuf> = REGEX_LINE.captures_iter(&output).map(|caps| PathBuf::from(caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(names.len(), expected.len());
+    assert_eq!(names.into_iter().collect::<HashSet<_>>(), expected);
+}
+
+#[cfg(unix)]
+fn do_test_xattr(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("xattr_{:016X}", random_u64()));
+    create_dir_all(&base_directory).unwrap();
+
+    let file_names = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+    for file_name in file_names.iter() {
+        fs::copy(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join(file_name.file_name().unwrap()), file_name).unwrap();
+    }
+
+    let errors = run_binary([OsStr::new("--check-xattr"), file_names[0usize].as_os_str()], false, true);
+    if errors.contains("Extended attributes are not supported") {
+        return; /* file system does not support extended attributes */
+    }
+    assert!(errors.contains("Checksum attribute not found"));
+
+    let mut parameters = vec![OsStr::new("--write-xattr")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.extend(file_names.iter().map(|file_name| file_name.as_os_str()));
+
+    let output = run_binary(&parameters, true, false);
+    assert_eq!(REGEX_LINE.captures_iter(&output).count(), 2usize);
+
+    parameters[0usize] = OsStr::new("--check-xattr");
+    let output = run_binary(&parameters, true, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["OK"; 2usize]);
+
+    fs::OpenOptions::new().append(true).open(&file_names[1usize]).unwrap().write_all(b"\n").unwrap();
+    let output = run_binary(&parameters, false, false);
+    let results: HashSet<(&str, &str)> =
+        REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(results, HashSet::from([(file_names[0usize].to_str().unwrap(), "OK"), (file_names[1usize].to_str().unwrap(), "FAILED")]));
+}
+
+fn do_test_sorted(depth_first: bool) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("sorted_{:016X}", random_u64()));
+    let sub_directory = base_directory.join("sub");
+    create_dir_all(&sub_directory).unwrap();
+
+    for file_name in
+        [base_directory.join("c.txt"), sub_directory.join("z.txt"), base_directory.join("a.txt"), sub_directory.join("y.txt"), base_directory.join("b.txt")]
+    {
+        write!(File::create_new(&file_name).unwrap(), "{}", file_name.file_name().unwrap().to_str().unwrap()).unwrap();
+    }
+
+    let strategy = if depth_first { "dfs" } else { "bfs" };
+    let run_sorted = || {
+        let env = HashMap::from([("SPONGE256SUM_DIRWALK_STRATEGY", strategy.to_owned())]);
+        run_binary_with_env([OsStr::new("--sorted"), OsStr::new("--recursive"), base_directory.as_os_str()], env, true, false)
+    };
+
+    let output_1 = run_sorted();
+    let output_2 = run_sorted();
+    assert_eq!(output_1, output_2);
+
+    let names: Vec<PathBuf> = REGEX_LINE.captures_iter(&output_1).map(|caps| PathBuf::from(caps.get(2).unwrap().as_str())).collect();
+    let expected =
+        [base_directory.join("a.txt"), base_directory.join("b.txt"), base_directory.join("c.txt"), sub_directory.join("y.txt"), sub_directory.join("z.txt")];
+    assert_eq!(names, expected);
+}
+
+fn do_verify_chunked(chunk_size: usize) {
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("chunked_{:016X}", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    create_dir_all(&base_dir).unwrap();
+
+    let mut check_data = BufWriter::new(File::create_new(&check_file).unwrap());
+    for index in 0usize..4096usize {
+        let file_name = base_dir.join(format!("file_{:04}.txt", index));
+        let content = format!("Content of file #{index}");
+        File::create_new(&file_name).unwrap().write_all(content.as_bytes()).unwrap();
+        let digest: [u8; DEFAULT_DIGEST_SIZE] = compute(None, &content);
+        match index {
+            1000usize => writeln!(check_data, "# comment line").unwrap(),
+            3333usize => writeln!(check_data, "malformed line").unwrap(),
+            _ => writeln!(check_data, "{} {}", hex::encode(digest), file_name.to_str().unwrap()).unwrap(),
+        }
+    }
+    drop(check_data);
+
+    let parameters = [OsStr::new("--check"), OsStr::new("--warn"), OsStr::new("--multi-threading"), check_file.as_os_str()];
+    let do_verify = |chunk_size: usize, force_stderr: bool| {
+        let env = HashMap::from([("SPONGE256SUM_THREAD_COUNT", String::from("4")), ("SPONGE256SUM_PARSE_CHUNK_SIZE", chunk_size.to_string())]);
+        run_binary_with_env(parameters, env, true, force_stderr)
+    };
+
+    let (output_serial, output_chunked) = (do_verify(usize::MAX, false), do_verify(chunk_size, false));
+    let mut results_serial: Vec<&str> = output_serial.lines().collect();
+    let mut results_chunked: Vec<&str> = output_chunked.lines().collect();
+    results_serial.sort_unstable();
+    results_chunked.sort_unstable();
+
+    assert_eq!(results_chunked.len(), 4094usize);
+    assert!(results_chunked.iter().all(|line| line.ends_with(": OK")));
+    assert_eq!(results_serial, results_chunked);
+
+    let (errors_serial, errors_chunked) = (do_verify(usize::MAX, true), do_verify(chunk_size, true));
+    let lines_serial: Vec<&str> = REGEX_IMPROPER.captures_iter(&errors_serial).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    let lines_chunked: Vec<&str> = REGEX_IMPROPER.captures_iter(&errors_chunked).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(lines_chunked, vec!["3334"]);
+    assert_eq!(lines_serial, lines_chunked);
+}
+
+fn do_test_regenerate(multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("regenerate_{:016X}", random_u64()));
+    let (check_file, output_file) = (base_directory.join("checksums.txt"), base_directory.join("updated.txt"));
+    create_dir_all(&base_directory).unwrap();
+
+    let file_names = [base_directory.join("a.txt"), base_directory.join("b.txt"), base_directory.join("c.txt"), base_directory.join("missing.txt")];
+    for file_name in file_names[..3usize].iter() {
+        write!(File::create_new(file_name).unwrap(), "{}", file_name.file_name().unwrap().to_str().unwrap()).unwrap();
+    }
+
+    let digests: Vec<[u8; DEFAULT_DIGEST_SIZE]> = ["a.txt", "b.txt", "c.txt", "missing.txt"].iter().map(|data| compute(None, data)).collect();
+    let names: Vec<&str> = file_names.iter().map(|file_name| file_name.to_str().unwrap()).collect();
+    let checksum_data = format!(
+        "# comment\n{} {}\n{} {}\nnot a checksum line\nSpongeHash256-192 ({}) = {}\n{} {}\n",
+        hex::encode(digests[0usize]),
+        names[0usize],
+        hex::encode(&digests[1usize][..16usize]),
+        names[1usize],
+        names[2usize],
+        hex::encode(&digests[2usize][..24usize]),
+        hex::encode(digests[3usize]),
+        names[3usize]
+    );
+    File::create_new(&check_file).unwrap().write_all(checksum_data.as_bytes()).unwrap();
+    File::create(&file_names[1usize]).unwrap().write_all(b"modified").unwrap();
+
+    let env = HashMap::from([("SPONGE256SUM_THREAD_COUNT", if multi_threading { "3" } else { "1" }.to_owned())]);
+    let output = run_binary_with_env([OsStr::new("--check"), OsStr::new("--regenerate"), output_file.as_os_str(), check_file.as_os_str()], env, false, false);
+    let mut results: Vec<(&str, &str)> =
+        REGEX_CHECK.captures_iter(&output).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    results.sort();
+    assert_eq!(results, vec![(names[0usize], "OK"), (names[1usize], "FAILED"), (names[2usize], "OK")]);
+
+    let digest_modified: [u8; DEFAULT_DIGEST_SIZE] = compute(None, "modified");
+    let expected = format!(
+        "# comment\n{} {}\n{} {}\nnot a checksum line\nSpongeHash256-192 ({}) = {}\n{} {}\n",
+        hex::encode(digests[0usize]),
+        names[0usize],
+        hex::encode(&digest_modified[..16usize]),
+        names[1usize],
+        names[2usize],
+        hex::encode(&digests[2usize][..24usize]),
+        hex::encode(digests[3usize]),
+        names[3usize]
+    );
+    assert_eq!(read_to_string(&output_file).unwrap(), expected);
+
+    let output = run_binary([OsStr::new("--check"), OsStr::new("--keep-going"), output_file.as_os_str()], false, false);
+    assert_eq!(REGEX_CHECK.captures_iter(&output).filter(|caps| caps.get(2).unwrap().as_str() == "OK").count(), 3usize);
+
+    let output =
+        run_binary([OsStr::new("--check"), OsStr::new("--regenerate"), output_file.as_os_str(), check_file.as_os_str(), check_file.as_os_str()], false, true);
+    assert!(output.contains("exactly one checksum file"));
+}
+
+fn do_test_max_size(explicit: bool) {
+    let base_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("max_size_{:016X}", random_u64()));
+    create_dir_all(&base_directory).unwrap();
+
+    let (small_file, large_file) = (base_directory.join("small.txt"), base_directory.join("large.bin"));
+    File::create_new(&small_file).unwrap().write_all(&[0x41u8; 1000usize]).unwrap();
+    File::create_new(&large_file).unwrap().write_all(&[0x42u8; 1025usize]).unwrap();
+
+    let mut parameters = vec![OsStr::new("--max-size"), OsStr::new("1000"), OsStr::new("--warn"), OsStr::new("--dirs"), base_directory.as_os_str()];
+    if explicit {
+        parameters.push(large_file.as_os_str());
+    }
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let mut names: Vec<PathBuf> = REGEX_LINE.captures_iter(&output).map(|caps| PathBuf::from(caps.get(2).unwrap().as_str())).collect();
+    names.sort();
+    assert_eq!(names, if explicit { vec![large_file.clone(), small_file] } else { vec![small_file] });
+
+    let skipped: Vec<(&str, &str)> =
+        REGEX_OVERSIZED.captures_iter(&errors).map(|caps| (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(skipped, vec![(large_file.to_str().unwrap(), "1025")]);
+}
+
+fn do_test_wipe(tree_mode: bool) {
+    let data: Vec<u8> = (0usize..((11usize * TREE_LEAF_SIZE) / 2usize)).map(|index| (index % 251usize) as u8).collect();
+    let expected: [u8; DEFAULT_DIGEST_SIZE] = if tree_mode { compute_tree(&data) } else { compute(None, &data) };
+
+    let mut parameters = vec![OsStr::new("--wipe")];
+    if tree_mode {
+        parameters.push(OsStr::new("--tree"));
+    }
+
+    let output = run_binary_with_data(parameters, &data);
+    let caps = REGEX_LINE.captures(&output).expect("Regex did not match!");
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), &hex::encode(expected)));
+}
+
+fn do_test_snail_target(target_millis: &str) {
+    const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    let input_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("input_{:016X}.txt", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    File::create_new(&input_file).unwrap().write_all(MESSAGE).unwrap();
+
+    let (output, error) =
+        run_binary_with_stderr([OsStr::new("--snail-target"), OsStr::new(target_millis), OsStr::new("--show-rounds"), input_file.as_os_str()]);
+    let caps = REGEX_SNAIL_TARGET.captures(&error).expect("Snail target line not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), target_millis);
+
+    let rounds = caps.get(2).unwrap().as_str().parse::<NonZeroUsize>().unwrap();
+    assert!(rounds.get() <= 65521usize);
+
+    let mut hasher = SpongeHash256Builder::new().rounds(rounds).build();
+    hasher.update(MESSAGE);
+    let expected = format!("{}@{}", hex::encode(hasher.digest::<DEFAULT_DIGEST_SIZE>()), rounds);
+    assert!(output.trim_ascii_end().starts_with(&expected));
+
+    File::create_new(&check_file).unwrap().write_all(output.as_bytes()).unwrap();
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], true, false);
+    assert!(output.trim_ascii_end().ends_with(": OK"));
+}
+
+fn do_test_show_rounds(tagged: bool, multi_threading: bool) {
+    let input_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("input_{:016X}.txt", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    File::create_new(&input_file).unwrap().write_all(b"The quick brown fox jumps over the lazy dog").unwrap();
+
+    let mut parameters = vec![OsStr::new("--snail"), OsStr::new("--snail"), OsStr::new("--show-rounds")];
+    if tagged {
+        parameters.push(OsStr::new("--tag"));
+    }
+    parameters.push(input_file.as_os_str());
+    run_binary_to_file(parameters, &check_file, true, true);
+    assert!(read_to_string(&check_file).unwrap().trim_ascii_end().contains("@251"));
+
+    let mut parameters = vec![OsStr::new("--check")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["OK"]);
+
+    let content = read_to_string(&check_file).unwrap().replace("@251", "");
+    File::create(&check_file).unwrap().write_all(content.as_bytes()).unwrap();
+
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], false, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["FAILED"]);
+}
+
+fn do_test_relative_to(multi_threading: bool) {
+    let work_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("relative_{:016X}", random_u64()));
+    let (base_dir, other_dir) = (work_dir.join("base"), work_dir.join("other"));
+    let (path_1, path_2, check_file) = (base_dir.join("sub").join("a.txt"), other_dir.join("b.txt"), work_dir.join("checksums.txt"));
+
+    create_dir_all(base_dir.join("sub")).unwrap();
+    create_dir_all(&other_dir).unwrap();
+    File::create_new(&path_1).unwrap().write_all(b"The quick brown fox").unwrap();
+    File::create_new(&path_2).unwrap().write_all(b"jumps over the lazy dog").unwrap();
+
+    let mut parameters = vec![OsStr::new("--relative-to"), base_dir.as_os_str()];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.extend_from_slice(&[path_1.as_os_str(), path_2.as_os_str()]);
+    run_binary_to_file(parameters, &check_file, true, true);
+
+    let output = read_to_string(&check_file).unwrap();
+    let names: HashSet<PathBuf> = REGEX_LINE.captures_iter(&output).map(|caps| PathBuf::from(caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(names, HashSet::from([Path::new("sub").join("a.txt"), Path::new("..").join("other").join("b.txt")]));
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--base-dir"), base_dir.as_os_str()];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["OK", "OK"]);
+
+    run_binary([OsStr::new("--check"), check_file.as_os_str()], false, false);
+}
+
+#[cfg(feature = "with-tar")]
+fn do_test_into_archives(multi_threading: bool) {
+    const MEMBERS: [(&str, &[u8]); 2usize] = [("docs/fox.txt", b"The quick brown fox"), ("dog.txt", b"jumps over the lazy dog")];
+
+    let archive_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("archive_{:016X}.tar", random_u64()));
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let mut builder = tar::Builder::new(File::create_new(&archive_file).unwrap());
+    for (name, data) in MEMBERS {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        builder.append_data(&mut header, name, data).unwrap();
+    }
+    builder.into_inner().unwrap();
+
+    let mut parameters = vec![OsStr::new("--into-archives")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(archive_file.as_os_str());
+    run_binary_to_file(parameters, &check_file, true, true);
+
+    let output = read_to_string(&check_file).unwrap();
+    let results: HashMap<String, String> =
+        REGEX_LINE.captures_iter(&output).map(|caps| (caps.get(2).unwrap().as_str().to_owned(), caps.get(1).unwrap().as_str().to_owned())).collect();
+    assert_eq!(results.len(), MEMBERS.len());
+
+    for (name, data) in MEMBERS {
+        let member_name = format!("{}!{}", archive_file.to_str().unwrap(), name);
+        assert!(digest_eq(&results[&member_name], &hex::encode(compute::<DEFAULT_DIGEST_SIZE, _>(None, data))));
+    }
+
+    let mut parameters = vec![OsStr::new("--check"), OsStr::new("--into-archives")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let results: Vec<&str> = REGEX_CHECK.captures_iter(&output).map(|caps| caps.get(2).unwrap().as_str()).collect();
+    assert_eq!(results, vec!["OK", "OK"]);
+
+    run_binary([OsStr::new("--check"), check_file.as_os_str()], false, false);
+}
+
+fn do_test_block_stdin(record_size: Option<usize>) {
+    const RECORDS: [&[u8]; 3usize] = [b"The quick brown fox ", b"jumps over the lazy ", b"dog"];
+
+    let record_size_str = record_size.map(|value| value.to_string());
+    let mut parameters = vec![OsStr::new("--block-stdin")];
+    let data = match record_size_str.as_ref() {
+        Some(record_size_str) => {
+            parameters.extend_from_slice(&[OsStr::new("--record-size"), OsStr::new(record_size_str)]);
+            RECORDS.concat()
+        }
+        None => RECORDS.join(&b'\n'),
+    };
+
+    let output = run_binary_with_data(parameters, &data);
+    let digests: Vec<&str> = REGEX_LINE.captures_iter(&output).map(|caps| caps.get(1).unwrap().as_str()).collect();
+    assert_eq!(digests.len(), RECORDS.len());
+
+    for (digest, record) in digests.iter().zip(RECORDS) {
+        assert!(digest_eq(digest, &hex::encode(compute::<DEFAULT_DIGEST_SIZE, _>(None, record))));
+    }
+}
+
+fn do_verify_tagged(length: Option<u32>, multi_threading: bool) {
+    let base_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let paths = [base_directory.join("frank.pdf"), base_directory.join("dracula.pdf")];
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+
+    let length_str = length.map(|value| value.to_string());
+    let mut parameters = Vec::with_capacity(paths.len() + 3usize);
+    parameters.push(OsStr::new("--tag"));
+
+    if let Some(length_str) = length_str.as_ref() {
+        parameters.extend_from_slice(&[OsStr::new("--length"), OsStr::new(length_str)]);
+    }
+
+    paths.iter().for_each(|path| parameters.push(path.as_os_str()));
+    run_binary_to_file(parameters, &check_file, true, true);
+
+    let expected_bits = length.unwrap_or(256u32);
+    for line in BufReader::new(File::open(&check_file).unwrap()).lines() {
+        let caps = REGEX_TAGGED.captures(line.as_ref().unwrap()).expect("Regex did not match!");
+        assert_eq!(caps.get(1).unwrap().as_str().parse::<u32>().unwrap(), expected_bits);
+        assert_eq!(caps.get(3).unwrap().as_str().len(), (expected_bits / 4u32) as usize);
+    }
+
+    let mut parameters = Vec::with_capacity(3usize);
+    parameters.push(OsStr::new("--check"));
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    parameters.push(check_file.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let mut result_set = HashSet::with_capacity(paths.len());
+
+    for caps in REGEX_CHECK.captures_iter(&output) {
+        let (file_name, result) = (caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str());
+        assert_eq!(result, "OK");
+        assert!(result_set.insert(get_file_name(file_name).to_owned()));
+    }
+
+    assert_eq!(result_set.len(), paths.len());
+}
+
+fn do_test_exclude(patterns: &[&str], expected_names: &[&str], multi_threading: bool) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+    let mut parameters = Vec::with_capacity(2usize * patterns.len() + 3usize);
+    parameters.push(OsStr::new("--recursive"));
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    for pattern in patterns {
+        parameters.extend_from_slice(&[OsStr::new("--exclude"), OsStr::new(pattern)]);
+    }
+
+    parameters.push(path.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let name_set: HashSet<&str> = REGEX_LINE.captures_iter(&output).map(|caps| get_file_name(caps.get(2).unwrap().as_str())).collect();
+
+    assert!(!name_set.iter().any(|file_name| patterns.iter().any(|pattern| file_name.ends_with(pattern.trim_start_matches('*')))));
+    expected_names.iter().for_each(|file_name| assert!(name_set.contains(file_name)));
+}
+
+#[cfg(unix)]
+fn do_test_symlinks(follow_symlinks: bool, multi_threading: bool) {
+    let outside_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("symlinks_{:016X}", random_u64()));
+
+    create_dir(&base_dir).unwrap();
+    File::create(base_dir.join("inside.txt")).unwrap().write_all(b"justsomearbitrarydatainthefile\n").unwrap();
+    symlink(&outside_dir, base_dir.join("outside")).unwrap();
+    symlink(outside_dir.join("frank.pdf"), base_dir.join("linked.pdf")).unwrap();
+
+    let mut parameters = Vec::with_capacity(4usize);
+    parameters.push(OsStr::new("--recursive"));
+
+    if follow_symlinks {
+        parameters.push(OsStr::new("--follow-symlinks"));
+    }
+
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+
+    parameters.push(base_dir.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let name_set: HashSet<&str> = REGEX_LINE.captures_iter(&output).map(|caps| get_file_name(caps.get(2).unwrap().as_str())).collect();
+
+    assert!(name_set.contains("inside.txt"));
+    assert_eq!(name_set.contains("linked.pdf"), follow_symlinks);
+    assert_eq!(name_set.contains("dracula.pdf"), follow_symlinks);
+    assert_eq!(name_set.contains("dorian.pdf"), follow_symlinks);
+}
+
+#[cfg(unix)]
+fn do_test_cycle(multi_threading: bool, warn: bool) {
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("cycle_{:016X}", random_u64()));
+    let sub_dir = base_dir.join("subdir");
+
+    create_dir(&base_dir).unwrap();
+    create_dir(&sub_dir).unwrap();
+    File::create(sub_dir.join("inside.txt")).unwrap().write_all(b"justsomearbitrarydatainthefile\n").unwrap();
+    symlink(&base_dir, sub_dir.join("loop")).unwrap();
+
+    let mut parameters = vec![OsStr::new("--recursive"), OsStr::new("--follow-symlinks")];
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    if warn {
+        parameters.push(OsStr::new("--warn"));
+    }
+    parameters.push(base_dir.as_os_str());
+
+    let (output, errors) = run_binary_with_stderr(parameters);
+    let name_set: Vec<&str> = REGEX_LINE.captures_iter(&output).map(|caps| get_file_name(caps.get(2).unwrap().as_str())).collect();
+    assert_eq!(name_set, vec!["inside.txt"]);
+
+    let cycles: Vec<&str> = REGEX_CYCLE.captures_iter(&errors).map(|caps| get_file_name(caps.get(1).unwrap().as_str())).collect();
+    assert_eq!(cycles, if warn { vec!["loop"] } else { vec![] });
+}
+
+#[cfg(unix)]
+fn do_test_fifo(all_files: bool, multi_threading: bool) {
+    const DATA: &[u8] = b"justsomearbitrarydatathatissentthroughthepipe\n";
+    let base_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("fifo_{:016X}", random_u64()));
+    let fifo_path = base_dir.join("pipe");
+
+    create_dir(&base_dir).unwrap();
+    File::create(base_dir.join("regular.txt")).unwrap().write_all(DATA).unwrap();
+    mkfifo(&fifo_path, Mode::S_IRUSR | Mode::S_IWUSR).unwrap();
+
+    let writer = all_files.then(|| thread::spawn(move || File::options().write(true).open(fifo_path).unwrap().write_all(DATA).unwrap()));
+
+    let mut parameters = vec![OsStr::new("--dirs")];
+    if all_files {
+        parameters.push(OsStr::new("--all"));
+    }
+    if multi_threading {
+        parameters.push(OsStr::new("--multi-threading"));
+    }
+    parameters.push(base_dir.as_os_str());
+
+    let output = run_binary(parameters, true, false);
+    let result_map: HashMap<&str, &str> =
+        REGEX_LINE.captures_iter(&output).map(|caps| (get_file_name(caps.get(2).unwrap().as_str()), caps.get(1).unwrap().as_str())).collect();
+
+    if let Some(handle) = writer {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(result_map.len(), if all_files { 2usize } else { 1usize });
+    assert!(result_map.contains_key("regular.txt"));
+    if all_files {
+        assert_eq!(result_map.get("pipe"), result_map.get("regular.txt"));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// File tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_file_1a() {
+    do_test_file(EXPECTED[0usize], "frank.pdf", false, 0usize, false);
+}
+
+#[test]
+fn test_file_1b() {
+    do_test_file(EXPECTED[1usize], "frank.pdf", false, 1usize, false);
+}
+
+#[test]
+fn test_file_1c() {
+    do_test_file(EXPECTED[2usize], "frank.pdf", false, 2usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_file_1d() {
+    do_test_file(EXPECTED[3usize], "frank.pdf", false, 3usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_file_1e() {
+    do_test_file(EXPECTED[4usize], "frank.pdf", false, 4usize, false);
+}
+
+#[test]
+fn test_file_2a() {
+    do_test_file(EXPECTED[5usize], "dracula.pdf", false, 0usize, false);
+}
+
+#[test]
+fn test_file_2b() {
+    do_test_file(EXPECTED[6usize], "dracula.pdf", false, 1usize, false);
+}
+
+#[test]
+fn test_file_2c() {
+    do_test_file(EXPECTED[7usize], "dracula.pdf", false, 2usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_file_2d() {
+    do_test_file(EXPECTED[8usize], "dracula.pdf", false, 3usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_file_2e() {
+    do_test_file(EXPECTED[9usize], "dracula.pdf", false, 4usize, false);
+}
+
+#[test]
+fn test_file_3a() {
+    do_test_file(EXPECTED[0usize], "frank.pdf", false, 0usize, true);
+}
+
+#[test]
+fn test_file_3b() {
+    do_test_file(EXPECTED[5usize], "dracula.pdf", false, 0usize, true);
+}
+
+#[test]
+fn test_file_with_buffer_1a() {
+    do_test_file_with_buffer(EXPECTED[0usize], "frank.pdf", "1");
+}
+
+#[test]
+fn test_file_with_buffer_1b() {
+    do_test_file_with_buffer(EXPECTED[0usize], "frank.pdf", "65536");
+}
+
+#[test]
+fn test_file_with_buffer_1c() {
+    do_test_file_with_buffer(EXPECTED[5usize], "dracula.pdf", "4096");
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// File tests with info
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_file_with_len_1a() {
+    do_test_file_with_length(EXPECTED[10usize], "frank.pdf", 512u32, false);
+}
+
+#[test]
+fn test_file_with_len_1b() {
+    do_test_file_with_length(EXPECTED[11usize], "frank.pdf", 192u32, false);
+}
+
+#[test]
+fn test_file_with_len_1c() {
+    do_test_file_with_length(&EXPECTED[0usize][..2usize], "frank.pdf", 8u32, false);
+}
+
+#[test]
+fn test_file_with_len_2a() {
+    do_test_file_with_length(EXPECTED[12usize], "dracula.pdf", 512u32, false);
+}
+
+#[test]
+fn test_file_with_len_2b() {
+    do_test_file_with_length(EXPECTED[13usize], "dracula.pdf", 192u32, false);
+}
+
+#[test]
+fn test_file_with_len_3a() {
+    do_test_file_with_length(EXPECTED[0usize], "frank.pdf", 32u32, true);
+}
+
+#[test]
+fn test_file_with_len_3b() {
+    do_test_file_with_length(EXPECTED[10usize], "frank.pdf", 64u32, true);
+}
+
+#[test]
+fn test_file_with_len_3c() {
+    do_test_file_with_length(EXPECTED[13usize], "dracula.pdf", 24u32, true);
+}
+
+#[test]
+fn test_file_with_info_1a() {
+    do_test_file_with_info(EXPECTED[14usize], "frank.pdf", "whatchamacallit", 0usize);
+}
+
+#[test]
+fn test_file_with_info_1b() {
+    do_test_file_with_info(EXPECTED[15usize], "frank.pdf", "thingamabob", 0usize);
+}
+
+#[test]
+fn test_file_with_info_1c() {
+    do_test_file_with_info(EXPECTED[16usize], "frank.pdf", "whatchamacallit", 1usize);
+}
+
+#[test]
+fn test_file_with_info_1d() {
+    do_test_file_with_info(EXPECTED[17usize], "frank.pdf", "thingamabob", 2usize);
+}
+
+#[test]
+#[ignore]
+fn test_file_with_info_1e() {
+    do_test_file_with_info(EXPECTED[18usize], "frank.pdf", "whatchamacallit", 3usize);
+}
+
+#[test]
+#[ignore]
+fn test_file_with_info_1f() {
+    do_test_file_with_info(EXPECTED[19usize], "frank.pdf", "thingamabob", 4usize);
+}
+
+#[test]
+fn test_file_with_info_2a() {
+    do_test_file_with_info(EXPECTED[20usize], "dracula.pdf", "whatchamacallit", 0usize);
+}
+
+#[test]
+fn test_file_with_info_2b() {
+    do_test_file_with_info(EXPECTED[21usize], "dracula.pdf", "thingamabob", 0usize);
+}
+
+#[test]
+fn test_file_with_info_2c() {
+    do_test_file_with_info(EXPECTED[22usize], "dracula.pdf", "whatchamacallit", 1usize);
+}
+
+#[test]
+fn test_file_with_info_2d() {
+    do_test_file_with_info(EXPECTED[23usize], "dracula.pdf", "thingamabob", 2usize);
+}
+
+#[test]
+#[ignore]
+fn test_file_with_info_2e() {
+    do_test_file_with_info(EXPECTED[24usize], "dracula.pdf", "whatchamacallit", 3usize);
+}
+
+#[test]
+#[ignore]
+fn test_file_with_info_2f() {
+    do_test_file_with_info(EXPECTED[25usize], "dracula.pdf", "thingamabob", 4usize);
+}
+
+#[test]
+fn test_file_with_info_3a() {
+    do_test_file_with_info_file("frank.pdf", "foo", "");
+}
+
+#[test]
+fn test_file_with_info_3b() {
+    do_test_file_with_info_file("frank.pdf", "foo", "\n");
+}
+
+#[test]
+fn test_file_with_info_3c() {
+    do_test_file_with_info_file("frank.pdf", "foo", "\r\n");
+}
+
+#[test]
+fn test_file_with_info_4a() {
+    do_test_file_with_info_hex("frank.pdf", "abc", "616263");
+}
+
+#[test]
+fn test_file_with_info_4b() {
+    do_test_file_with_info_hex("dracula.pdf", "\u{e4}bc", "C3A46263");
+}
+
+#[test]
+fn test_file_with_info_4c() {
+    let output = run_binary(["--info-hex", "xyz", "frank.pdf"], false, true);
+    assert!(output.contains("Context info must be a hex string"));
+}
+
+#[test]
+fn test_file_with_info_4d() {
+    let output = run_binary(["--info-hex", &"00".repeat(256usize), "frank.pdf"], false, true);
+    assert!(output.contains("Context info must be a hex string"));
+}
+
+#[test]
+fn test_file_with_info_4e() {
+    let output = run_binary(["--info", "abc", "--info-hex", "616263", "frank.pdf"], false, true);
+    assert!(REGEX_MUTEX.is_match(&output))
+}
+
+#[test]
+fn test_file_with_salt_1a() {
+    do_test_file_with_salt("frank.pdf", "00112233", "00112234");
+}
+
+#[test]
+fn test_file_with_salt_1b() {
+    do_test_file_with_salt("dracula.pdf", "DEADBEEF", "deadbeef00");
+}
+
+#[test]
+fn test_file_with_salt_2() {
+    let output = run_binary(["--salt", "xyz", "frank.pdf"], false, true);
+    assert!(output.contains("Salt must be a hex string"));
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Text file tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_text_file_1a() {
+    do_test_file(EXPECTED[26usize], "alice29.txt", true, 0usize, false);
+}
+
+#[test]
+fn test_text_file_1b() {
+    do_test_file(EXPECTED[27usize], "alice29.txt", true, 1usize, false);
+}
+
+#[test]
+fn test_text_file_1c() {
+    do_test_file(EXPECTED[28usize], "alice29.txt", true, 2usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_text_file_1d() {
+    do_test_file(EXPECTED[29usize], "alice29.txt", true, 3usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_text_file_1e() {
+    do_test_file(EXPECTED[30usize], "alice29.txt", true, 4usize, false);
+}
+
+#[test]
+fn test_text_file_2a() {
+    do_test_file(EXPECTED[31usize], "asyoulik.txt", true, 0usize, false);
+}
+
+#[test]
+fn test_text_file_2b() {
+    do_test_file(EXPECTED[32usize], "asyoulik.txt", true, 1usize, false);
+}
+
+#[test]
+fn test_text_file_2c() {
+    do_test_file(EXPECTED[33usize], "asyoulik.txt", true, 2usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_text_file_2d() {
+    do_test_file(EXPECTED[34usize], "asyoulik.txt", true, 3usize, false);
+}
+
+#[test]
+#[ignore]
+fn test_text_file_2e() {
+    do_test_file(EXPECTED[35usize], "asyoulik.txt", true, 4usize, false);
+}
+
+#[test]
+fn test_text_file_3a() {
+    let text_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("text_{:016X}.txt", random_u64()));
+    File::create_new(&text_file).unwrap().write_all(b"Caf\xE9 cr\xE8me\r\nna\xEFve\rr\xE9sum\xE9\r\n\r\nend\r\n").unwrap();
+
+    let expected = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], b"Caf\xE9 cr\xE8me\nna\xEFve\rr\xE9sum\xE9\n\nend");
+    let output = run_binary([OsStr::new("--text"), text_file.as_os_str()], true, false);
+
+    let (caps0, caps1) = (REGEX_LINE.captures(&expected).unwrap(), REGEX_LINE.captures(&output).unwrap());
+    assert_eq!(caps0.get(1).unwrap().as_str(), caps1.get(1).unwrap().as_str());
+}
+
+#[test]
+fn test_text_file_3b() {
+    let text_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("text_{:016X}.txt", random_u64()));
+    File::create_new(&text_file).unwrap().write_all(b"first\rsecond\r\nthird\r").unwrap();
+
+    let expected = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], b"first\nsecond\nthird");
+    let output = run_binary([OsStr::new("--text"), OsStr::new("--cr-newline"), text_file.as_os_str()], true, false);
+
+    let (caps0, caps1) = (REGEX_LINE.captures(&expected).unwrap(), REGEX_LINE.captures(&output).unwrap());
+    assert_eq!(caps0.get(1).unwrap().as_str(), caps1.get(1).unwrap().as_str());
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Multi file tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_multi_file_1a() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_multi_file(&expected, NonZeroUsize::MIN);
+}
+
+#[test]
+fn test_multi_file_1b() {
     let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
     do_test_multi_file(&expected, NonZeroUsize::new(expected.len()).unwrap());
 }
 
 #[test]
-fn test_multi_file_2a() {
-    let expected = HashMap::from([(EXPECTED[5usize], "dracula.pdf"), (EXPECTED[0usize], "frank.pdf")]);
-    do_test_multi_file(&expected, NonZeroUsize::MIN);
+fn test_multi_file_2a() {
+    let expected = HashMap::from([(EXPECTED[5usize], "dracula.pdf"), (EXPECTED[0usize], "frank.pdf")]);
+    do_test_multi_file(&expected, NonZeroUsize::MIN);
+}
+
+#[test]
+fn test_multi_file_2b() {
+    let expected = HashMap::from([(EXPECTED[5usize], "dracula.pdf"), (EXPECTED[0usize], "frank.pdf")]);
+    do_test_multi_file(&expected, NonZeroUsize::new(expected.len()).unwrap());
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Directory tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_dir_1a() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, false, false, false, false);
+}
+
+#[test]
+fn test_dir_1b() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, false, false, true, false);
+}
+
+#[test]
+fn test_dir_1c() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, false, true, false, false);
+}
+
+#[test]
+fn test_dir_1d() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, false, true, true, false);
+}
+
+#[test]
+fn test_dir_1e() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, false, false, false);
+}
+
+#[test]
+fn test_dir_1f() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, false, true, false);
+}
+
+#[test]
+fn test_dir_1g() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, true, false, false);
+}
+
+#[test]
+fn test_dir_1h() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, true, true, false);
+}
+
+#[test]
+fn test_dir_1i() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, true, false, true);
+}
+
+#[test]
+fn test_dir_1j() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
+    do_test_dir(&expected, None, true, true, true, true);
+}
+
+#[test]
+fn test_dir_2a() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), false, false, false, false);
+}
+
+#[test]
+fn test_dir_2b() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), false, false, true, false);
+}
+
+#[test]
+fn test_dir_2c() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), false, true, false, false);
+}
+
+#[test]
+fn test_dir_2d() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), false, true, true, false);
+}
+
+#[test]
+fn test_dir_2e() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, false, false, false);
+}
+
+#[test]
+fn test_dir_2f() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, false, true, false);
+}
+
+#[test]
+fn test_dir_2g() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, true, false, false);
+}
+
+#[test]
+fn test_dir_2h() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, true, true, false);
+}
+
+#[test]
+fn test_dir_2i() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, true, false, true);
+}
+
+#[test]
+fn test_dir_2j() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(false), true, true, true, true);
+}
+
+#[test]
+fn test_dir_3a() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), false, false, false, false);
+}
+
+#[test]
+fn test_dir_3b() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), false, false, true, false);
+}
+
+#[test]
+fn test_dir_3c() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), false, true, false, false);
+}
+
+#[test]
+fn test_dir_3d() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), false, true, true, false);
+}
+
+#[test]
+fn test_dir_3e() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, false, false, false);
+}
+
+#[test]
+fn test_dir_3f() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, false, true, false);
+}
+
+#[test]
+fn test_dir_3g() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, true, false, false);
+}
+
+#[test]
+fn test_dir_3h() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, true, true, false);
+}
+
+#[test]
+fn test_dir_3i() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, true, false, true);
+}
+
+#[test]
+fn test_dir_3j() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_dir(&expected, Some(true), true, true, true, true);
+}
+
+#[test]
+fn test_dir_4a() {
+    do_test_exclude(&["*.txt"], &["frank.pdf", "dracula.pdf", "dorian.pdf"], false);
+}
+
+#[test]
+fn test_dir_4b() {
+    do_test_exclude(&["*.txt"], &["frank.pdf", "dracula.pdf", "dorian.pdf"], true);
+}
+
+#[test]
+fn test_dir_4c() {
+    do_test_exclude(&["*.txt", "*.pdf"], &["LICENSE", "SHA512SUMS"], false);
+}
+
+#[test]
+fn test_dir_4d() {
+    do_test_exclude(&["extra", "*.txt"], &["frank.pdf", "dracula.pdf"], false);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+    let output = run_binary([OsStr::new("--recursive"), OsStr::new("--exclude"), OsStr::new("extra"), path.as_os_str()], true, false);
+    assert!(!output.contains("dorian.pdf"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_5a() {
+    do_test_symlinks(false, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_5b() {
+    do_test_symlinks(false, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_5c() {
+    do_test_symlinks(true, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_5d() {
+    do_test_symlinks(true, true);
+}
+
+#[test]
+fn test_dir_6a() {
+    do_test_dir_strategy("AUTO");
+}
+
+#[test]
+fn test_dir_6b() {
+    do_test_dir_strategy("auto");
+}
+
+#[test]
+fn test_dir_6c() {
+    do_test_dir_strategy("DFS");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_cycle_1a() {
+    do_test_cycle(false, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_cycle_1b() {
+    do_test_cycle(false, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_cycle_1c() {
+    do_test_cycle(true, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_fifo_1a() {
+    do_test_fifo(false, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_fifo_1b() {
+    do_test_fifo(true, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dir_fifo_1c() {
+    do_test_fifo(true, true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Current directory tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_cwd_1a() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_cwd(&expected, false, false, false);
+}
+
+#[test]
+fn test_cwd_1b() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_cwd(&expected, false, false, true);
+}
+
+#[test]
+fn test_cwd_1c() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_cwd(&expected, false, true, false);
+}
+
+#[test]
+fn test_cwd_1d() {
+    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
+    do_test_cwd(&expected, false, true, true);
+}
+
+#[test]
+fn test_cwd_2a() {
+    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
+    do_test_cwd(&expected, true, false, false);
+}
+
+#[test]
+fn test_cwd_2b() {
+    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
+    do_test_cwd(&expected, true, false, true);
+}
+
+#[test]
+fn test_cwd_2c() {
+    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
+    do_test_cwd(&expected, true, true, false);
+}
+
+#[test]
+fn test_cwd_2d() {
+    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
+    do_test_cwd(&expected, true, true, true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Data (stdin) tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_data_1a() {
+    static STDIN_DATA: &[u8] = include_bytes!("data/binary/frank.pdf");
+    do_test_data(EXPECTED[0usize], STDIN_DATA, None, 0usize);
+}
+
+#[test]
+fn test_data_1b() {
+    static STDIN_DATA: &[u8] = include_bytes!("data/binary/frank.pdf");
+    do_test_data(EXPECTED[1usize], STDIN_DATA, None, 1usize);
+}
+
+#[test]
+fn test_data_2a() {
+    static STDIN_DATA: &[u8] = include_bytes!("data/binary/dracula.pdf");
+    do_test_data(EXPECTED[5usize], STDIN_DATA, None, 0usize);
+}
+
+#[test]
+fn test_data_2b() {
+    static STDIN_DATA: &[u8] = include_bytes!("data/binary/dracula.pdf");
+    do_test_data(EXPECTED[6usize], STDIN_DATA, None, 1usize);
+}
+
+#[test]
+fn test_data_3a() {
+    do_test_data(EXPECTED[37usize], INPUT_MESSAGE, None, 2usize);
+}
+
+#[test]
+fn test_data_3b() {
+    do_test_data(EXPECTED[38usize], INPUT_MESSAGE, None, 3usize);
+}
+
+#[test]
+fn test_data_3c() {
+    do_test_data(EXPECTED[39usize], INPUT_MESSAGE, None, 4usize);
+}
+
+#[test]
+fn test_data_4a() {
+    do_test_data(EXPECTED[40usize], INPUT_MESSAGE, Some("thingamabob"), 0usize);
+}
+
+#[test]
+fn test_data_4b() {
+    do_test_data(EXPECTED[41usize], INPUT_MESSAGE, Some("thingamabob"), 1usize);
+}
+
+#[test]
+fn test_data_4c() {
+    do_test_data(EXPECTED[42usize], INPUT_MESSAGE, Some("thingamabob"), 2usize);
+}
+
+#[test]
+fn test_data_4d() {
+    do_test_data(EXPECTED[43usize], INPUT_MESSAGE, Some("thingamabob"), 3usize);
+}
+
+#[test]
+fn test_data_4e() {
+    do_test_data(EXPECTED[44usize], INPUT_MESSAGE, Some("thingamabob"), 4usize);
+}
+
+#[test]
+fn test_data_5a() {
+    let output = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE);
+    let caps = REGEX_LINE.captures(&output).unwrap();
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[45usize]));
+}
+
+#[test]
+fn test_data_5b() {
+    let output = run_binary_with_data([OsStr::new("--snail"), OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE);
+    let caps = REGEX_LINE.captures(&output).unwrap();
+    assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[46usize]));
+}
+
+#[test]
+fn test_data_5c() {
+    let data: Vec<u8> = (0u32..(16u32 * 1024u32 * 1024u32)).map(|index| (index % 251u32) as u8).collect();
+    let expected = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], &data);
+    let output = run_binary_with_data([OsStr::new("--progress"), OsStr::new(STDIN_DEV_FILE)], &data);
+    let (caps0, caps1) = (REGEX_LINE.captures(&expected).unwrap(), REGEX_LINE.captures(&output).unwrap());
+    assert_eq!(caps0.get(1).unwrap().as_str(), caps1.get(1).unwrap().as_str());
+}
+
+#[test]
+fn test_data_5d() {
+    let data: Vec<u8> = (0u32..(3u32 * 1024u32 * 1024u32 + 4099u32)).map(|index| (index % 253u32) as u8).collect();
+    let data_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("data_{:016X}.bin", random_u64()));
+    File::create_new(&data_file).unwrap().write_all(&data).unwrap();
+    let expected = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], &data);
+    let output = run_binary([data_file.as_os_str()], true, false);
+    let (caps0, caps1) = (REGEX_LINE.captures(&expected).unwrap(), REGEX_LINE.captures(&output).unwrap());
+    assert_eq!(caps0.get(1).unwrap().as_str(), caps1.get(1).unwrap().as_str());
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Verify tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_verify_1a() {
+    do_verify_files(false, 3usize, false, false, false);
+}
+
+#[test]
+fn test_verify_1b() {
+    do_verify_files(false, 3usize, true, false, false);
+}
+
+#[test]
+fn test_verify_2a() {
+    do_verify_files(true, 3usize, false, false, false);
+}
+
+#[test]
+fn test_verify_2b() {
+    do_verify_files(true, 3usize, true, false, false);
+}
+
+#[test]
+fn test_verify_3a() {
+    do_verify_files(false, 3usize, false, true, false);
+}
+
+#[test]
+fn test_verify_3b() {
+    do_verify_files(false, 3usize, true, true, false);
+}
+
+#[test]
+fn test_verify_4a() {
+    do_verify_files(false, 3usize, false, false, true);
+}
+
+#[test]
+fn test_verify_4b() {
+    do_verify_files(true, 3usize, false, false, true);
+}
+
+#[test]
+fn test_verify_5a() {
+    do_verify_warn(false);
+}
+
+#[test]
+fn test_verify_5b() {
+    do_verify_warn(true);
+}
+
+#[test]
+fn test_verify_6a() {
+    do_verify_tagged(None, false);
+}
+
+#[test]
+fn test_verify_6b() {
+    do_verify_tagged(None, true);
+}
+
+#[test]
+fn test_verify_6c() {
+    do_verify_tagged(Some(512u32), false);
+}
+
+#[test]
+fn test_verify_6d() {
+    do_verify_tagged(Some(128u32), true);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_verify_7a() {
+    do_verify_raw_name(false, false);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_verify_7b() {
+    do_verify_raw_name(true, false);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_verify_7c() {
+    do_verify_raw_name(false, true);
+}
+
+#[test]
+fn test_verify_8a() {
+    do_verify_fail_only(false, false);
+}
+
+#[test]
+fn test_verify_8b() {
+    do_verify_fail_only(false, true);
+}
+
+#[test]
+fn test_verify_8c() {
+    do_verify_fail_only(true, false);
+}
+
+#[test]
+fn test_verify_8d() {
+    do_verify_fail_only(true, true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// NDJSON tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_ndjson_1a() {
+    do_test_ndjson(false, false);
+}
+
+#[test]
+fn test_ndjson_1b() {
+    do_test_ndjson(true, false);
 }
 
 #[test]
-fn test_multi_file_2b() {
-    let expected = HashMap::from([(EXPECTED[5usize], "dracula.pdf"), (EXPECTED[0usize], "frank.pdf")]);
-    do_test_multi_file(&expected, NonZeroUsize::new(expected.len()).unwrap());
+fn test_ndjson_2a() {
+    do_test_ndjson(false, true);
+}
+
+#[test]
+fn test_ndjson_2b() {
+    do_test_ndjson(true, true);
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Directory tests
+// Plain fields tests
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 #[test]
-fn test_dir_1a() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, false, false, false, false);
+fn test_plain_fields_1a() {
+    do_test_plain_fields("hex", false);
 }
 
 #[test]
-fn test_dir_1b() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, false, false, true, false);
+fn test_plain_fields_1b() {
+    do_test_plain_fields("hex,size,name", false);
 }
 
 #[test]
-fn test_dir_1c() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, false, true, false, false);
+fn test_plain_fields_1c() {
+    do_test_plain_fields("size,hex", false);
 }
 
 #[test]
-fn test_dir_1d() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, false, true, true, false);
+fn test_plain_fields_2a() {
+    do_test_plain_fields("name,size,hex", true);
 }
 
 #[test]
-fn test_dir_1e() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, false, false, false);
+fn test_plain_fields_2b() {
+    do_test_plain_fields("size", true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// File metadata tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_dir_1f() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, false, true, false);
+fn test_with_meta_1a() {
+    do_test_with_meta(false);
 }
 
 #[test]
-fn test_dir_1g() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, true, false, false);
+fn test_with_meta_1b() {
+    do_test_with_meta(true);
 }
 
 #[test]
-fn test_dir_1h() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, true, true, false);
+fn test_with_meta_2a() {
+    do_verify_with_meta(false, false);
 }
 
 #[test]
-fn test_dir_1i() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, true, false, true);
+fn test_with_meta_2b() {
+    do_verify_with_meta(false, true);
 }
 
 #[test]
-fn test_dir_1j() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf")]);
-    do_test_dir(&expected, None, true, true, true, true);
+fn test_with_meta_2c() {
+    do_verify_with_meta(true, false);
 }
 
 #[test]
-fn test_dir_2a() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), false, false, false, false);
+fn test_with_meta_2d() {
+    do_verify_with_meta(true, true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Truncated digest tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_dir_2b() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), false, false, true, false);
+fn test_compare_1a() {
+    do_test_compare(EXPECTED[0usize], false, true);
 }
 
 #[test]
-fn test_dir_2c() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), false, true, false, false);
+fn test_compare_1b() {
+    do_test_compare(&EXPECTED[0usize][..24usize], false, true);
 }
 
 #[test]
-fn test_dir_2d() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), false, true, true, false);
+fn test_compare_1c() {
+    do_test_compare(EXPECTED[0usize], true, true);
 }
 
 #[test]
-fn test_dir_2e() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, false, false, false);
+fn test_compare_2a() {
+    do_test_compare(EXPECTED[5usize], false, false);
 }
 
 #[test]
-fn test_dir_2f() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, false, true, false);
+fn test_compare_2b() {
+    do_test_compare(&EXPECTED[5usize][..24usize], false, false);
 }
 
 #[test]
-fn test_dir_2g() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, true, false, false);
+fn test_verify_one_1a() {
+    do_test_verify_one(EXPECTED[0usize], false, true);
 }
 
 #[test]
-fn test_dir_2h() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, true, true, false);
+fn test_verify_one_1b() {
+    do_test_verify_one(EXPECTED[0usize], true, true);
 }
 
 #[test]
-fn test_dir_2i() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, true, false, true);
+fn test_verify_one_1c() {
+    do_test_verify_one(EXPECTED[5usize], false, false);
 }
 
 #[test]
-fn test_dir_2j() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(false), true, true, true, true);
+fn test_verify_one_1d() {
+    let output = run_binary([OsStr::new("--verify-one"), OsStr::new(EXPECTED[0usize])], false, true);
+    assert!(output.contains("required arguments were not provided"));
 }
 
 #[test]
-fn test_dir_3a() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), false, false, false, false);
+fn test_verify_mixed_len_1a() {
+    do_verify_mixed_len(false);
 }
 
 #[test]
-fn test_dir_3b() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), false, false, true, false);
+fn test_verify_mixed_len_1b() {
+    do_verify_mixed_len(true);
+}
+
+#[test]
+fn test_verify_truncated_1a() {
+    do_verify_truncated(false, false);
+}
+
+#[test]
+fn test_verify_truncated_1b() {
+    do_verify_truncated(true, false);
+}
+
+#[test]
+fn test_verify_truncated_2a() {
+    do_verify_truncated(false, true);
+}
+
+#[test]
+fn test_verify_truncated_2b() {
+    do_verify_truncated(true, true);
+}
+
+#[test]
+fn test_verify_strict_1a() {
+    do_verify_strict(false, false);
+}
+
+#[test]
+fn test_verify_strict_1b() {
+    do_verify_strict(true, false);
+}
+
+#[test]
+fn test_verify_strict_1c() {
+    do_verify_strict(true, true);
+}
+
+#[test]
+fn test_verify_comments_1a() {
+    do_verify_comments(false);
+}
+
+#[test]
+fn test_verify_comments_1b() {
+    do_verify_comments(true);
+}
+
+#[test]
+fn test_self_describing_1a() {
+    do_test_self_describing(None, false);
+}
+
+#[test]
+fn test_self_describing_1b() {
+    do_test_self_describing(Some("thingamajig"), false);
+}
+
+#[test]
+fn test_self_describing_1c() {
+    do_test_self_describing(None, true);
+}
+
+#[test]
+fn test_self_describing_1d() {
+    do_test_self_describing(Some("thingamajig"), true);
+}
+
+#[test]
+fn test_verify_color_1a() {
+    do_verify_color(None);
+}
+
+#[test]
+fn test_verify_color_1b() {
+    do_verify_color(Some("auto"));
+}
+
+#[test]
+fn test_verify_color_1c() {
+    do_verify_color(Some("always"));
+}
+
+#[test]
+fn test_verify_color_1d() {
+    do_verify_color(Some("never"));
+}
+
+#[test]
+fn test_regenerate_1a() {
+    do_test_regenerate(false);
+}
+
+#[test]
+fn test_regenerate_1b() {
+    do_test_regenerate(true);
+}
+
+#[test]
+fn test_verify_chunked_1a() {
+    do_verify_chunked(4096usize);
+}
+
+#[test]
+fn test_verify_chunked_1b() {
+    do_verify_chunked(1usize);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Quoted file name tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(unix)]
+#[test]
+fn test_quote_1a() {
+    do_test_quote(false, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_quote_1b() {
+    do_test_quote(false, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_quote_1c() {
+    do_test_quote(true, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_line_break_1a() {
+    do_test_line_break(None);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_line_break_1b() {
+    do_test_line_break(Some("--quote"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_line_break_1c() {
+    do_test_line_break(Some("--null"));
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Duplicate input tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(unix)]
+#[test]
+fn test_dedup_1a() {
+    do_test_dedup(false, false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dedup_1b() {
+    do_test_dedup(false, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dedup_1c() {
+    do_test_dedup(true, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dedup_2a() {
+    do_test_dedup_hardlink(false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_dedup_2b() {
+    do_test_dedup_hardlink(true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Dry run tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_dry_run_1a() {
+    do_test_dry_run(false, false);
+}
+
+#[test]
+fn test_dry_run_1b() {
+    do_test_dry_run(true, false);
+}
+
+#[test]
+fn test_dry_run_1c() {
+    do_test_dry_run(true, true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// File list tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_files_from_1a() {
+    do_test_files_from(false, false);
+}
+
+#[test]
+fn test_files_from_1b() {
+    do_test_files_from(false, true);
+}
+
+#[test]
+fn test_files_from_1c() {
+    do_test_files_from(true, false);
+}
+
+#[test]
+fn test_files_from_1d() {
+    do_test_files_from(true, true);
+}
+
+#[test]
+fn test_null_input_1a() {
+    do_test_null_input(false, false);
+}
+
+#[test]
+fn test_null_input_1b() {
+    do_test_null_input(false, true);
+}
+
+#[test]
+fn test_null_input_2a() {
+    do_test_null_input(true, false);
+}
+
+#[test]
+fn test_null_input_2b() {
+    do_test_null_input(true, true);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_null_input_3a() {
+    do_test_null_newline(false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_null_input_3b() {
+    do_test_null_newline(true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Continue from tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_continue_from_1a() {
+    do_test_continue_from(false, false);
+}
+
+#[test]
+fn test_continue_from_1b() {
+    do_test_continue_from(false, true);
 }
 
 #[test]
-fn test_dir_3c() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), false, true, false, false);
+fn test_continue_from_2a() {
+    do_test_continue_from(true, false);
 }
 
 #[test]
-fn test_dir_3d() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), false, true, true, false);
+fn test_continue_from_2b() {
+    do_test_continue_from(true, true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Summary tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_dir_3e() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, false, false, false);
+fn test_summary_1a() {
+    do_test_summary(false);
 }
 
 #[test]
-fn test_dir_3f() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, false, true, false);
+fn test_summary_1b() {
+    do_test_summary(true);
 }
 
 #[test]
-fn test_dir_3g() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, true, false, false);
+fn test_summary_2a() {
+    do_test_verify_summary(false);
 }
 
 #[test]
-fn test_dir_3h() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, true, true, false);
+fn test_summary_2b() {
+    do_test_verify_summary(true);
 }
 
 #[test]
-fn test_dir_3i() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, true, false, true);
+fn test_summary_3a() {
+    do_test_verify_list_failed(false);
 }
 
 #[test]
-fn test_dir_3j() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_dir(&expected, Some(true), true, true, true, true);
+fn test_summary_3b() {
+    do_test_verify_list_failed(true);
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Current directory tests
+// Timing tests
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 #[test]
-fn test_cwd_1a() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_cwd(&expected, false, false, false);
+fn test_time_1a() {
+    do_test_time(false);
 }
 
 #[test]
-fn test_cwd_1b() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_cwd(&expected, false, false, true);
+fn test_time_1b() {
+    do_test_time(true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Tree hashing tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_cwd_1c() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_cwd(&expected, false, true, false);
+fn test_tree_1a() {
+    do_test_tree(None, false);
 }
 
 #[test]
-fn test_cwd_1d() {
-    let expected = HashMap::from([(EXPECTED[0usize], "frank.pdf"), (EXPECTED[5usize], "dracula.pdf"), (EXPECTED[36usize], "dorian.pdf")]);
-    do_test_cwd(&expected, false, true, true);
+fn test_tree_1b() {
+    do_test_tree(Some(2usize), false);
 }
 
 #[test]
-fn test_cwd_2a() {
-    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
-    do_test_cwd(&expected, true, false, false);
+fn test_tree_1c() {
+    do_test_tree(Some(7usize), false);
 }
 
 #[test]
-fn test_cwd_2b() {
-    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
-    do_test_cwd(&expected, true, false, true);
+fn test_tree_1d() {
+    do_test_tree(None, true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Tree digest tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_cwd_2c() {
-    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
-    do_test_cwd(&expected, true, true, false);
+fn test_tree_digest_1a() {
+    do_test_tree_digest(false);
 }
 
 #[test]
-fn test_cwd_2d() {
-    let expected = HashMap::from([(EXPECTED[26usize], "alice29.txt"), (EXPECTED[31usize], "asyoulik.txt")]);
-    do_test_cwd(&expected, true, true, true);
+fn test_tree_digest_1b() {
+    do_test_tree_digest(true);
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Data (stdin) tests
+// Sorted directory tests
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 #[test]
-fn test_data_1a() {
-    static STDIN_DATA: &[u8] = include_bytes!("data/binary/frank.pdf");
-    do_test_data(EXPECTED[0usize], STDIN_DATA, None, 0usize);
+fn test_sorted_1a() {
+    do_test_sorted(false);
 }
 
 #[test]
-fn test_data_1b() {
-    static STDIN_DATA: &[u8] = include_bytes!("data/binary/frank.pdf");
-    do_test_data(EXPECTED[1usize], STDIN_DATA, None, 1usize);
+fn test_sorted_1b() {
+    do_test_sorted(true);
 }
 
 #[test]
-fn test_data_2a() {
-    static STDIN_DATA: &[u8] = include_bytes!("data/binary/dracula.pdf");
-    do_test_data(EXPECTED[5usize], STDIN_DATA, None, 0usize);
+fn test_limit_files_1a() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data");
+    let (output, errors) = run_binary_with_stderr([OsStr::new("--recursive"), OsStr::new("--limit-files"), OsStr::new("1"), path.as_os_str()]);
+    assert_eq!(REGEX_LINE.captures_iter(&output).count(), 1usize);
+    assert!(errors.contains("File limit reached, stopped the walk after 1 file(s)"));
 }
 
 #[test]
-fn test_data_2b() {
-    static STDIN_DATA: &[u8] = include_bytes!("data/binary/dracula.pdf");
-    do_test_data(EXPECTED[6usize], STDIN_DATA, None, 1usize);
+fn test_limit_files_1b() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary");
+    let output =
+        run_binary([OsStr::new("--dirs"), OsStr::new("--limit-files"), OsStr::new("3"), OsStr::new("--multi-threading"), path.as_os_str()], true, false);
+    assert_eq!(REGEX_LINE.captures_iter(&output).count(), 3usize);
 }
 
 #[test]
-fn test_data_3a() {
-    do_test_data(EXPECTED[37usize], INPUT_MESSAGE, None, 2usize);
+fn test_limit_files_1c() {
+    let output = run_binary([OsStr::new("--limit-files"), OsStr::new("1"), OsStr::new("frank.pdf")], false, true);
+    assert!(REGEX_MISSING_ARG.is_match(&output))
 }
 
+#[cfg(unix)]
 #[test]
-fn test_data_3b() {
-    do_test_data(EXPECTED[38usize], INPUT_MESSAGE, None, 3usize);
+fn test_xattr_1a() {
+    do_test_xattr(false);
 }
 
+#[cfg(unix)]
 #[test]
-fn test_data_3c() {
-    do_test_data(EXPECTED[39usize], INPUT_MESSAGE, None, 4usize);
+fn test_xattr_1b() {
+    do_test_xattr(true);
 }
 
 #[test]
-fn test_data_4a() {
-    do_test_data(EXPECTED[40usize], INPUT_MESSAGE, Some("thingamabob"), 0usize);
+fn test_queue_limit_1a() {
+    do_test_queue_limit("1");
 }
 
 #[test]
-fn test_data_4b() {
-    do_test_data(EXPECTED[41usize], INPUT_MESSAGE, Some("thingamabob"), 1usize);
+fn test_queue_limit_1b() {
+    do_test_queue_limit("4");
 }
 
 #[test]
-fn test_data_4c() {
-    do_test_data(EXPECTED[42usize], INPUT_MESSAGE, Some("thingamabob"), 2usize);
+fn test_queue_limit_1c() {
+    do_test_queue_limit("4096");
 }
 
 #[test]
-fn test_data_4d() {
-    do_test_data(EXPECTED[43usize], INPUT_MESSAGE, Some("thingamabob"), 3usize);
+fn test_max_size_1a() {
+    do_test_max_size(false);
 }
 
 #[test]
-fn test_data_4e() {
-    do_test_data(EXPECTED[44usize], INPUT_MESSAGE, Some("thingamabob"), 4usize);
+fn test_max_size_1b() {
+    do_test_max_size(true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Buffer wiping tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_data_5a() {
-    let output = run_binary_with_data([OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE);
-    let caps = REGEX_LINE.captures(&output).unwrap();
-    assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[45usize]));
+fn test_wipe_1a() {
+    do_test_wipe(false);
 }
 
 #[test]
-fn test_data_5b() {
-    let output = run_binary_with_data([OsStr::new("--snail"), OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE);
-    let caps = REGEX_LINE.captures(&output).unwrap();
-    assert!(digest_eq(caps.get(1).unwrap().as_str(), EXPECTED[46usize]));
+fn test_wipe_1b() {
+    do_test_wipe(true);
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// Verify tests
+// Snail target tests
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 #[test]
-fn test_verify_1a() {
-    do_verify_files(false, 3usize, false, false, false);
+fn test_snail_target_1a() {
+    do_test_snail_target("1");
 }
 
 #[test]
-fn test_verify_1b() {
-    do_verify_files(false, 3usize, true, false, false);
+fn test_snail_target_1b() {
+    do_test_snail_target("25");
 }
 
 #[test]
-fn test_verify_2a() {
-    do_verify_files(true, 3usize, false, false, false);
+fn test_show_rounds_1a() {
+    do_test_show_rounds(false, false);
 }
 
 #[test]
-fn test_verify_2b() {
-    do_verify_files(true, 3usize, true, false, false);
+fn test_show_rounds_1b() {
+    do_test_show_rounds(true, false);
 }
 
 #[test]
-fn test_verify_3a() {
-    do_verify_files(false, 3usize, false, true, false);
+fn test_show_rounds_1c() {
+    do_test_show_rounds(false, true);
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Record-wise hashing tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
 #[test]
-fn test_verify_3b() {
-    do_verify_files(false, 3usize, true, true, false);
+fn test_block_stdin_1a() {
+    do_test_block_stdin(None);
 }
 
 #[test]
-fn test_verify_4a() {
-    do_verify_files(false, 3usize, false, false, true);
+fn test_block_stdin_1b() {
+    do_test_block_stdin(Some(20usize));
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Archive tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(feature = "with-tar")]
 #[test]
-fn test_verify_4b() {
-    do_verify_files(true, 3usize, false, false, true);
+fn test_into_archives_1a() {
+    do_test_into_archives(false);
+}
+
+#[cfg(feature = "with-tar")]
+#[test]
+fn test_into_archives_1b() {
+    do_test_into_archives(true);
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// Relative path tests
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[test]
+fn test_relative_to_1a() {
+    do_test_relative_to(false);
+}
+
+#[test]
+fn test_relative_to_1b() {
+    do_test_relative_to(true);
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -1152,7 +3660,7 @@ fn test_exit_code_2b() {
 
 #[test]
 fn test_exit_code_2c() {
-    do_test_exit_code(&["frank.pdf", "dracula.pdf"], true, true, false, 2i32);
+    do_test_exit_code(&["frank.pdf", "dracula.pdf"], true, true, false, 1i32);
 }
 
 #[test]
@@ -1160,6 +3668,38 @@ fn test_exit_code_2d() {
     do_test_exit_code(&["frank.pdf", "dracula.pdf"], true, true, true, 1i32);
 }
 
+#[test]
+fn test_exit_code_3a() {
+    do_test_exit_code_verify(true, false, false, 1i32);
+}
+
+#[test]
+fn test_exit_code_3b() {
+    do_test_exit_code_verify(false, true, false, 2i32);
+}
+
+#[test]
+fn test_exit_code_3c() {
+    do_test_exit_code_verify(false, true, true, 2i32);
+}
+
+#[test]
+fn test_exit_code_3d() {
+    do_test_exit_code_verify(true, true, true, 2i32);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_broken_pipe_1() {
+    do_test_broken_pipe(false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_broken_pipe_2() {
+    do_test_broken_pipe(true);
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // Error tests
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -1178,6 +3718,18 @@ fn test_interrupt_2() {
     assert!(REGEX_ABORTED.is_match(&output))
 }
 
+#[cfg(unix)]
+#[test]
+fn test_interrupt_3a() {
+    do_test_interrupt_partial(false);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_interrupt_3b() {
+    do_test_interrupt_partial(true);
+}
+
 #[test]
 fn test_invalid_args_1a() {
     let output = run_binary([OsStr::new("-w")], false, true);
@@ -1213,7 +3765,7 @@ fn test_invalid_args_2c() {
 
 #[test]
 fn test_invalid_args_2d() {
-    for arg_2 in ["--multi-threading", "--check", "filename.txt"] {
+    for arg_2 in ["--check", "filename.txt"] {
         let output = run_binary([OsStr::new("--self-test"), OsStr::new(arg_2)], false, true);
         assert!(REGEX_MUTEX.is_match(&output))
     }
@@ -1251,6 +3803,36 @@ fn test_invalid_args_3d() {
     assert!(REGEX_LEN_MAX.is_match(&output))
 }
 
+#[test]
+fn test_invalid_args_3e() {
+    let output = run_binary([OsStr::new("--bytes"), OsStr::new("1024")], false, true);
+    assert!(REGEX_LEN_MAX.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_3f() {
+    let output = run_binary([OsStr::new("--bytes"), OsStr::new("32"), OsStr::new("--length"), OsStr::new("256")], false, true);
+    assert!(REGEX_MUTEX.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_3g() {
+    let output = run_binary([OsStr::new("--buffer-size"), OsStr::new("65537")], false, true);
+    assert!(REGEX_BUF_MAX.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_3h() {
+    let output = run_binary([OsStr::new("--length"), OsStr::new("0")], false, true);
+    assert!(REGEX_LEN_MIN.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_3i() {
+    let output = run_binary([OsStr::new("--length"), OsStr::new("4")], false, true);
+    assert!(REGEX_LEN_MIN.is_match(&output))
+}
+
 #[test]
 fn test_invalid_args_4a() {
     let parameters: Vec<&OsStr> = iter::repeat_n(OsStr::new("--snail"), 5usize).collect();
@@ -1264,6 +3846,27 @@ fn test_invalid_args_4b() {
     assert!(REGEX_INFO.is_match(&output))
 }
 
+#[test]
+fn test_invalid_args_4c() {
+    let long_info = "\u{e4}".repeat(200usize);
+    let output = run_binary([OsStr::new("--info"), OsStr::new(&long_info)], false, true);
+    assert!(REGEX_INFO.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_4d() {
+    let info_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("info_{:016X}.txt", random_u64()));
+    File::create_new(&info_file).unwrap().write_all(&[0x41u8; 256usize]).unwrap();
+    let output = run_binary([OsStr::new("--info-file"), info_file.as_os_str()], false, true);
+    assert!(REGEX_INFO.is_match(&output))
+}
+
+#[test]
+fn test_invalid_args_4e() {
+    let output = run_binary([OsStr::new("--info"), OsStr::new("foo"), OsStr::new("--info-file"), OsStr::new("info.txt")], false, true);
+    assert!(REGEX_MUTEX.is_match(&output))
+}
+
 #[test]
 fn test_invalid_args_5a() {
     let output = run_binary([OsStr::new("--all")], false, true);
@@ -1350,6 +3953,26 @@ fn test_file_error_4b() {
     assert!(REGEX_FILE_READ.is_match(&output));
 }
 
+#[cfg(unix)]
+#[test]
+fn test_file_error_5a() {
+    let output = run_binary([OsStr::new("--max-bytes"), OsStr::new("1048576"), OsStr::new("/dev/zero")], false, true);
+    let caps = REGEX_FILE_LIMIT.captures(&output).expect("Regex did not match!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "1048576");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_file_error_5b() {
+    let output = run_binary(
+        [OsStr::new("--multi-threading"), OsStr::new("--text"), OsStr::new("--max-bytes"), OsStr::new("1048576"), OsStr::new("/dev/zero")],
+        false,
+        true,
+    );
+    let caps = REGEX_FILE_LIMIT.captures(&output).expect("Regex did not match!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "1048576");
+}
+
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 #[test]
 fn test_dir_error_1a() {
@@ -1443,6 +4066,46 @@ fn test_stdio_error_2f() {
     assert!(REGEX_SELF_IOERR.is_match(&output));
 }
 
+#[test]
+fn test_stdio_error_3a() {
+    let output = run_binary([OsStr::new(STDIN_DEV_FILE), OsStr::new(STDIN_DEV_FILE)], false, true);
+    let caps = REGEX_STDIN_ONCE.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "Input");
+}
+
+#[test]
+fn test_stdio_error_3b() {
+    let output = run_binary([OsStr::new("--multi-threading"), OsStr::new("--keep-going"), OsStr::new(STDIN_DEV_FILE), OsStr::new(STDIN_DEV_FILE)], false, true);
+    let caps = REGEX_STDIN_ONCE.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "Input");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_stdio_error_3c() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    File::create_new(&check_file).unwrap().write_all(format!("{} {}\n", EXPECTED[0usize], STDIN_DEV_FILE).as_bytes()).unwrap();
+    let output = run_binary_from_file([OsStr::new("--check")], &check_file, false, true);
+    let caps = REGEX_STDIN_ONCE.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "Target");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_stdio_error_3d() {
+    let output = run_binary_with_pipe([OsStr::new(STDIN_DEV_FILE), OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE, false, true);
+    let caps = REGEX_STDIN_ONCE.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "Input");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_stdio_error_3e() {
+    let output = run_binary_with_pipe([OsStr::new("/dev/fd/0"), OsStr::new(STDIN_DEV_FILE)], INPUT_MESSAGE, false, true);
+    let caps = REGEX_STDIN_ONCE.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(1).unwrap().as_str(), "Input");
+}
+
 #[test]
 fn test_check_error_1a() {
     let output = run_binary([OsStr::new("--check"), OsStr::new(NOT_FOUND_PATH)], false, true);
@@ -1497,6 +4160,60 @@ fn test_check_error_3c() {
     assert!(REGEX_MALFORMED.is_match(&output))
 }
 
+#[test]
+fn test_check_error_3d() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    File::create(&check_file).unwrap().write_all(format!("SpongeHash256-128 ({}) = {}\n", target_file.to_str().unwrap(), EXPECTED[0usize]).as_bytes()).unwrap();
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], false, true);
+    assert!(REGEX_MALFORMED.is_match(&output))
+}
+
+#[test]
+fn test_check_error_3e() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    File::create(&check_file).unwrap().write_all(format!("{} this-file-does-not-exist\n", "5a".repeat(375usize)).as_bytes()).unwrap();
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], false, true);
+    let caps = REGEX_DIGEST_LONG.captures(&output).expect("Error message not found!");
+    assert_eq!(caps.get(2).unwrap().as_str(), "1");
+    assert_eq!(caps.get(3).unwrap().as_str(), "2048");
+    assert!(!REGEX_MALFORMED.is_match(&output))
+}
+
+#[test]
+fn test_check_error_3f() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    File::create(&check_file).unwrap().write_all(format!("{} this-file-does-not-exist\n", "5a".repeat(375usize)).as_bytes()).unwrap();
+    let output = run_binary([OsStr::new("--check"), OsStr::new("--warn"), check_file.as_os_str()], true, true);
+    let caps = REGEX_DIGEST_LONG.captures(&output).expect("Warning message not found!");
+    assert_eq!(caps.get(2).unwrap().as_str(), "1");
+    assert_eq!(caps.get(3).unwrap().as_str(), "2048");
+}
+
+#[test]
+fn test_check_error_3g() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    File::create(&check_file)
+        .unwrap()
+        .write_all(format!("SpongeHash256-256 ({}) = {}\n", target_file.to_str().unwrap(), &EXPECTED[0usize][..48usize]).as_bytes())
+        .unwrap();
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], false, true);
+    assert!(REGEX_MALFORMED.is_match(&output))
+}
+
+#[test]
+fn test_check_error_3h() {
+    let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
+    let target_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join("binary").join("frank.pdf");
+    File::create(&check_file)
+        .unwrap()
+        .write_all(format!("SpongeHash256-192 ({}) = {}\n", target_file.to_str().unwrap(), &EXPECTED[0usize][..48usize]).as_bytes())
+        .unwrap();
+    let output = run_binary([OsStr::new("--check"), check_file.as_os_str()], true, false);
+    assert!(output.trim_end().ends_with(": OK"))
+}
+
 #[test]
 fn test_check_error_4a() {
     let check_file = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("checksums_{:016X}.txt", random_u64()));
@@ -1684,6 +4401,13 @@ fn test_version() {
     assert_eq!(caps.get(1).unwrap().as_str(), env!("CARGO_PKG_VERSION"));
 }
 
+#[test]
+fn test_version_backend() {
+    let output = run_binary([OsStr::new("--version")], true, false);
+    assert!(output.lines().any(|line| line.starts_with("AES backend: ")));
+    assert!(output.lines().any(|line| line.starts_with("SIMD features: ")));
+}
+
 #[test]
 fn test_help() {
     assert!(REGEX_HELP.is_match(&run_binary([OsStr::new("--help")], true, false)));
@@ -1726,3 +4450,17 @@ fn test_selftest() {
     let env = HashMap::from([("SPONGE256SUM_SELFTEST_PASSES", "1".to_owned())]);
     assert!(REGEX_SELFTEST.is_match(&run_binary_with_env([OsStr::new("--self-test")], env, true, false)));
 }
+
+#[test]
+fn test_selftest_mt() {
+    let env = HashMap::from([("SPONGE256SUM_SELFTEST_PASSES", "1".to_owned()), ("SPONGE256SUM_THREAD_COUNT", "2".to_owned())]);
+    assert!(REGEX_SELFTEST.is_match(&run_binary_with_env([OsStr::new("--self-test"), OsStr::new("--multi-threading")], env, true, false)));
+}
+
+#[test]
+fn test_selftest_stats() {
+    let env = HashMap::from([("SPONGE256SUM_SELFTEST_PASSES", "1".to_owned()), ("SPONGE256SUM_SELFTEST_SAMPLES", "1000".to_owned())]);
+    let output = run_binary_with_env([OsStr::new("--self-test")], env, true, false);
+    let caps = REGEX_SELFTEST_STATS.captures(&output).expect("Statistics not found!");
+    assert!((caps.get(1).unwrap().as_str().parse::<f64>().unwrap() - 50.0).abs() < 2.0);
+}
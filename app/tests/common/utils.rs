@@ -9,7 +9,7 @@ use std::{
     collections::HashMap,
     ffi::OsStr,
     fs::{File, OpenOptions},
-    io::Write,
+    io::{BufRead, BufReader, Write},
     path::Path,
     process::{Command, Stdio},
 };
@@ -64,6 +64,42 @@ where
     String::from_utf8(output.stdout).unwrap()
 }
 
+pub fn run_binary_with_pipe<I, S>(args: I, data: &[u8], expected_success: bool, force_stderr: bool) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let child = Command::new(env!("CARGO_BIN_EXE_sponge256sum"))
+        .args(args)
+        .stdout(if force_stderr { Stdio::null() } else { Stdio::piped() })
+        .stderr(if force_stderr { Stdio::piped() } else { Stdio::null() })
+        .stdin(Stdio::piped())
+        .spawn()
+        .expect("Failed to run binary!");
+
+    let _ = child.stdin.as_ref().unwrap().write_all(data);
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.success(), expected_success);
+    String::from_utf8(if force_stderr { output.stderr } else { output.stdout }).unwrap()
+}
+
+pub fn run_binary_with_stderr<I, S>(args: I) -> (String, String)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let output = Command::new(env!("CARGO_BIN_EXE_sponge256sum"))
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to run binary!");
+
+    assert!(output.status.success());
+    (String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
 pub fn run_binary_to_file<I, S>(args: I, dest_file: &Path, create_new: bool, expected_success: bool) -> String
 where
     I: IntoIterator<Item = S>,
@@ -180,6 +216,27 @@ where
     exit_status.code().expect("Failed to get exit code!")
 }
 
+#[cfg(unix)]
+pub fn run_binary_and_close<I, S>(args: I) -> (String, i32)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sponge256sum"))
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .stdin(Stdio::null())
+        .spawn()
+        .expect("Failed to run binary!");
+
+    let mut first_line = String::new();
+    BufReader::new(child.stdout.take().unwrap()).read_line(&mut first_line).expect("Failed to read output!");
+
+    let exit_status = child.wait().expect("Failed to wait for process!");
+    (first_line, exit_status.code().expect("Failed to get exit code!"))
+}
+
 pub fn get_file_name(path: &str) -> &str {
     path.split(['/', '\\']).next_back().unwrap_or(path)
 }
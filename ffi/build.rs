@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use std::env;
+
+// ---------------------------------------------------------------------------
+// Build main
+// ---------------------------------------------------------------------------
+
+/// Pass the target and host triples to the tests, which compile a C program against the static library
+fn main() {
+    println!("cargo:rustc-env=SPONGE256_TARGET={}", env::var("TARGET").unwrap());
+    println!("cargo:rustc-env=SPONGE256_HOST={}", env::var("HOST").unwrap());
+    println!("cargo:rerun-if-changed=build.rs");
+}
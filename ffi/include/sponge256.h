@@ -0,0 +1,46 @@
+/* SPDX-License-Identifier: 0BSD */
+/* SpongeHash-AES256 */
+/* Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de> */
+
+#ifndef SPONGE256_H
+#define SPONGE256_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes */
+#define SPONGE256_OK 0
+#define SPONGE256_INVALID_ARG (-1)
+
+/*
+ * Opaque hash context.
+ *
+ * A context is NOT thread-safe: It may be moved to another thread, but it must
+ * not be accessed by multiple threads concurrently without external locking.
+ */
+typedef struct sponge256_ctx sponge256_ctx;
+
+/* Allocate a new hash context, must be released by sponge256_free() */
+sponge256_ctx *sponge256_new(void);
+
+/* Absorb `len` bytes from `data`; `data` may be NULL only if `len` is zero */
+int32_t sponge256_update(sponge256_ctx *ctx, const uint8_t *data, size_t len);
+
+/* Write the final digest of `out_len` (> 0) bytes to `out`, then reset the context */
+int32_t sponge256_finalize_into(sponge256_ctx *ctx, uint8_t *out, size_t out_len);
+
+/* Release the hash context; passing NULL is a no-op */
+void sponge256_free(sponge256_ctx *ctx);
+
+/* One-shot computation of the digest of `len` bytes from `data` */
+int32_t sponge256_compute(const uint8_t *data, size_t len, uint8_t *out, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SPONGE256_H */
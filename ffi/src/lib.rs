@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+//! # SpongeHash-AES256 &ndash; C language bindings
+//!
+//! This crate provides a minimal C-compatible interface (FFI) to the [**`sponge-hash-aes256`**](sponge_hash_aes256) crate, so that the SpongeHash-AES256 hash function can be used from programs that are *not* written in Rust.
+//!
+//! It is built as a shared library (`cdylib`) as well as a static library (`staticlib`). The corresponding C header file can be found at **`include/sponge256.h`**.
+//!
+//! ## Usage
+//!
+//! ```c
+//! #include <sponge256.h>
+//!
+//! int main(void) {
+//!     uint8_t digest[32];
+//!     sponge256_ctx *ctx = sponge256_new();
+//!     sponge256_update(ctx, (const uint8_t*)"abc", 3);
+//!     sponge256_finalize_into(ctx, digest, sizeof(digest));
+//!     sponge256_free(ctx);
+//!     return 0;
+//! }
+//! ```
+//!
+//! ## Thread safety
+//!
+//! <div class="warning">
+//!
+//! A context, as returned by `sponge256_new()`, is **not** thread-safe. It may be *moved* to another thread, but it must **not** be accessed by multiple threads concurrently, unless the application provides its own synchronization. Separate contexts can be used by separate threads freely.
+//!
+//! </div>
+
+use core::{mem, slice};
+use sponge_hash_aes256::{compute_to_slice, SpongeHash256};
+
+/// Status code that indicates success
+pub const SPONGE256_OK: i32 = 0i32;
+
+/// Status code that indicates that an invalid argument, e.g., a `NULL` pointer, was passed
+pub const SPONGE256_INVALID_ARG: i32 = -1i32;
+
+/// Opaque hash context, as seen from the C side
+pub type Sponge256Ctx = SpongeHash256;
+
+/// Create a slice from a pointer and length, where a `NULL` pointer is permitted only if the length is zero
+#[inline]
+unsafe fn as_slice<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    match (data.is_null(), len) {
+        (true, 0usize) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(unsafe { slice::from_raw_parts(data, len) }),
+    }
+}
+
+/// Create a mutable slice from a pointer and length, the pointer must be non-`NULL` and the length must be positive
+#[inline]
+unsafe fn as_slice_mut<'a>(data: *mut u8, len: usize) -> Option<&'a mut [u8]> {
+    (!data.is_null() && len > 0usize).then(|| unsafe { slice::from_raw_parts_mut(data, len) })
+}
+
+/// Allocate a new hash context, with the default number of permutation rounds and without any “info” string.
+///
+/// The context must eventually be released by calling [`sponge256_free()`].
+#[no_mangle]
+pub extern "C" fn sponge256_new() -> *mut Sponge256Ctx {
+    Box::into_raw(Box::new(SpongeHash256::new()))
+}
+
+/// Absorb `len` bytes of message data, starting at `data`, into the hash context.
+///
+/// Returns `SPONGE256_OK` on success, or `SPONGE256_INVALID_ARG`, if `ctx` is `NULL`, or if `data` is `NULL` while `len` is non-zero.
+///
+/// # Safety
+///
+/// The `ctx` must have been returned by [`sponge256_new()`] and must not have been freed yet. The `data` must point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sponge256_update(ctx: *mut Sponge256Ctx, data: *const u8, len: usize) -> i32 {
+    match (unsafe { ctx.as_mut() }, unsafe { as_slice(data, len) }) {
+        (Some(hash), Some(chunk)) => {
+            hash.update(chunk);
+            SPONGE256_OK
+        }
+        _ => SPONGE256_INVALID_ARG,
+    }
+}
+
+/// Conclude the hash computation and write the final digest of `out_len` bytes to `out`.
+///
+/// Afterwards, the context is reset to its initial state, so that it can be re-used for a new computation. It still needs to be released by calling [`sponge256_free()`].
+///
+/// Returns `SPONGE256_OK` on success, or `SPONGE256_INVALID_ARG`, if `ctx` or `out` is `NULL`, or if `out_len` is zero.
+///
+/// # Safety
+///
+/// The `ctx` must have been returned by [`sponge256_new()`] and must not have been freed yet. The `out` must point to at least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sponge256_finalize_into(ctx: *mut Sponge256Ctx, out: *mut u8, out_len: usize) -> i32 {
+    match (unsafe { ctx.as_mut() }, unsafe { as_slice_mut(out, out_len) }) {
+        (Some(hash), Some(digest_out)) => {
+            mem::take(hash).digest_to_slice(digest_out);
+            SPONGE256_OK
+        }
+        _ => SPONGE256_INVALID_ARG,
+    }
+}
+
+/// Release a hash context that was returned by [`sponge256_new()`]. Passing `NULL` is a no-op.
+///
+/// # Safety
+///
+/// The `ctx` must have been returned by [`sponge256_new()`] and must not have been freed yet. It must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn sponge256_free(ctx: *mut Sponge256Ctx) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// Compute the digest of `len` bytes of message data, starting at `data`, and write the digest of `out_len` bytes to `out`, in a single step.
+///
+/// Returns `SPONGE256_OK` on success, or `SPONGE256_INVALID_ARG`, if `out` is `NULL`, if `out_len` is zero, or if `data` is `NULL` while `len` is non-zero.
+///
+/// # Safety
+///
+/// The `data` must point to at least `len` readable bytes, and `out` must point to at least `out_len` writable bytes. The two buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn sponge256_compute(data: *const u8, len: usize, out: *mut u8, out_len: usize) -> i32 {
+    match (unsafe { as_slice(data, len) }, unsafe { as_slice_mut(out, out_len) }) {
+        (Some(message), Some(digest_out)) => {
+            compute_to_slice(digest_out, None, message);
+            SPONGE256_OK
+        }
+        _ => SPONGE256_INVALID_ARG,
+    }
+}
@@ -0,0 +1,48 @@
+/* SPDX-License-Identifier: 0BSD */
+/* SpongeHash-AES256 */
+/* Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de> */
+
+#include <sponge256.h>
+#include <stdio.h>
+#include <string.h>
+
+static const char MESSAGE[] = "The quick brown fox jumps over the lazy dog";
+
+static const uint8_t EXPECTED[32] = {
+    0x98, 0x98, 0x3f, 0xca, 0x13, 0x24, 0x24, 0x41, 0x44, 0x3a, 0x8e, 0x6a, 0x5f, 0x8e, 0x2b, 0x4a,
+    0x64, 0xf6, 0xda, 0x8e, 0x2b, 0x71, 0xb2, 0x12, 0x2b, 0xb4, 0xef, 0xeb, 0x3d, 0x9d, 0xa3, 0x5a
+};
+
+static int check(const char *name, int condition) {
+    if (!condition) {
+        fprintf(stderr, "Check has failed: %s\n", name);
+    }
+    return condition ? 0 : 1;
+}
+
+int main(void) {
+    uint8_t digest_1[32], digest_2[32];
+    const size_t length = strlen(MESSAGE);
+    int failures = 0;
+
+    /* streaming API */
+    sponge256_ctx *ctx = sponge256_new();
+    failures += check("sponge256_new", ctx != NULL);
+    failures += check("sponge256_update", sponge256_update(ctx, (const uint8_t*)MESSAGE, 16) == SPONGE256_OK);
+    failures += check("sponge256_update", sponge256_update(ctx, (const uint8_t*)MESSAGE + 16, length - 16) == SPONGE256_OK);
+    failures += check("sponge256_update", sponge256_update(ctx, NULL, 0) == SPONGE256_OK);
+    failures += check("sponge256_finalize_into", sponge256_finalize_into(ctx, digest_1, sizeof(digest_1)) == SPONGE256_OK);
+    failures += check("digest_1", memcmp(digest_1, EXPECTED, sizeof(EXPECTED)) == 0);
+
+    /* invalid arguments */
+    failures += check("sponge256_update", sponge256_update(NULL, (const uint8_t*)MESSAGE, length) == SPONGE256_INVALID_ARG);
+    failures += check("sponge256_finalize_into", sponge256_finalize_into(ctx, digest_1, 0) == SPONGE256_INVALID_ARG);
+    sponge256_free(ctx);
+    sponge256_free(NULL);
+
+    /* one-shot API */
+    failures += check("sponge256_compute", sponge256_compute((const uint8_t*)MESSAGE, length, digest_2, sizeof(digest_2)) == SPONGE256_OK);
+    failures += check("digest_2", memcmp(digest_2, EXPECTED, sizeof(EXPECTED)) == 0);
+
+    return failures ? 1 : 0;
+}
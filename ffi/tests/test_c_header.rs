@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+//! Compiles a C program against `include/sponge256.h` and links it with the *static* library, as a C application would do.
+//!
+//! Skipped in coverage builds, because the instrumented static library requires the profiler runtime.
+
+#![cfg(all(any(target_os = "linux", target_os = "macos"), not(coverage)))]
+
+use std::{env, path::Path, process::Command};
+
+/// The file name of the static library, which Cargo places next to the test executable
+const STATIC_LIB: &str = "libsponge_hash_aes256_ffi.a";
+
+/// The system libraries that the static library depends on, as reported by `rustc --print native-static-libs`
+#[cfg(target_os = "linux")]
+const NATIVE_LIBS: &[&str] = &["-lgcc_s", "-lutil", "-lrt", "-lpthread", "-lm", "-ldl", "-lc"];
+#[cfg(target_os = "macos")]
+const NATIVE_LIBS: &[&str] = &["-lSystem", "-lc", "-lm"];
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_c_header() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = env::current_exe().unwrap().parent().unwrap().to_owned();
+    let executable = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("test_c_header_{:08X}", std::process::id()));
+
+    let compiler = cc::Build::new()
+        .target(env!("SPONGE256_TARGET"))
+        .host(env!("SPONGE256_HOST"))
+        .opt_level(0u32)
+        .cargo_metadata(false)
+        .cargo_warnings(false)
+        .get_compiler();
+    let status = compiler
+        .to_command()
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests").join("c").join("test_header.c"))
+        .arg(library_dir.join(STATIC_LIB))
+        .args(NATIVE_LIBS)
+        .arg("-o")
+        .arg(&executable)
+        .status()
+        .expect("Failed to run the C compiler!");
+    assert!(status.success(), "Failed to compile the C program!");
+
+    let status = Command::new(&executable).status().expect("Failed to run the C program!");
+    assert!(status.success(), "The C program has failed!");
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use sponge_hash_aes256::{compute, DEFAULT_DIGEST_SIZE};
+use std::ptr;
+
+// Link the library as an "rlib", the functions are called through their C-compatible declarations below (see `test_c_header.rs` for a test that uses the C header and the static library)
+use sponge_hash_aes256_ffi as _;
+
+#[repr(C)]
+struct Sponge256Ctx {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    fn sponge256_new() -> *mut Sponge256Ctx;
+    fn sponge256_update(ctx: *mut Sponge256Ctx, data: *const u8, len: usize) -> i32;
+    fn sponge256_finalize_into(ctx: *mut Sponge256Ctx, out: *mut u8, out_len: usize) -> i32;
+    fn sponge256_free(ctx: *mut Sponge256Ctx);
+    fn sponge256_compute(data: *const u8, len: usize, out: *mut u8, out_len: usize) -> i32;
+}
+
+const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_ffi_streaming() {
+    let mut digest = [0u8; DEFAULT_DIGEST_SIZE];
+    unsafe {
+        let ctx = sponge256_new();
+        assert!(!ctx.is_null());
+        assert_eq!(sponge256_update(ctx, MESSAGE.as_ptr(), 16usize), 0i32);
+        assert_eq!(sponge256_update(ctx, MESSAGE[16usize..].as_ptr(), MESSAGE.len() - 16usize), 0i32);
+        assert_eq!(sponge256_update(ctx, ptr::null(), 0usize), 0i32);
+        assert_eq!(sponge256_finalize_into(ctx, digest.as_mut_ptr(), digest.len()), 0i32);
+        sponge256_free(ctx);
+    }
+    assert_eq!(digest, compute::<DEFAULT_DIGEST_SIZE, _>(None, MESSAGE));
+}
+
+#[test]
+pub fn test_ffi_reset() {
+    let (mut digest_1, mut digest_2) = ([0u8; 48usize], [0u8; 48usize]);
+    unsafe {
+        let ctx = sponge256_new();
+        assert_eq!(sponge256_update(ctx, MESSAGE.as_ptr(), MESSAGE.len()), 0i32);
+        assert_eq!(sponge256_finalize_into(ctx, digest_1.as_mut_ptr(), digest_1.len()), 0i32);
+        assert_eq!(sponge256_update(ctx, MESSAGE.as_ptr(), MESSAGE.len()), 0i32);
+        assert_eq!(sponge256_finalize_into(ctx, digest_2.as_mut_ptr(), digest_2.len()), 0i32);
+        sponge256_free(ctx);
+    }
+    assert_eq!(digest_1, digest_2);
+    assert_eq!(digest_1, compute::<48usize, _>(None, MESSAGE));
+}
+
+#[test]
+pub fn test_ffi_compute() {
+    let mut digest = [0u8; 64usize];
+    unsafe {
+        assert_eq!(sponge256_compute(MESSAGE.as_ptr(), MESSAGE.len(), digest.as_mut_ptr(), digest.len()), 0i32);
+    }
+    assert_eq!(digest, compute::<64usize, _>(None, MESSAGE));
+}
+
+#[test]
+pub fn test_ffi_invalid() {
+    let mut digest = [0u8; DEFAULT_DIGEST_SIZE];
+    unsafe {
+        let ctx = sponge256_new();
+        assert_eq!(sponge256_update(ptr::null_mut(), MESSAGE.as_ptr(), MESSAGE.len()), -1i32);
+        assert_eq!(sponge256_update(ctx, ptr::null(), 1usize), -1i32);
+        assert_eq!(sponge256_finalize_into(ctx, ptr::null_mut(), digest.len()), -1i32);
+        assert_eq!(sponge256_finalize_into(ctx, digest.as_mut_ptr(), 0usize), -1i32);
+        assert_eq!(sponge256_compute(MESSAGE.as_ptr(), MESSAGE.len(), digest.as_mut_ptr(), 0usize), -1i32);
+        sponge256_free(ctx);
+        sponge256_free(ptr::null_mut());
+    }
+}
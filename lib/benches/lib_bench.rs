@@ -13,6 +13,10 @@ use std::{hint::black_box, time::Instant};
 const ITERATIONS: usize = 100usize;
 const SAMPLES: usize = 0xFFFFusize;
 
+const CHUNKED_DATA_SIZE: usize = 1048576usize;
+const CHUNKED_ITERATIONS: usize = 3usize;
+const CHUNKED_SAMPLES: usize = 31usize;
+
 macro_rules! measure {
     ($function:expr) => {
         let mut measurement = Measurement::new();
@@ -23,6 +27,18 @@ macro_rules! measure {
     };
 }
 
+macro_rules! measure_chunked {
+    ($function:expr, $data:expr, $chunk_size:expr) => {
+        let mut measurement = Measurement::new();
+        for _i in 0usize..CHUNKED_SAMPLES {
+            black_box($function(black_box(&mut measurement), black_box($data), $chunk_size));
+        }
+        let duration = measurement.result();
+        let throughput = ($data.len() as f64) / duration / 1048576f64;
+        println!("{:.10} -- {}_{} [{:.2} MiB/s]", duration, stringify!($function).strip_prefix("perf_").unwrap(), $chunk_size, throughput);
+    };
+}
+
 // ---------------------------------------------------------------------------
 // Utilities
 // ---------------------------------------------------------------------------
@@ -66,11 +82,33 @@ impl Measurement {
         self.rolling_median.push(duration).unwrap();
     }
 
+    #[inline]
+    pub fn run_chunked<R, F: Fn(&[u8], usize) -> R>(&mut self, data: &[u8], chunk_size: usize, function: F) {
+        let timestamp_start = Instant::now();
+        for _i in 0usize..CHUNKED_ITERATIONS {
+            black_box(function(black_box(data), black_box(chunk_size)));
+        }
+        let duration = timestamp_start.elapsed().as_secs_f64() / (CHUNKED_ITERATIONS as f64);
+        self.rolling_median.push(duration).unwrap();
+    }
+
     pub fn result(self) -> f64 {
         self.rolling_median.get().unwrap_or(f64::MAX)
     }
 }
 
+fn create_chunked_data() -> Vec<u8> {
+    let mut state = 0x2545F491u32;
+    (0usize..CHUNKED_DATA_SIZE)
+        .map(|_i| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Functions
 // ---------------------------------------------------------------------------
@@ -143,6 +181,17 @@ fn perf_spongehash256_digest(measurement: &mut Measurement) {
     });
 }
 
+fn perf_spongehash256_update_chunked(measurement: &mut Measurement, data: &[u8], chunk_size: usize) {
+    measurement.run_chunked(data, chunk_size, |data, chunk_size| {
+        let mut instance = SpongeHash256::default();
+        for chunk in data.chunks(chunk_size) {
+            instance.update(black_box(chunk));
+        }
+        let digest: [u8; DEFAULT_DIGEST_SIZE] = instance.digest();
+        black_box(digest)
+    });
+}
+
 // ---------------------------------------------------------------------------
 // Main
 // ---------------------------------------------------------------------------
@@ -156,4 +205,9 @@ fn main() {
     measure!(perf_spongehash256_update_big);
     measure!(perf_spongehash256_update_huge);
     measure!(perf_spongehash256_digest);
+
+    let data = create_chunked_data();
+    for chunk_size in [16usize, 64usize, 256usize, 4096usize, 65536usize] {
+        measure_chunked!(perf_spongehash256_update_chunked, &data[..], chunk_size);
+    }
 }
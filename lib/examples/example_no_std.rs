@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+//! Example of using the “streaming” API in a `no_std` environment, without any heap allocation.
+//!
+//! This example is built as a `#![no_std]` library that provides its own panic handler. The CI builds it for a bare-metal target (`thumbv7em-none-eabi`), where the Rust standard library is not available, so the build fails if any dependency were to pull it in:
+//!
+//! ```sh
+//! cargo build -p sponge-hash-aes256 --example example_no_std --target thumbv7em-none-eabi
+//! ```
+
+#![no_std]
+
+use sponge_hash_aes256::{SpongeHash256, DEFAULT_DIGEST_SIZE};
+
+/// Size of the chunks in which the message is processed, e.g., as received from a peripheral device
+const CHUNK_SIZE: usize = 16usize;
+
+/// Compute the digest of the given `message`, processing the message in small chunks
+pub fn compute_digest(message: &[u8]) -> [u8; DEFAULT_DIGEST_SIZE] {
+    // Create new hash instance (on the stack)
+    let mut hash = SpongeHash256::default();
+
+    // Process message, chunk by chunk
+    for chunk in message.chunks(CHUNK_SIZE) {
+        hash.update(chunk);
+    }
+
+    // Retrieve the final digest (on the stack)
+    hash.digest::<DEFAULT_DIGEST_SIZE>()
+}
+
+/// Minimal panic handler, as is required in a `no_std` environment
+///
+/// **Note:** With the `std` feature enabled, the Rust standard library provides the panic handler. The same applies to the `tracing` feature on hosted targets, because the `log` crate is built with `std` support there, as the `simple_logger` dev-dependency requires it.
+#[cfg(not(any(feature = "std", all(feature = "tracing", not(target_os = "none")))))]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sponge_hash_aes256::{compute, SpongeHash256, DEFAULT_DIGEST_SIZE};
+
+// Input layout: [chunk_count] [chunk_len; chunk_count] [message...]
+fuzz_target!(|data: &[u8]| {
+    let Some((&chunk_count, data)) = data.split_first() else {
+        return;
+    };
+
+    let (chunk_lens, message) = data.split_at((chunk_count as usize).min(data.len()));
+    let expected: [u8; DEFAULT_DIGEST_SIZE] = compute(None, message);
+
+    let mut hash: SpongeHash256 = SpongeHash256::new();
+    let mut remaining = message;
+
+    for &chunk_len in chunk_lens.iter().chain(core::iter::once(&u8::MAX)).cycle() {
+        if remaining.is_empty() {
+            break;
+        }
+        let (chunk, tail) = remaining.split_at((chunk_len as usize).min(remaining.len()));
+        hash.update(chunk);
+        remaining = tail;
+    }
+
+    assert_eq!(hash.digest::<DEFAULT_DIGEST_SIZE>(), expected);
+});
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use crate::sponge_hash::{DigestIter, SpongeHash256, DEFAULT_DOMAIN, DEFAULT_PERMUTE_ROUNDS};
+use core::{fmt, num::NonZeroUsize};
+
+// ---------------------------------------------------------------------------
+// Builder API
+// ---------------------------------------------------------------------------
+
+/// Builder for configuring a SpongeHash-AES256 instance whose number of permutation rounds is chosen at runtime.
+///
+/// This is useful, if the number of permutation rounds is *not* known at compile-time, e.g., because it is read from a configuration file. Otherwise, the [`SpongeHash256`] struct with its const generic parameter `R` should be preferred.
+///
+/// ### Usage Example
+///
+/// ```rust
+/// use core::{fmt, num::NonZeroUsize};
+/// use sponge_hash_aes256::{SpongeHash256, SpongeHash256Builder};
+///
+/// fn main() {
+///     // Create new hash instance with “info” and 42 permutation rounds
+///     let mut hash = SpongeHash256Builder::new().info("my_application").rounds(NonZeroUsize::new(42).unwrap()).build();
+///
+///     // Process message
+///     hash.update(b"The quick brown fox jumps over the lazy dog");
+///
+///     // Compare to the equivalent const-generic instance
+///     let mut reference: SpongeHash256<42> = SpongeHash256::with_info("my_application");
+///     reference.update(b"The quick brown fox jumps over the lazy dog");
+///
+///     assert_eq!(hash.digest::<32>(), reference.digest::<32>());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpongeHash256Builder<'a> {
+    info: &'a [u8],
+    salt: Option<&'a [u8]>,
+    rounds: NonZeroUsize,
+}
+
+impl<'a> SpongeHash256Builder<'a> {
+    /// Creates a new builder, with an *empty* `info` string, *no* salt and the default number of permutation rounds, as given by [`DEFAULT_PERMUTE_ROUNDS`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self { info: &[], salt: None, rounds: NonZeroUsize::new(DEFAULT_PERMUTE_ROUNDS).unwrap() }
+    }
+
+    /// Sets the `info` string to be used for the hash computation.
+    ///
+    /// **Note:** The length of the `info` string, measured in **bytes** of its UTF-8 encoding, **must not** exceed 255 bytes! The [`build()`](Self::build()) function panics, if the limit is exceeded! &#x1F6A8;
+    #[inline]
+    pub const fn info(mut self, info: &'a str) -> Self {
+        self.info = info.as_bytes();
+        self
+    }
+
+    /// Sets the `info` value, which may contain *arbitrary* bytes, to be used for the hash computation.
+    ///
+    /// See [`SpongeHash256::with_info_bytes()`] for details!
+    ///
+    /// **Note:** The length of the `info` value **must not** exceed 255 bytes! The [`build()`](Self::build()) function panics, if the limit is exceeded! &#x1F6A8;
+    #[inline]
+    pub const fn info_bytes(mut self, info: &'a [u8]) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Sets the `salt` value to be used for the hash computation.
+    ///
+    /// Unlike the `info` string, which is intended for a *fixed* domain separation, the salt is intended to be chosen, e.g., randomly, for each invocation. It is absorbed, prefixed by its length, right after the `info` string.
+    ///
+    /// **Note:** The length of the `salt` value **must not** exceed 255 bytes! The [`build()`](Self::build()) function panics, if the limit is exceeded! &#x1F6A8;
+    #[inline]
+    pub const fn salt(mut self, salt: &'a [u8]) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Sets the number of permutation rounds to be performed, which must be a *positive* value.
+    #[inline]
+    pub const fn rounds(mut self, rounds: NonZeroUsize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Creates a new SpongeHash-AES256 instance with the configured parameters and initializes the hash computation.
+    #[inline]
+    pub fn build(self) -> DynSpongeHash256 {
+        let mut hash = SpongeHash256::with_info_and_rounds(self.info, self.rounds.get());
+        if let Some(salt) = self.salt {
+            hash.absorb_salt(salt, self.rounds.get());
+        }
+        DynSpongeHash256 { hash, rounds: self.rounds }
+    }
+}
+
+impl Default for SpongeHash256Builder<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dynamic-rounds hasher
+// ---------------------------------------------------------------------------
+
+/// This struct encapsulates the state for a “streaming” (incremental) SpongeHash-AES256 computation, with a number of permutation rounds that is chosen at runtime.
+///
+/// Instances are created by the [`SpongeHash256Builder`]. The generated digest is identical to the digest generated by a [`SpongeHash256`] instance whose const generic parameter `R` equals the configured number of permutation rounds.
+#[derive(Clone)]
+pub struct DynSpongeHash256 {
+    hash: SpongeHash256,
+    rounds: NonZeroUsize,
+}
+
+impl DynSpongeHash256 {
+    /// Returns the number of permutation rounds that this instance was configured with.
+    #[inline]
+    pub fn rounds(&self) -> NonZeroUsize {
+        self.rounds
+    }
+
+    /// Processes the next chunk of the message, as given by the `chunk` parameter.
+    ///
+    /// See [`SpongeHash256::update()`] for details!
+    #[inline]
+    pub fn update<T: AsRef<[u8]>>(&mut self, chunk: T) {
+        self.hash.absorb_message(chunk.as_ref(), self.rounds.get());
+    }
+
+    /// Processes the next byte of the message, as given by the `value` parameter.
+    ///
+    /// See [`SpongeHash256::update_byte()`] for details!
+    #[inline]
+    pub fn update_byte(&mut self, value: u8) {
+        self.hash.absorb_byte(value, self.rounds.get());
+    }
+
+    /// Returns the total number of message bytes that have been processed so far.
+    ///
+    /// See [`SpongeHash256::bytes_absorbed()`] for details!
+    #[inline]
+    pub fn bytes_absorbed(&self) -> u64 {
+        self.hash.bytes_absorbed()
+    }
+
+    /// Concludes the hash computation and returns the final digest.
+    ///
+    /// See [`SpongeHash256::digest()`] for details!
+    pub fn digest<const N: usize>(self) -> [u8; N] {
+        let mut digest = [0u8; N];
+        self.digest_to_slice(&mut digest);
+        digest
+    }
+
+    /// Concludes the hash computation and returns the final digest.
+    ///
+    /// See [`SpongeHash256::digest_to_slice()`] for details!
+    pub fn digest_to_slice(self, digest_out: &mut [u8]) {
+        self.digest_to_slice_domain(DEFAULT_DOMAIN, digest_out);
+    }
+
+    /// Concludes the hash computation and returns the final digest, for the given `domain` byte.
+    ///
+    /// See [`SpongeHash256::digest_to_slice_domain()`] for details!
+    pub fn digest_to_slice_domain(self, domain: u8, digest_out: &mut [u8]) {
+        self.hash.squeeze_to_slice(digest_out, domain, self.rounds.get());
+    }
+
+    /// Concludes the hash computation, writes the final digest into `digest_out` and returns a *new* instance that is seeded from the digest.
+    ///
+    /// See [`SpongeHash256::finalize_chain()`] for details! The returned instance uses the same number of permutation rounds.
+    pub fn finalize_chain(self, digest_out: &mut [u8]) -> Self {
+        Self { hash: self.hash.squeeze_and_chain(digest_out, self.rounds.get()), rounds: self.rounds }
+    }
+
+    /// Concludes the hash computation and returns an iterator over the bytes of the final digest.
+    ///
+    /// See [`SpongeHash256::digest_iter()`] for details!
+    pub fn digest_iter(self) -> DigestIter {
+        self.hash.squeeze_iter(self.rounds.get())
+    }
+}
+
+/// Reports the configured number of permutation rounds only, the internal state is **not** revealed.
+impl fmt::Debug for DynSpongeHash256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynSpongeHash256").field("rounds", &self.rounds).finish_non_exhaustive()
+    }
+}
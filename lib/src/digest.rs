@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use crate::sponge_hash::{compute, DEFAULT_DIGEST_SIZE};
+use core::{
+    array,
+    fmt::{self, Write},
+    ops::Deref,
+    slice,
+};
+
+// ---------------------------------------------------------------------------
+// Digest type
+// ---------------------------------------------------------------------------
+
+/// Thin wrapper around a hash value (digest) of size `N`, in bytes, that supports hex formatting.
+///
+/// The digest is formatted as a lower-case hex string by [`Display`](fmt::Display) and [`LowerHex`](fmt::LowerHex), or as an upper-case hex string by [`UpperHex`](fmt::UpperHex). The “alternate” flag, e.g., `{:#x}`, adds the `0x` prefix.
+///
+/// No heap allocation or external dependency is required for formatting. The raw digest bytes are accessible via [`as_bytes()`](Self::as_bytes()) or [`into_inner()`](Self::into_inner()).
+///
+/// A `Digest<N>` can be used wherever a byte slice is expected, because it implements [`AsRef<[u8]>`](AsRef) and [`Deref<Target = [u8]>`](Deref), and it can be iterated over its bytes via [`IntoIterator`]. Conversely, it can be created from a raw `[u8; N]` array via [`From`].
+///
+/// ### Usage Example
+///
+/// The **`Digest`** type can be used as follows:
+///
+/// ```rust
+/// use sponge_hash_aes256::{DEFAULT_DIGEST_SIZE, Digest, compute_digest_typed};
+///
+/// fn main() {
+///     // Compute the digest using the “one-shot” function
+///     let digest: Digest<DEFAULT_DIGEST_SIZE> = compute_digest_typed(
+///         None,
+///         b"The quick brown fox jumps over the lazy dog");
+///
+///     // Print the digest (hex format)
+///     println!("{:#x}", digest);
+///
+///     // The digest can be passed directly to APIs that expect a byte slice
+///     assert_eq!(hex::encode(digest), format!("{}", digest));
+///     assert_eq!(digest.len(), DEFAULT_DIGEST_SIZE);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Digest<const N: usize = DEFAULT_DIGEST_SIZE>(pub [u8; N]);
+
+impl<const N: usize> Digest<N> {
+    /// Get a `&[u8; N]` reference to the contained digest bytes
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Unwraps the contained digest bytes, as `[u8; N]` array
+    #[inline]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Writes the digest bytes as hex string, using the given lower-case or upper-case `digits`
+    fn write_hex(&self, f: &mut fmt::Formatter<'_>, digits: &[u8; 16usize]) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in self.0.iter() {
+            f.write_char(char::from(digits[usize::from(byte >> 4)]))?;
+            f.write_char(char::from(digits[usize::from(byte & 0x0F)]))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Digest<N> {
+    #[inline]
+    fn from(value: [u8; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Digest<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<Digest<N>> for [u8; N] {
+    #[inline]
+    fn from(value: Digest<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> Deref for Digest<N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> IntoIterator for Digest<N> {
+    type Item = u8;
+    type IntoIter = array::IntoIter<u8, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a Digest<N> {
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hex(f, b"0123456789abcdef")
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hex(f, b"0123456789ABCDEF")
+    }
+}
+
+impl<const N: usize> fmt::Display for Digest<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for Digest<N> {
+    type Error = ParseError;
+
+    /// Parses the given hex string into a new `Digest<N>` instance. See [`parse_hex()`] for details!
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_hex(value).map(Self)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hex parsing
+// ---------------------------------------------------------------------------
+
+/// Marker for characters in the nibble table that are *not* valid hex digits
+const INVALID: u8 = 0xFF;
+
+/// Lookup table mapping each ASCII character to its hex digit value, or to [`INVALID`]
+static NIBBLE_TABLE: [u8; 256usize] = {
+    let mut table = [INVALID; 256usize];
+    let mut index = 0usize;
+    while index < 10usize {
+        table[b'0' as usize + index] = index as u8;
+        index += 1usize;
+    }
+    index = 0usize;
+    while index < 6usize {
+        table[b'a' as usize + index] = 10u8 + index as u8;
+        table[b'A' as usize + index] = 10u8 + index as u8;
+        index += 1usize;
+    }
+    table
+};
+
+/// Error type that is returned by [`parse_hex()`], if the given string is *not* a valid hex representation of the digest
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The hex string has an *odd* number of characters
+    OddLength,
+    /// The hex string does *not* have exactly `2 × N` characters
+    InvalidLength,
+    /// The hex string contains a character, at the given byte offset, that is *not* a valid hex digit
+    InvalidCharacter(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::OddLength => f.write_str("hex string has an odd length"),
+            ParseError::InvalidLength => f.write_str("hex string has an invalid length"),
+            ParseError::InvalidCharacter(offset) => write!(f, "invalid hex character at offset {}", offset),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Parses a hex string into a hash value (digest) of size `N`, in bytes.
+///
+/// Both, lower-case and upper-case hex digits are accepted. The string must consist of exactly `2 × N` hex digits, without any prefix or whitespace. This is the inverse of the hex formatting provided by the [`Digest`] type.
+///
+/// This function requires *no* heap allocation or external dependency, so that a stored hex string can be compared to a computed digest even in a `no_std` environment.
+///
+/// ### Usage Example
+///
+/// ```rust
+/// use sponge_hash_aes256::{DEFAULT_DIGEST_SIZE, compute, parse_hex};
+///
+/// fn main() {
+///     // Parse the stored digest
+///     let expected: [u8; DEFAULT_DIGEST_SIZE] = parse_hex("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a").unwrap();
+///
+///     // Compare to the computed digest
+///     assert_eq!(compute::<DEFAULT_DIGEST_SIZE, _>(None, b"The quick brown fox jumps over the lazy dog"), expected);
+/// }
+/// ```
+pub fn parse_hex<const N: usize>(s: &str) -> Result<[u8; N], ParseError> {
+    let digits = s.as_bytes();
+    if !digits.len().is_multiple_of(2usize) {
+        return Err(ParseError::OddLength);
+    }
+    if digits.len() != N.saturating_mul(2usize) {
+        return Err(ParseError::InvalidLength);
+    }
+
+    let mut digest = [0u8; N];
+    for (index, (pair, value)) in digits.chunks_exact(2usize).zip(digest.iter_mut()).enumerate() {
+        let upper = NIBBLE_TABLE[usize::from(pair[0usize])];
+        if upper == INVALID {
+            return Err(ParseError::InvalidCharacter(2usize * index));
+        }
+        let lower = NIBBLE_TABLE[usize::from(pair[1usize])];
+        if lower == INVALID {
+            return Err(ParseError::InvalidCharacter(2usize * index + 1usize));
+        }
+        *value = (upper << 4) | lower;
+    }
+
+    Ok(digest)
+}
+
+// ---------------------------------------------------------------------------
+// One-Shot API
+// ---------------------------------------------------------------------------
+
+/// Convenience function for “one-shot” SpongeHash-AES256 computation
+///
+/// The hash value (digest) of the given `message` is returned as an new [`Digest<N>`] instance, which supports hex formatting. Otherwise, this function behaves exactly like [`compute()`](crate::compute()).
+///
+/// **Note:** The digest output size `N`, in bytes, must be a *positive* value! &#x1F6A8;
+#[inline]
+pub fn compute_digest_typed<const N: usize, T: AsRef<[u8]>>(info: Option<&str>, message: T) -> Digest<N> {
+    Digest(compute(info, message))
+}
@@ -15,6 +15,12 @@
 //!
 //! Please see the **[`SpongeHash256`]** struct for details! &#128161;
 //!
+//! For convenient hex formatting of the hash value (digest), the **[`Digest`]** wrapper type is provided. Conversely, a hex string can be parsed by the **[`parse_hex()`]** function.
+//!
+//! If the number of permutation rounds is only known at runtime, the **[`SpongeHash256Builder`]** can be used to create a suitable instance.
+//!
+//! For the *parallel* hashing of large messages, a “tree” hashing mode is provided by the **[`SpongeTree256`]** struct.
+//!
 //! ## Dependencies
 //!
 //! This crate is **`#![no_std]`** compatible and does not link the Rust standard library.
@@ -23,9 +29,12 @@
 //!
 //! ## Optional features
 //!
-//! Feature   | Meaning
-//! --------- | -----------------------------------------------------------------------------------------------------------------------
-//! `tracing` | Dump the internal state to the logging sub-system (via `log::trace()`) after each step.
+//! Feature               | Meaning
+//! --------------------- | -----------------------------------------------------------------------------------------------------------
+//! `tracing`             | Dump the internal state to the logging sub-system (via `log::trace()`) after each step.
+//! `alloc`               | Enable the `compute_into_vec()` function, which requires the [`alloc`](https://doc.rust-lang.org/alloc/) crate.
+//! `std`                 | Enable the `SpongeHash256::absorb_reader()` function, which requires the Rust standard library. Implies `alloc`.
+//! `debug-introspection` | Expose a snapshot of the internal state via `SpongeHash256::state_snapshot()`, e.g., for cross-validation, and implement `PartialEq`/`Eq` for comparing the state of two `SpongeHash256` instances.
 //!
 //! ## Rust support
 //!
@@ -44,8 +53,22 @@
 //! &#x1F517; <https://crates.io/crates/sponge-hash-aes256>  
 //! &#x1F517; <https://github.com/lordmulder/sponge-hash-aes256>
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod builder;
+mod digest;
 mod sponge_hash;
+mod tree_hash;
 mod utilities;
 
-pub use sponge_hash::{compute, compute_to_slice, SpongeHash256, DEFAULT_DIGEST_SIZE, DEFAULT_PERMUTE_ROUNDS};
-pub use utilities::version;
+pub use builder::{DynSpongeHash256, SpongeHash256Builder};
+pub use digest::{compute_digest_typed, parse_hex, Digest, ParseError};
+#[cfg(feature = "alloc")]
+pub use sponge_hash::compute_into_vec;
+pub use sponge_hash::{compute, compute_chunks, compute_to_slice, derive_keys, DigestIter, SpongeHash256, DEFAULT_DIGEST_SIZE, DEFAULT_PERMUTE_ROUNDS};
+pub use tree_hash::{compute_tree, compute_tree_to_slice, SpongeTree256, TREE_LEAF_SIZE};
+pub use utilities::{version, version_parts};
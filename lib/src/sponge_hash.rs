@@ -2,8 +2,14 @@
 // SpongeHash-AES256
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
-use crate::utilities::{length, Aes256Crypto, BlockType, BLOCK_SIZE};
-use core::ops::Range;
+use crate::utilities::{Aes256Crypto, BlockType, BLOCK_SIZE};
+use core::{fmt, iter::FusedIterator, ops::Range};
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::io::{ErrorKind as IoErrorKind, Read, Result as IoResult};
 
 /// Default digest size, in bytes
 ///
@@ -15,11 +21,21 @@ pub const DEFAULT_DIGEST_SIZE: usize = 2usize * BLOCK_SIZE;
 /// The default number of permutation rounds is currently defined as **1**.
 pub const DEFAULT_PERMUTE_ROUNDS: usize = 1usize;
 
+/// Size of the buffer that is used to read the message from an [`std::io::Read`] source
+#[cfg(feature = "std")]
+const READ_BUFFER_SIZE: usize = 8192usize;
+
 /// Pre-define round keys
 static ROUND_KEY_X: BlockType = BlockType::new::<0x5Cu8>();
 static ROUND_KEY_Y: BlockType = BlockType::new::<0x36u8>();
 static ROUND_KEY_Z: BlockType = BlockType::new::<0x6Au8>();
 
+/// The `info` string that is used to seed the next instance in a hash chain
+const CHAIN_INFO: &str = "SpongeHash-AES256/chain";
+
+/// The domain byte that is used for the finalization, unless specified otherwise
+pub(crate) const DEFAULT_DOMAIN: u8 = 0u8;
+
 // ---------------------------------------------------------------------------
 // Tracing
 // ---------------------------------------------------------------------------
@@ -148,12 +164,13 @@ impl Default for Scratch {
 ///
 /// The padding of the final input block is performed by first appending a single `1` bit, followed by the minimal number of `0` bits needed to make the total message length a multiple of the block size.
 ///
-/// Following the final input block, a 128-bit block filled entirely with `0x6A` bytes is absorbed into the state.
+/// Following the final input block, a 128-bit block filled entirely with `0x6A` bytes is absorbed into the state. The first byte of that block is additionally XOR'ed with the *domain* byte, which is `0` by default (see [`digest_to_slice_domain()`](Self::digest_to_slice_domain())).
 #[repr(align(32))]
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct SpongeHash256<const R: usize = DEFAULT_PERMUTE_ROUNDS> {
     state: (BlockType, BlockType, BlockType),
     offset: usize,
+    length: u64,
 }
 
 impl<const R: usize> SpongeHash256<R> {
@@ -167,24 +184,40 @@ impl<const R: usize> SpongeHash256<R> {
 
     /// Creates a new SpongeHash-AES256 instance and initializes the hash computation with the given `info` string.
     ///
-    /// **Note:** The length of the `info` string **must not** exceed a length of 255 characters!
+    /// **Note:** The length of the `info` string, measured in **bytes** of its UTF-8 encoding, **must not** exceed 255 bytes! Characters outside of the ASCII range occupy multiple bytes each, so a string of fewer than 255 characters may still exceed the limit. This function panics, if the limit is exceeded! &#x1F6A8;
     #[inline]
     pub fn with_info(info: &str) -> Self {
+        Self::with_info_bytes(info.as_bytes())
+    }
+
+    /// Creates a new SpongeHash-AES256 instance and initializes the hash computation with the given `info` value, which may contain *arbitrary* bytes.
+    ///
+    /// This is useful, if the domain separator is a *binary* value, e.g., a 16-byte protocol identifier. Calling [`with_info(info)`](Self::with_info()) is exactly equivalent to calling `with_info_bytes(info.as_bytes())`.
+    ///
+    /// **Note:** The length of the `info` value **must not** exceed 255 bytes! This function panics, if the limit is exceeded! &#x1F6A8;
+    #[inline]
+    pub fn with_info_bytes(info: &[u8]) -> Self {
         let () = NoneZeroArg::<R>::OK;
-        let mut hash = Self { state: (BlockType::zero(), BlockType::zero(), BlockType::zero()), offset: 0usize };
-        hash.initialize(info.as_bytes());
+        Self::with_info_and_rounds(info, R)
+    }
+
+    /// Creates a new instance, using the given number of permutation rounds instead of `R`
+    #[inline]
+    pub(crate) fn with_info_and_rounds(info: &[u8], rounds: usize) -> Self {
+        let mut hash = Self { state: (BlockType::zero(), BlockType::zero(), BlockType::zero()), offset: 0usize, length: 0u64 };
+        hash.initialize(info, rounds);
         hash
     }
 
     /// Initializes the internal state with the given `info` string
     #[inline]
-    fn initialize(&mut self, info_data: &[u8]) {
+    fn initialize(&mut self, info_data: &[u8], rounds: usize) {
         trace!(self, "initlz::enter");
 
         match info_data.len().try_into() {
             Ok(length) => {
-                self.update(u8::to_be_bytes(length));
-                self.update(info_data);
+                self.absorb(&u8::to_be_bytes(length), rounds);
+                self.absorb(info_data, rounds);
             }
             Err(_) => panic!("Info length exceeds the allowable maximum!"),
         };
@@ -192,6 +225,18 @@ impl<const R: usize> SpongeHash256<R> {
         trace!(self, "initlz::leave");
     }
 
+    /// Absorbs the given `salt` value, length-prefixed, right after the `info` block
+    #[inline]
+    pub(crate) fn absorb_salt(&mut self, salt_data: &[u8], rounds: usize) {
+        match salt_data.len().try_into() {
+            Ok(length) => {
+                self.absorb(&u8::to_be_bytes(length), rounds);
+                self.absorb(salt_data, rounds);
+            }
+            Err(_) => panic!("Salt length exceeds the allowable maximum!"),
+        };
+    }
+
     /// Processes the next chunk of the message, as given by the `chunk` parameter.
     ///
     /// A `chunk` can be of *any* type that implements the [`AsRef<[u8]>`](AsRef<T>) trait, e.g., `&[u8]`, `&str` or `String`.
@@ -199,58 +244,111 @@ impl<const R: usize> SpongeHash256<R> {
     /// The internal state of the hash computation is updated by this function.
     #[inline]
     pub fn update<T: AsRef<[u8]>>(&mut self, chunk: T) {
-        trace!(self, "update::enter");
-
-        let source = chunk.as_ref().as_ptr_range();
-        if !source.is_empty() {
-            unsafe {
-                self.update_range(source);
-            }
-        }
+        self.absorb_message(chunk.as_ref(), R);
+    }
 
-        trace!(self, "update::leave");
+    /// Absorbs the next chunk of the message and accounts for its length, using the given number of permutation rounds
+    #[inline]
+    pub(crate) fn absorb_message(&mut self, chunk: &[u8], rounds: usize) {
+        self.length = self.length.wrapping_add(chunk.len() as u64);
+        self.absorb(chunk, rounds);
     }
 
-    /// Processes the next chunk of "raw" bytes, as specified by the [`Range<*const u8>`](slice::as_ptr_range) in the `source` parameter.
-    ///
-    /// The internal state of the hash computation is updated by this function.
-    ///
-    /// # Safety
-    ///
-    /// The caller **must** ensure that *all* byte addresses in the range from `source.start` up to but excluding `source.end` are valid!
+    /// Absorbs the next chunk of the message, using the given number of permutation rounds
     #[inline]
-    pub unsafe fn update_range(&mut self, source: Range<*const u8>) {
-        let mut source_next = source.start;
+    pub(crate) fn absorb(&mut self, chunk: &[u8], rounds: usize) {
+        trace!(self, "update::enter");
+
         let mut scratch_buffer = Scratch::default();
+        let head_length = if self.offset != 0usize { (BLOCK_SIZE - self.offset).min(chunk.len()) } else { 0usize };
+        let (head, tail) = chunk.split_at(head_length);
 
-        while (self.offset != 0usize) && (source_next < source.end) {
-            self.state.0[self.offset] ^= *source_next;
+        for value in head {
+            self.state.0[self.offset] ^= *value;
             self.offset += 1usize;
-            source_next = source_next.add(1usize);
 
             if self.offset >= BLOCK_SIZE {
-                self.permute(&mut scratch_buffer);
+                self.permute(&mut scratch_buffer, rounds);
                 self.offset = 0usize;
             }
         }
 
-        if source_next < source.end {
+        let blocks = tail.chunks_exact(BLOCK_SIZE);
+        let remainder = blocks.remainder();
+
+        for block in blocks {
             debug_assert_eq!(self.offset, 0usize);
+            self.state.0.xor_with_array(block.try_into().unwrap());
+            self.permute(&mut scratch_buffer, rounds);
+        }
 
-            while length(source_next, source.end) >= BLOCK_SIZE {
-                self.state.0.xor_with_u8_ptr(source_next);
-                self.permute(&mut scratch_buffer);
-                source_next = source_next.add(BLOCK_SIZE);
-            }
+        for value in remainder {
+            self.state.0[self.offset] ^= *value;
+            self.offset += 1usize;
+        }
+
+        debug_assert!(self.offset < BLOCK_SIZE);
+        trace!(self, "update::leave");
+    }
 
-            while source_next < source.end {
-                self.state.0[self.offset] ^= *source_next;
-                self.offset += 1usize;
-                source_next = source_next.add(1usize);
+    /// Processes the remainder of the message, as read from the given `reader`, until the end of the stream is reached.
+    ///
+    /// The message is read in chunks, which are processed as if they were passed to [`update()`](Self::update()). Returns the total number of bytes that have been processed. The hash computation can be continued, or concluded, afterwards.
+    ///
+    /// If an I/O error occurs, except for [`Interrupted`](std::io::ErrorKind::Interrupted), the error is returned and the hash computation *should* be discarded, because an unknown amount of the message may have been processed.
+    #[cfg(feature = "std")]
+    pub fn absorb_reader<T: Read>(&mut self, reader: &mut T) -> IoResult<u64> {
+        let mut buffer = [0u8; READ_BUFFER_SIZE];
+        let mut total_bytes = 0u64;
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0usize) => return Ok(total_bytes),
+                Ok(length) => {
+                    self.update(&buffer[..length]);
+                    total_bytes += length as u64;
+                }
+                Err(error) if error.kind() == IoErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
             }
         }
+    }
 
-        debug_assert!(self.offset < BLOCK_SIZE);
+    /// Processes the next byte of the message, as given by the `value` parameter.
+    ///
+    /// This is equivalent to calling [`update()`](Self::update()) with a slice of length one, but avoids the overhead of the slice handling, e.g., for callers that feed the message one byte at a time.
+    ///
+    /// The internal state of the hash computation is updated by this function.
+    #[inline]
+    pub fn update_byte(&mut self, value: u8) {
+        self.absorb_byte(value, R);
+    }
+
+    /// Absorbs the next byte of the message, using the given number of permutation rounds
+    #[inline]
+    pub(crate) fn absorb_byte(&mut self, value: u8, rounds: usize) {
+        self.length = self.length.wrapping_add(1u64);
+        self.state.0[self.offset] ^= value;
+        self.offset += 1usize;
+
+        if self.offset >= BLOCK_SIZE {
+            self.permute(&mut Scratch::default(), rounds);
+            self.offset = 0usize;
+        }
+    }
+
+    /// Processes the next chunk of "raw" bytes, as specified by the [`Range<*const u8>`](slice::as_ptr_range) in the `source` parameter.
+    ///
+    /// The internal state of the hash computation is updated by this function.
+    ///
+    /// # Safety
+    ///
+    /// The caller **must** ensure that *all* byte addresses in the range from `source.start` up to but excluding `source.end` are valid!
+    #[inline]
+    pub unsafe fn update_range(&mut self, source: Range<*const u8>) {
+        if source.start < source.end {
+            self.absorb_message(core::slice::from_raw_parts(source.start, source.end.offset_from_unsigned(source.start)), R);
+        }
     }
 
     /// Concludes the hash computation and returns the final digest.
@@ -274,20 +372,48 @@ impl<const R: usize> SpongeHash256<R> {
     /// The output slice is filled completely, generating a hash value (digest) of the appropriate size.
     ///
     /// **Note:** The specified digest output size, i.e., `digest_out.len()`, in bytes, must be a *positive* value! &#x1F6A8;
-    pub fn digest_to_slice(mut self, digest_out: &mut [u8]) {
+    pub fn digest_to_slice(self, digest_out: &mut [u8]) {
+        self.squeeze_to_slice(digest_out, DEFAULT_DOMAIN, R);
+    }
+
+    /// Concludes the hash computation and returns the final digest, for the given `domain` byte.
+    ///
+    /// This allows for deriving multiple *independent* outputs, e.g., subkeys, from a single absorbed message: Since the `domain` byte is mixed into the state during the finalization, the outputs generated for different `domain` bytes are unrelated.
+    ///
+    /// The digest generated with `domain` equal to `0` is identical to the digest generated by [`digest_to_slice()`](Self::digest_to_slice()).
+    ///
+    /// **Note:** The specified digest output size, i.e., `digest_out.len()`, in bytes, must be a *positive* value! &#x1F6A8;
+    ///
+    /// ```rust
+    /// use sponge_hash_aes256::SpongeHash256;
+    ///
+    /// fn main() {
+    ///     let mut hash: SpongeHash256 = SpongeHash256::new();
+    ///     hash.update(b"The quick brown fox jumps over the lazy dog");
+    ///
+    ///     let (mut key_1, mut key_2) = ([0u8; 32], [0u8; 32]);
+    ///     hash.clone().digest_to_slice_domain(1u8, &mut key_1);
+    ///     hash.digest_to_slice_domain(2u8, &mut key_2);
+    ///     assert_ne!(key_1, key_2);
+    /// }
+    /// ```
+    pub fn digest_to_slice_domain(self, domain: u8, digest_out: &mut [u8]) {
+        self.squeeze_to_slice(digest_out, domain, R);
+    }
+
+    /// Concludes the hash computation and writes the final digest, using the given domain byte and number of permutation rounds
+    #[inline]
+    pub(crate) fn squeeze_to_slice(mut self, digest_out: &mut [u8], domain: u8, rounds: usize) {
         trace!(self, "digest::enter");
         assert!(!digest_out.is_empty(), "Digest output size must be positive!");
 
         let mut scratch_buffer = Scratch::default();
-
-        self.state.0[self.offset] ^= 0x80u8;
-        self.permute(&mut scratch_buffer);
-        self.state.0.xor_with(&ROUND_KEY_Z);
+        self.finalize(&mut scratch_buffer, domain, rounds);
 
         let mut pos = 0usize;
 
         while pos < digest_out.len() {
-            self.permute(&mut scratch_buffer);
+            self.permute(&mut scratch_buffer, rounds);
             let copy_len = BLOCK_SIZE.min(digest_out.len() - pos);
             digest_out[pos..(pos + copy_len)].copy_from_slice(&self.state.0[..copy_len]);
             pos += copy_len;
@@ -296,12 +422,111 @@ impl<const R: usize> SpongeHash256<R> {
         trace!(self, "digest::leave");
     }
 
+    /// Concludes the hash computation, writes the final digest into `digest_out` and returns a *new* instance that is seeded from the digest.
+    ///
+    /// This allows for “chained” constructions, where the hash computation of message *B* is seeded by the final digest of message *A*, without the need to reconstruct the state manually.
+    ///
+    /// The returned instance is exactly equivalent to an instance that was created by [`with_info("SpongeHash-AES256/chain")`](Self::with_info()), and then was updated with the length of `digest_out`, in bytes, encoded as a 64-bit big-endian value, followed by the contents of `digest_out`. This includes the number of bytes processed, as returned by [`bytes_absorbed()`](Self::bytes_absorbed()), which starts at `8 + digest_out.len()`. Hence, the chained computation is *domain-separated* from the “plain” hash computation with an empty `info` string.
+    ///
+    /// **Note:** The specified digest output size, i.e., `digest_out.len()`, in bytes, must be a *positive* value! &#x1F6A8;
+    ///
+    /// ```rust
+    /// use sponge_hash_aes256::SpongeHash256;
+    ///
+    /// fn main() {
+    ///     let mut hash_a: SpongeHash256 = SpongeHash256::new();
+    ///     hash_a.update(b"message A");
+    ///
+    ///     let mut digest_a = [0u8; 32];
+    ///     let mut hash_b = hash_a.finalize_chain(&mut digest_a);
+    ///     hash_b.update(b"message B");
+    ///
+    ///     let digest_b = hash_b.digest::<32>();
+    /// }
+    /// ```
+    pub fn finalize_chain(self, digest_out: &mut [u8]) -> Self {
+        self.squeeze_and_chain(digest_out, R)
+    }
+
+    /// Concludes the hash computation, writes the final digest and returns a new instance seeded from the digest, using the given number of permutation rounds
+    #[inline]
+    pub(crate) fn squeeze_and_chain(self, digest_out: &mut [u8], rounds: usize) -> Self {
+        self.squeeze_to_slice(digest_out, DEFAULT_DOMAIN, rounds);
+        let mut hash = Self::with_info_and_rounds(CHAIN_INFO.as_bytes(), rounds);
+        hash.absorb_message(&u64::to_be_bytes(digest_out.len() as u64), rounds);
+        hash.absorb_message(digest_out, rounds);
+        hash
+    }
+
+    /// Concludes the hash computation and returns an iterator over the bytes of the final digest.
+    ///
+    /// The returned iterator squeezes the hash value (digest) lazily, i.e., one block at a time, and yields an *unbounded* sequence of bytes. Use, e.g., [`Iterator::take()`] in order to limit the digest size.
+    ///
+    /// The first `k` bytes yielded by the iterator are identical to the digest of size `k` that is generated by [`digest_to_slice()`](Self::digest_to_slice()).
+    ///
+    /// ```rust
+    /// use sponge_hash_aes256::SpongeHash256;
+    ///
+    /// fn main() {
+    ///     let mut hash: SpongeHash256 = SpongeHash256::new();
+    ///     hash.update(b"The quick brown fox jumps over the lazy dog");
+    ///
+    ///     let digest_1 = hash.clone().digest_iter().take(64).collect::<Vec<_>>();
+    ///     let digest_2 = hash.digest::<64>();
+    ///
+    ///     assert_eq!(digest_1, digest_2);
+    /// }
+    /// ```
+    pub fn digest_iter(self) -> DigestIter<R> {
+        self.squeeze_iter(R)
+    }
+
+    /// Concludes the hash computation and returns a digest iterator, using the given number of permutation rounds
+    #[inline]
+    pub(crate) fn squeeze_iter(mut self, rounds: usize) -> DigestIter<R> {
+        trace!(self, "digest::enter");
+
+        let mut scratch_buffer = Scratch::default();
+        self.finalize(&mut scratch_buffer, DEFAULT_DOMAIN, rounds);
+        self.offset = BLOCK_SIZE;
+
+        trace!(self, "digest::leave");
+        DigestIter { hash: self, scratch: scratch_buffer, rounds }
+    }
+
+    /// Returns the total number of message bytes that have been processed so far, e.g., for accounting purposes.
+    ///
+    /// This counts all bytes that have been passed to [`update()`](Self::update()), [`update_byte()`](Self::update_byte()) or [`update_range()`](Self::update_range()). The `info` string is **not** counted. The counter does **not** affect the computed digest in any way.
+    #[inline]
+    pub fn bytes_absorbed(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns a snapshot of the internal state, i.e., a copy of the three 128-bit state blocks and the current offset into the first block.
+    ///
+    /// This function is intended for cross-implementation validation, e.g., for comparing the intermediate state to a reference implementation. It is available only if the `debug-introspection` feature is enabled.
+    ///
+    /// **Note:** The returned copy of the state is ***not*** zeroized automatically; it is the responsibility of the caller to dispose of it securely! &#x1F6A8;
+    #[cfg(feature = "debug-introspection")]
+    pub fn state_snapshot(&self) -> ([u8; BLOCK_SIZE], [u8; BLOCK_SIZE], [u8; BLOCK_SIZE], usize) {
+        (self.state.0.to_array(), self.state.1.to_array(), self.state.2.to_array(), self.offset)
+    }
+
+    /// Pads the final input block and absorbs the finalization block, so that the output can be squeezed
+    #[inline]
+    fn finalize(&mut self, work: &mut Scratch, domain: u8, rounds: usize) {
+        self.state.0[self.offset] ^= 0x80u8;
+        self.permute(work, rounds);
+        self.state.0.xor_with(&ROUND_KEY_Z);
+        self.state.0[0usize] ^= domain;
+    }
+
     /// Pseudorandom permutation, based on the AES-256 block cipher
     #[inline]
-    fn permute(&mut self, work: &mut Scratch) {
+    fn permute(&mut self, work: &mut Scratch, rounds: usize) {
         trace!(self, "permfn::enter");
 
-        for _ in 0..R {
+        for _ in 0..rounds {
             work.aes256.encrypt(&mut work.temp.0, &self.state.0, &self.state.1, &self.state.2);
             work.aes256.encrypt(&mut work.temp.1, &self.state.1, &self.state.2, &self.state.0);
             work.aes256.encrypt(&mut work.temp.2, &self.state.2, &self.state.0, &self.state.1);
@@ -325,6 +550,66 @@ impl Default for SpongeHash256 {
     }
 }
 
+/// Formats the number of permutation rounds, the current offset and the number of bytes processed so far.
+///
+/// The internal state blocks are deliberately **redacted**, i.e., they are *not* included in the output, so that secret state can not leak into log files by accident.
+impl<const R: usize> fmt::Debug for SpongeHash256<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpongeHash256").field("rounds", &R).field("offset", &self.offset).field("bytes_absorbed", &self.length).finish_non_exhaustive()
+    }
+}
+
+/// Compares the complete internal state, i.e., the three 128-bit state blocks and the current offset, of two instances.
+///
+/// The number of bytes that have been processed, as returned by [`bytes_absorbed()`](SpongeHash256::bytes_absorbed()), is *not* part of the internal state and therefore is **not** compared.
+///
+/// All state blocks are compared, regardless of whether a difference has already been found, so that the timing does not depend on the position of the first difference. It is available only if the `debug-introspection` feature is enabled.
+#[cfg(feature = "debug-introspection")]
+impl<const R: usize> PartialEq for SpongeHash256<R> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.state.0 == other.state.0) & (self.state.1 == other.state.1) & (self.state.2 == other.state.2) & (self.offset == other.offset)
+    }
+}
+
+#[cfg(feature = "debug-introspection")]
+impl<const R: usize> Eq for SpongeHash256<R> {}
+
+// ---------------------------------------------------------------------------
+// Digest iterator
+// ---------------------------------------------------------------------------
+
+/// Iterator over the bytes of a SpongeHash-AES256 digest, as returned by [`SpongeHash256::digest_iter()`].
+///
+/// This iterator yields an *unbounded* sequence of bytes, i.e., it never returns `None`. A new output block is squeezed from the state, whenever the previous block has been consumed.
+pub struct DigestIter<const R: usize = DEFAULT_PERMUTE_ROUNDS> {
+    hash: SpongeHash256<R>,
+    scratch: Scratch,
+    rounds: usize,
+}
+
+impl<const R: usize> Iterator for DigestIter<R> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.hash.offset >= BLOCK_SIZE {
+            self.hash.permute(&mut self.scratch, self.rounds);
+            self.hash.offset = 0usize;
+        }
+
+        let value = self.hash.state.0[..BLOCK_SIZE][self.hash.offset];
+        self.hash.offset += 1usize;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<const R: usize> FusedIterator for DigestIter<R> {}
+
 // ---------------------------------------------------------------------------
 // One-Shot API
 // ---------------------------------------------------------------------------
@@ -335,7 +620,7 @@ impl Default for SpongeHash256 {
 ///
 /// A `message` can be of *any* type that implements the [`AsRef<[u8]>`](AsRef<T>) trait, e.g., `&[u8]`, `&str` or `String`.
 ///
-/// Optionally, an additional `info` string may be specified.
+/// Optionally, an additional `info` string may be specified, which must be non-empty and must not exceed 255 bytes; this function panics otherwise.
 ///
 /// The returned array is filled completely, generating a hash value (digest) of the appropriate size.
 ///
@@ -402,7 +687,7 @@ pub fn compute<const N: usize, T: AsRef<[u8]>>(info: Option<&str>, message: T) -
 ///
 /// A `message` can be of *any* type that implements the [`AsRef<[u8]>`](AsRef<T>) trait, e.g., `&[u8]`, `&str` or `String`.
 ///
-/// Optionally, an additional `info` string may be specified.
+/// Optionally, an additional `info` string may be specified, subject to the same constraints as for [`compute()`].
 ///
 /// The output slice is filled completely, generating a hash value (digest) of the appropriate size.
 ///
@@ -463,3 +748,97 @@ pub fn compute_to_slice<T: AsRef<[u8]>>(digest_out: &mut [u8], info: Option<&str
     state.update(message);
     state.digest_to_slice(digest_out);
 }
+
+/// Convenience function for “one-shot” SpongeHash-AES256 computation over a sequence of chunks
+///
+/// The hash value (digest) of the *concatenation* of all chunks produced by the iterator `chunks` is returned, as an array of type `[u8; N]`. Otherwise, this function behaves exactly like [`compute()`]. This is useful, if the message is *not* available as a contiguous slice, e.g., a sequence of [`IoSlice`](https://doc.rust-lang.org/std/io/struct.IoSlice.html)s, because no copying is required.
+///
+/// Each chunk can be of *any* type that implements the [`AsRef<[u8]>`](AsRef<T>) trait, e.g., `&[u8]`, `&str` or `String`.
+///
+/// **Note:** The digest output size `N`, in bytes, must be a *positive* value! &#x1F6A8;
+///
+/// ### Usage Example
+///
+/// The **`compute_chunks()`** function can be used as follows:
+///
+/// ```rust
+/// use sponge_hash_aes256::{DEFAULT_DIGEST_SIZE, compute, compute_chunks};
+///
+/// fn main() {
+///     // Compute the digest over a sequence of chunks
+///     let digest: [u8; DEFAULT_DIGEST_SIZE] = compute_chunks(
+///         None,
+///         ["The quick brown fox ", "jumps over the lazy dog"]);
+///
+///     // Equivalent to the digest of the concatenated message
+///     assert_eq!(digest, compute::<DEFAULT_DIGEST_SIZE, _>(None, "The quick brown fox jumps over the lazy dog"));
+/// }
+/// ```
+pub fn compute_chunks<const N: usize, I: IntoIterator<Item = T>, T: AsRef<[u8]>>(info: Option<&str>, chunks: I) -> [u8; N] {
+    assert!(!info.is_some_and(str::is_empty), "Info must not be empty!");
+    let mut state: SpongeHash256 = SpongeHash256::with_info(info.unwrap_or_default());
+    chunks.into_iter().for_each(|chunk| state.update(chunk));
+    state.digest()
+}
+
+/// Convenience function for deriving multiple subkeys from a single input keying material
+///
+/// The input keying material `ikm` is absorbed *once*, and then `K` independent keys of `N` bytes each are squeezed, where the `k`-th key (zero-based) is generated with the domain byte `k + 1`, as by [`SpongeHash256::digest_to_slice_domain()`]. This is more efficient than `K` separate hash computations, while the derived keys are still unrelated to each other, *and* to the plain digest of `ikm`.
+///
+/// Optionally, an additional `info` string may be specified, subject to the same constraints as for [`compute()`].
+///
+/// **Note:** The key size `N`, in bytes, must be a *positive* value, and the number of keys `K` must not exceed 255! &#x1F6A8;
+///
+/// ### Usage Example
+///
+/// The **`derive_keys()`** function can be used as follows:
+///
+/// ```rust
+/// use sponge_hash_aes256::derive_keys;
+///
+/// fn main() {
+///     // Derive an encryption key and a MAC key from the same input
+///     let [enc_key, mac_key] = derive_keys::<32usize, 2usize>(Some("my_application"), b"shared secret");
+///     assert_ne!(enc_key, mac_key);
+/// }
+/// ```
+pub fn derive_keys<const N: usize, const K: usize>(info: Option<&str>, ikm: &[u8]) -> [[u8; N]; K] {
+    assert!(!info.is_some_and(str::is_empty), "Info must not be empty!");
+    assert!(K <= u8::MAX as usize, "Number of keys must not exceed 255!");
+    let mut state: SpongeHash256 = SpongeHash256::with_info(info.unwrap_or_default());
+    state.update(ikm);
+    core::array::from_fn(|index| {
+        let mut key = [0u8; N];
+        state.clone().digest_to_slice_domain((index + 1usize) as u8, &mut key);
+        key
+    })
+}
+
+/// Convenience function for “one-shot” SpongeHash-AES256 computation, with a digest size that is chosen at runtime
+///
+/// The hash value (digest) of the given `message` is returned as a new heap-allocated `Vec<u8>` of `length` bytes. Otherwise, this function behaves exactly like [`compute_to_slice()`].
+///
+/// This function is available only if the `alloc` feature is enabled.
+///
+/// **Note:** The digest output size `length`, in bytes, must be a *positive* value! &#x1F6A8;
+///
+/// ### Usage Example
+///
+/// The **`compute_into_vec()`** function can be used as follows:
+///
+/// ```rust
+/// use sponge_hash_aes256::compute_into_vec;
+///
+/// fn main() {
+///     // Compute digest using the “one-shot” function, the digest size is chosen at runtime
+///     let digest = compute_into_vec(None, 48usize, b"The quick brown fox jumps over the lazy dog");
+///     assert_eq!(digest.len(), 48usize);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compute_into_vec<T: AsRef<[u8]>>(info: Option<&str>, length: usize, message: T) -> Vec<u8> {
+    assert!(length > 0usize, "Digest output size must be positive!");
+    let mut digest_out = vec![0u8; length];
+    compute_to_slice(&mut digest_out, info, message);
+    digest_out
+}
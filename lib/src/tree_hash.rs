@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use crate::sponge_hash::{SpongeHash256, DEFAULT_DIGEST_SIZE};
+
+/// Size of a single leaf, in bytes, as used by the tree hashing mode
+///
+/// The leaf size is currently defined as **1048576** bytes, i.e., **1** MiB.
+pub const TREE_LEAF_SIZE: usize = 1024usize * 1024usize;
+
+/// Domain separation string for the leaf digests
+const LEAF_INFO: &str = "SpongeHash-AES256/tree/leaf";
+
+/// Domain separation string for the root digest
+const ROOT_INFO: &str = "SpongeHash-AES256/tree/root";
+
+// ---------------------------------------------------------------------------
+// Tree hashing API
+// ---------------------------------------------------------------------------
+
+/// This struct encapsulates the state for a “tree” SpongeHash-AES256 computation.
+///
+/// In tree hashing mode, the message is split into consecutive leaves of [`TREE_LEAF_SIZE`] bytes each; only the final leaf may be shorter. Each leaf is hashed *independently*, using a dedicated leaf-domain “info” string, which allows for the leaves to be processed in parallel. Finally, the concatenation of all leaf digests, in the original order, is hashed using a dedicated root-domain “info” string.
+///
+/// **Note:** The digests generated in tree hashing mode are ***different*** from the digests that are generated by [`SpongeHash256`] or [`compute()`](crate::compute()) for the same message! &#x1F6A8;
+///
+/// ### Usage Example
+///
+/// The **`SpongeTree256`** structure can be used as follows:
+///
+/// ```rust
+/// use sponge_hash_aes256::{DEFAULT_DIGEST_SIZE, SpongeTree256, TREE_LEAF_SIZE};
+///
+/// fn main() {
+///     let message = vec![0x2Au8; 3usize * TREE_LEAF_SIZE];
+///
+///     // Create new tree hash instance
+///     let mut tree = SpongeTree256::new();
+///
+///     // Process all leaves (this could be done in parallel)
+///     for leaf in message.chunks(TREE_LEAF_SIZE) {
+///         tree.update(&SpongeTree256::leaf_digest(leaf));
+///     }
+///
+///     // Retrieve the final digest
+///     let digest = tree.digest::<DEFAULT_DIGEST_SIZE>();
+///     /* ... */
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpongeTree256 {
+    root: SpongeHash256,
+}
+
+impl SpongeTree256 {
+    /// Creates a new tree hash instance and initializes the root-level hash computation.
+    #[inline]
+    pub fn new() -> Self {
+        Self { root: SpongeHash256::with_info(ROOT_INFO) }
+    }
+
+    /// Computes the digest of a single leaf, using the leaf-domain “info” string.
+    ///
+    /// **Note:** The length of the `leaf` **must not** exceed [`TREE_LEAF_SIZE`], and only the *final* leaf of a message may be shorter! &#x1F6A8;
+    #[inline]
+    pub fn leaf_digest(leaf: &[u8]) -> [u8; DEFAULT_DIGEST_SIZE] {
+        assert!(leaf.len() <= TREE_LEAF_SIZE, "Leaf size exceeds the allowable maximum!");
+        let mut state: SpongeHash256 = SpongeHash256::with_info(LEAF_INFO);
+        state.update(leaf);
+        state.digest()
+    }
+
+    /// Appends the next leaf digest, as returned by [`leaf_digest()`](Self::leaf_digest()), to the root-level hash computation.
+    ///
+    /// The leaf digests **must** be appended in the original order of the leaves.
+    #[inline]
+    pub fn update(&mut self, leaf_digest: &[u8; DEFAULT_DIGEST_SIZE]) {
+        self.root.update(leaf_digest);
+    }
+
+    /// Concludes the tree hash computation and returns the final digest as an new array of size `N`.
+    ///
+    /// **Note:** The digest output size `N`, in bytes, must be a *positive* value! &#x1F6A8;
+    #[inline]
+    pub fn digest<const N: usize>(self) -> [u8; N] {
+        self.root.digest()
+    }
+
+    /// Concludes the tree hash computation and writes the final digest into the slice `digest_out`.
+    ///
+    /// **Note:** The specified digest output size, i.e., `digest_out.len()`, in bytes, must be a *positive* value! &#x1F6A8;
+    #[inline]
+    pub fn digest_to_slice(self, digest_out: &mut [u8]) {
+        self.root.digest_to_slice(digest_out)
+    }
+}
+
+impl Default for SpongeTree256 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// One-shot API
+// ---------------------------------------------------------------------------
+
+/// Convenience function for “one-shot” SpongeHash-AES256 computation in tree hashing mode
+///
+/// The tree hash value (digest) of the given `message` is returned as an new array of type `[u8; N]`. See [`SpongeTree256`] for details!
+///
+/// **Note:** The digests generated in tree hashing mode are ***different*** from the digests that are generated by [`compute()`](crate::compute()) for the same message! &#x1F6A8;
+pub fn compute_tree<const N: usize, T: AsRef<[u8]>>(message: T) -> [u8; N] {
+    let mut tree = SpongeTree256::new();
+    message.as_ref().chunks(TREE_LEAF_SIZE).for_each(|leaf| tree.update(&SpongeTree256::leaf_digest(leaf)));
+    tree.digest()
+}
+
+/// Convenience function for “one-shot” SpongeHash-AES256 computation in tree hashing mode
+///
+/// The tree hash value (digest) of the given `message` is written into the slice `digest_out`. See [`SpongeTree256`] for details!
+///
+/// **Note:** The digests generated in tree hashing mode are ***different*** from the digests that are generated by [`compute_to_slice()`](crate::compute_to_slice()) for the same message! &#x1F6A8;
+pub fn compute_tree_to_slice<T: AsRef<[u8]>>(digest_out: &mut [u8], message: T) {
+    let mut tree = SpongeTree256::new();
+    message.as_ref().chunks(TREE_LEAF_SIZE).for_each(|leaf| tree.update(&SpongeTree256::leaf_digest(leaf)));
+    tree.digest_to_slice(digest_out)
+}
@@ -60,12 +60,12 @@ impl BlockType {
         self.0 ^= other.0;
     }
 
-    /// Computes the bit-wise XOR of `raw_data` and *self*, stores the result "in-place" in *self*
+    /// Computes the bit-wise XOR of `data` and *self*, stores the result "in-place" in *self*
+    ///
+    /// The `data` array does **not** need to be aligned; it is simply loaded into a `u8x16` value, whose XOR is implemented by the `wide` crate.
     #[inline(always)]
-    pub fn xor_with_u8_ptr(&mut self, raw_data: *const u8) {
-        unsafe {
-            self.0 ^= u8x16::new(*raw_data.cast::<[u8; BLOCK_SIZE]>());
-        }
+    pub fn xor_with_array(&mut self, data: &[u8; BLOCK_SIZE]) {
+        self.0 ^= u8x16::new(*data);
     }
 
     /// Get a `&[u8; BLOCK_SIZE]` reference to the contained data
@@ -74,6 +74,13 @@ impl BlockType {
         self.0.as_array()
     }
 
+    /// Get a copy of the contained data, as `[u8; BLOCK_SIZE]` array
+    #[cfg(feature = "debug-introspection")]
+    #[inline(always)]
+    pub fn to_array(&self) -> [u8; BLOCK_SIZE] {
+        *self.as_array()
+    }
+
     /// Get a `&mut [u8; BLOCK_SIZE]` reference to the contained data
     #[inline(always)]
     fn as_mut_array(&mut self) -> &mut [u8; BLOCK_SIZE] {
@@ -198,19 +205,33 @@ impl Default for Aes256Crypto {
 // Functions
 // ---------------------------------------------------------------------------
 
-/// Determines the length, in bytes, of the range specified by two "raw" adjacent pointers
-#[inline(always)]
-pub fn length(from: *const u8, to: *const u8) -> usize {
-    debug_assert!(to >= from);
-    unsafe { to.offset_from(from) as usize }
-}
-
 /// Returns the version of the library as a string
 pub const fn version() -> &'static str {
     static PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
     PKG_VERSION
 }
 
+/// Returns the version of the library as a `(major, minor, patch)` tuple, e.g., for version comparisons
+pub const fn version_parts() -> (u64, u64, u64) {
+    const MAJOR: u64 = parse_decimal(env!("CARGO_PKG_VERSION_MAJOR"));
+    const MINOR: u64 = parse_decimal(env!("CARGO_PKG_VERSION_MINOR"));
+    const PATCH: u64 = parse_decimal(env!("CARGO_PKG_VERSION_PATCH"));
+    (MAJOR, MINOR, PATCH)
+}
+
+/// Parses a string of decimal digits at compile-time
+const fn parse_decimal(digits: &str) -> u64 {
+    let bytes = digits.as_bytes();
+    let (mut value, mut index) = (0u64, 0usize);
+    assert!(!bytes.is_empty(), "Version number must not be empty!");
+    while index < bytes.len() {
+        assert!(bytes[index].is_ascii_digit(), "Version number must be decimal!");
+        value = value * 10u64 + ((bytes[index] - b'0') as u64);
+        index += 1usize;
+    }
+    value
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -317,18 +338,18 @@ mod tests {
 
         fn do_xor_arrays(input0: &BlockType, input1: &BlockType) {
             let mut output_xor = input0.clone();
-            let mut output_ptr = input0.clone();
+            let mut output_arr = input0.clone();
             let mut output_ref = input0.clone();
 
             output_xor.xor_with(input1);
-            output_ptr.xor_with_u8_ptr(input1.as_array().as_ptr());
+            output_arr.xor_with_array(input1.as_array());
 
             for (dst, src) in output_ref.as_mut_array().iter_mut().zip(input1.as_array().iter()) {
                 *dst ^= src;
             }
 
             assert_eq!(&output_xor, &output_ref);
-            assert_eq!(&output_ptr, &output_ref);
+            assert_eq!(&output_arr, &output_ref);
         }
 
         #[test]
@@ -350,6 +371,24 @@ mod tests {
         fn test_xor_arrays_4() {
             do_xor_arrays(&BlockType::from_array(hex!("710180b32b5a982ee21d8e76d287e509")), &BlockType::from_array(hex!("389b742402576214410c0633722c593a")));
         }
+
+        #[test]
+        fn test_xor_arrays_unaligned() {
+            let input0 = BlockType::from_array(hex!("75863721fe83cf3d6f0500df428126ae"));
+            let mut buffer = [0u8; 2usize * BLOCK_SIZE + 1usize];
+            buffer[1usize..=BLOCK_SIZE].copy_from_slice(&hex!("cc39d4653cce685b8de3398eccfe9c48"));
+
+            for offset in 0usize..=BLOCK_SIZE {
+                let data: &[u8; BLOCK_SIZE] = buffer[offset..offset + BLOCK_SIZE].try_into().unwrap();
+                let mut output_arr = input0.clone();
+                let mut output_ref = input0.clone();
+                output_arr.xor_with_array(data);
+                for (dst, src) in output_ref.as_mut_array().iter_mut().zip(data.iter()) {
+                    *dst ^= src;
+                }
+                assert_eq!(&output_arr, &output_ref);
+            }
+        }
     }
 
     mod concat_keys {
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+#![cfg(feature = "alloc")]
+
+include!("include/utils.rs");
+
+use sponge_hash_aes256::{compute_into_vec, compute_to_slice};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+fn do_test(length: usize, info: Option<&str>, message: &str) {
+    let digest_vec = compute_into_vec(info, length, message.as_bytes());
+    let mut digest_ref = vec![0u8; length];
+    compute_to_slice(&mut digest_ref, info, message.as_bytes());
+
+    assert_eq!(digest_vec.len(), length);
+    assert!(digest_equal(&digest_vec, &digest_ref));
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_case_1a() {
+    for length in [1usize, 15usize, 16usize, 17usize, 32usize, 100usize, 256usize] {
+        do_test(length, None, "The quick brown fox jumps over the lazy dog");
+    }
+}
+
+#[test]
+pub fn test_case_1b() {
+    for length in [1usize, 15usize, 16usize, 17usize, 32usize, 100usize, 256usize] {
+        do_test(length, Some("thingamajig"), "The quick brown fox jumps over the lazy dog");
+    }
+}
+
+#[test]
+pub fn test_case_2() {
+    let digest = compute_into_vec(None, 32usize, "The quick brown fox jumps over the lazy dog");
+    assert_digest_eq(&digest.try_into().unwrap(), &hex!("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+}
+
+#[test]
+#[should_panic]
+pub fn test_case_3() {
+    compute_into_vec(None, 0usize, "The quick brown fox jumps over the lazy dog");
+}
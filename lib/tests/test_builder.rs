@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+include!("include/utils.rs");
+
+use core::num::NonZeroUsize;
+use sponge_hash_aes256::{SpongeHash256, SpongeHash256Builder, DEFAULT_DIGEST_SIZE};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+fn create_instance<const R: usize>(info: Option<&str>) -> SpongeHash256<R> {
+    if let Some(info) = info {
+        SpongeHash256::with_info(info)
+    } else {
+        SpongeHash256::new()
+    }
+}
+
+fn do_test<const R: usize>(info: Option<&str>, message: &str) {
+    let builder = SpongeHash256Builder::new().rounds(NonZeroUsize::new(R).unwrap());
+    let builder = if let Some(info) = info { builder.info(info) } else { builder };
+
+    // DynSpongeHash256::digest()
+    {
+        let mut expected = create_instance::<R>(info);
+        expected.update(message.as_bytes());
+        let mut hash = builder.clone().build();
+        hash.update(message.as_bytes());
+        assert_eq!(hash.rounds().get(), R);
+        assert_eq!(hash.bytes_absorbed(), message.len() as u64);
+        assert_digest_eq(&hash.digest::<DEFAULT_DIGEST_SIZE>(), &expected.digest());
+    }
+
+    // DynSpongeHash256::digest_to_slice()
+    {
+        let mut expected = create_instance::<R>(info);
+        expected.update(message.as_bytes());
+        let mut hash = builder.clone().build();
+        hash.update(message.as_bytes());
+        let mut digest = [0u8; 100usize];
+        hash.digest_to_slice(&mut digest);
+        assert!(digest_equal(&digest, &expected.digest::<100usize>()));
+    }
+
+    // DynSpongeHash256::digest_iter()
+    {
+        let mut expected = create_instance::<R>(info);
+        expected.update(message.as_bytes());
+        let mut hash = builder.clone().build();
+        hash.update(message.as_bytes());
+        let digest: Vec<u8> = hash.digest_iter().take(100usize).collect();
+        assert!(digest_equal(&digest, &expected.digest::<100usize>()));
+    }
+
+    // DynSpongeHash256::update_byte()
+    {
+        let mut expected = create_instance::<R>(info);
+        expected.update(message.as_bytes());
+        let mut hash = builder.build();
+        message.bytes().for_each(|value| hash.update_byte(value));
+        assert_eq!(hash.bytes_absorbed(), message.len() as u64);
+        assert_digest_eq(&hash.digest::<DEFAULT_DIGEST_SIZE>(), &expected.digest());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_case_1a() {
+    do_test::<1usize>(None, "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_1b() {
+    do_test::<1usize>(Some("thingamajig"), "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_2a() {
+    do_test::<3usize>(None, "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_2b() {
+    do_test::<3usize>(Some("thingamajig"), "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_3a() {
+    do_test::<257usize>(None, "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_3b() {
+    do_test::<257usize>(Some("thingamajig"), "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_4() {
+    let mut hash = SpongeHash256Builder::default().build();
+    hash.update("The quick brown fox jumps over the lazy dog");
+    assert_digest_eq(&hash.digest(), &hex!("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+}
+
+#[test]
+#[should_panic]
+pub fn test_case_5() {
+    let info = core::str::from_utf8(&[0x41u8; 256usize]).unwrap();
+    SpongeHash256Builder::new().info(info).build();
+}
+
+#[test]
+pub fn test_case_6() {
+    let mut hash_1 = SpongeHash256Builder::new().salt(b"\x01\x02\x03\x04").build();
+    let mut hash_2 = SpongeHash256Builder::new().salt(b"\x01\x02\x03\x05").build();
+    let mut hash_3 = SpongeHash256Builder::new().salt(b"\x01\x02\x03\x04").build();
+    hash_1.update("The quick brown fox jumps over the lazy dog");
+    hash_2.update("The quick brown fox jumps over the lazy dog");
+    hash_3.update("The quick brown fox jumps over the lazy dog");
+    let digest_1: [u8; DEFAULT_DIGEST_SIZE] = hash_1.digest();
+    let digest_2: [u8; DEFAULT_DIGEST_SIZE] = hash_2.digest();
+    assert!(!digest_equal(&digest_1, &digest_2));
+    assert_digest_eq(&hash_3.digest::<DEFAULT_DIGEST_SIZE>(), &digest_1);
+}
+
+#[test]
+pub fn test_case_7() {
+    let mut expected: SpongeHash256 = SpongeHash256::with_info("thingamajig");
+    expected.update(b"\x04\x01\x02\x03\x04The quick brown fox jumps over the lazy dog");
+    let mut hash = SpongeHash256Builder::new().info("thingamajig").salt(b"\x01\x02\x03\x04").build();
+    hash.update("The quick brown fox jumps over the lazy dog");
+    assert_digest_eq(&hash.digest::<DEFAULT_DIGEST_SIZE>(), &expected.digest());
+}
+
+#[test]
+#[should_panic]
+pub fn test_case_8() {
+    SpongeHash256Builder::new().salt(&[0x41u8; 256usize]).build();
+}
+
+#[test]
+pub fn test_case_9() {
+    let mut expected: SpongeHash256 = SpongeHash256::with_info_bytes(b"\x00\xFF\x80");
+    expected.update("The quick brown fox jumps over the lazy dog");
+    let mut hash = SpongeHash256Builder::new().info_bytes(b"\x00\xFF\x80").build();
+    hash.update("The quick brown fox jumps over the lazy dog");
+    assert_digest_eq(&hash.digest::<DEFAULT_DIGEST_SIZE>(), &expected.digest());
+}
+
+#[test]
+pub fn test_case_10() {
+    let mut hash = SpongeHash256Builder::new().rounds(NonZeroUsize::new(42usize).unwrap()).build();
+    hash.update("The quick brown fox jumps over the lazy dog");
+    assert_eq!(format!("{:?}", hash), "DynSpongeHash256 { rounds: 42, .. }");
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+include!("include/utils.rs");
+
+use core::num::NonZeroUsize;
+use sponge_hash_aes256::{SpongeHash256, SpongeHash256Builder, DEFAULT_DIGEST_SIZE};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+fn do_test<const R: usize, const N: usize>(message_a: &str, message_b: &str) {
+    // Reference sequence
+    let mut reference_a: SpongeHash256<R> = SpongeHash256::new();
+    reference_a.update(message_a);
+    let expected_a: [u8; N] = reference_a.digest();
+    let mut reference_b: SpongeHash256<R> = SpongeHash256::with_info("SpongeHash-AES256/chain");
+    reference_b.update(u64::to_be_bytes(N as u64));
+    reference_b.update(expected_a);
+    reference_b.update(message_b);
+    let expected_length = reference_b.bytes_absorbed();
+    let expected_b: [u8; DEFAULT_DIGEST_SIZE] = reference_b.digest();
+
+    // SpongeHash256::finalize_chain()
+    {
+        let mut hash_a: SpongeHash256<R> = SpongeHash256::new();
+        hash_a.update(message_a);
+        let mut digest_a = [0u8; N];
+        let mut hash_b = hash_a.finalize_chain(&mut digest_a);
+        hash_b.update(message_b);
+        assert_eq!(hash_b.bytes_absorbed(), expected_length);
+        assert!(digest_equal(&digest_a, &expected_a));
+        assert_digest_eq(&hash_b.digest::<DEFAULT_DIGEST_SIZE>(), &expected_b);
+    }
+
+    // DynSpongeHash256::finalize_chain()
+    {
+        let mut hash_a = SpongeHash256Builder::new().rounds(NonZeroUsize::new(R).unwrap()).build();
+        hash_a.update(message_a);
+        let mut digest_a = [0u8; N];
+        let mut hash_b = hash_a.finalize_chain(&mut digest_a);
+        hash_b.update(message_b);
+        assert_eq!(hash_b.rounds().get(), R);
+        assert_eq!(hash_b.bytes_absorbed(), expected_length);
+        assert!(digest_equal(&digest_a, &expected_a));
+        assert_digest_eq(&hash_b.digest::<DEFAULT_DIGEST_SIZE>(), &expected_b);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_case_1a() {
+    do_test::<1usize, 32usize>("The quick brown fox", "jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_1b() {
+    do_test::<1usize, 100usize>("The quick brown fox", "jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_2a() {
+    do_test::<3usize, 32usize>("The quick brown fox", "jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_2b() {
+    do_test::<3usize, 100usize>("The quick brown fox", "jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_3() {
+    let mut hash_a: SpongeHash256 = SpongeHash256::new();
+    hash_a.update("The quick brown fox");
+    let mut digest_a = [0u8; DEFAULT_DIGEST_SIZE];
+    let mut chained = hash_a.finalize_chain(&mut digest_a);
+    chained.update("jumps over the lazy dog");
+
+    let mut plain: SpongeHash256 = SpongeHash256::new();
+    plain.update(u64::to_be_bytes(DEFAULT_DIGEST_SIZE as u64));
+    plain.update(digest_a);
+    plain.update("jumps over the lazy dog");
+
+    assert!(!digest_equal(&chained.digest::<DEFAULT_DIGEST_SIZE>(), &plain.digest::<DEFAULT_DIGEST_SIZE>()));
+}
+
+#[test]
+pub fn test_case_4() {
+    let mut hash_a: SpongeHash256 = SpongeHash256::new();
+    hash_a.update("The quick brown fox");
+    let mut digest_a = [0u8; DEFAULT_DIGEST_SIZE];
+    let mut hash_b = hash_a.finalize_chain(&mut digest_a);
+    hash_b.update("jumps over the lazy dog");
+    assert_digest_eq(&hash_b.digest(), &hex!("0ee0844b485399f2b7bfe4d5e46aba471625da5a9c60b4ee8bb7555324d0ff1a"));
+}
+
+#[test]
+pub fn test_case_5() {
+    let mut hash_a: SpongeHash256 = SpongeHash256::new();
+    hash_a.update("The quick brown fox");
+    let mut digest_a = [0u8; 64usize];
+    let mut hash_b = hash_a.finalize_chain(&mut digest_a);
+    assert_eq!(hash_b.bytes_absorbed(), 72u64);
+    hash_b.update("jumps over the lazy dog");
+    assert_eq!(hash_b.bytes_absorbed(), 95u64);
+    assert!(digest_equal(
+        &digest_a,
+        &hex!("c9a4e880ab7729b5fd4485de723f35afd2f370b9d825acbe54090dec27616c2f29105f9641e7eda69eb4b2a1efef5045dbf5f4e97ff46417a2de9b2fb83a6009")
+    ));
+    assert_digest_eq(&hash_b.digest(), &hex!("db0a935b34af67ddd313216c12c44294986bba01e7c93a9e4fe3812975b5e57b"));
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+include!("include/utils.rs");
+
+use sponge_hash_aes256::{compute, compute_digest_typed, parse_hex, Digest, ParseError, DEFAULT_DIGEST_SIZE};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+static MESSAGE: &str = "The quick brown fox jumps over the lazy dog";
+
+fn do_test<const N: usize>(expected: &str) {
+    let digest: Digest<N> = compute_digest_typed(None, MESSAGE);
+    let raw_digest: [u8; N] = compute(None, MESSAGE);
+    assert_digest_eq(digest.as_bytes(), &raw_digest);
+
+    // Lower-case hex
+    assert_eq!(format!("{:x}", digest), expected);
+    assert_eq!(format!("{}", digest), expected);
+    assert_eq!(format!("{:#x}", digest), format!("0x{}", expected));
+
+    // Upper-case hex
+    assert_eq!(format!("{:X}", digest), expected.to_ascii_uppercase());
+    assert_eq!(format!("{:#X}", digest), format!("0x{}", expected.to_ascii_uppercase()));
+
+    // Length
+    assert_eq!(format!("{:x}", digest).len(), 2usize * N);
+
+    // Round-trip
+    assert_eq!(parse_hex::<N>(expected), Ok(raw_digest));
+    assert_eq!(parse_hex::<N>(&expected.to_ascii_uppercase()), Ok(raw_digest));
+    assert_eq!(Digest::<N>::try_from(expected), Ok(digest));
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_digest_1a() {
+    do_test::<DEFAULT_DIGEST_SIZE>("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a");
+}
+
+#[test]
+pub fn test_digest_1b() {
+    do_test::<1usize>("98");
+}
+
+#[test]
+pub fn test_digest_1c() {
+    do_test::<16usize>("98983fca13242441443a8e6a5f8e2b4a");
+}
+
+#[test]
+pub fn test_digest_2() {
+    let digest = Digest::from(hex!("00010a0f10a0f0ff"));
+    assert_eq!(format!("{}", digest), "00010a0f10a0f0ff");
+    assert_eq!(format!("{:X}", digest), "00010A0F10A0F0FF");
+    assert_eq!(digest.into_inner(), hex!("00010a0f10a0f0ff"));
+}
+
+#[test]
+pub fn test_digest_3() {
+    let digest: Digest<8usize> = Digest::from(hex!("00010a0f10a0f0ff"));
+    let bytes: &[u8] = digest.as_ref();
+    assert_eq!(bytes, &hex!("00010a0f10a0f0ff"));
+    assert_eq!(&digest[..2usize], &[0x00u8, 0x01u8]);
+    assert_eq!(digest.len(), 8usize);
+    assert_eq!((&digest).into_iter().copied().max(), Some(0xFFu8));
+    assert_eq!(digest.into_iter().map(u32::from).sum::<u32>(), 0x2B9u32);
+    assert_eq!(<[u8; 8usize]>::from(digest), hex!("00010a0f10a0f0ff"));
+}
+
+#[test]
+pub fn test_parse_hex_1() {
+    assert_eq!(parse_hex::<8usize>("00010a0f10a0f0ff"), Ok(hex!("00010a0f10a0f0ff")));
+    assert_eq!(parse_hex::<8usize>("00010A0F10A0F0FF"), Ok(hex!("00010a0f10a0f0ff")));
+    assert_eq!(parse_hex::<0usize>(""), Ok([]));
+}
+
+#[test]
+pub fn test_parse_hex_2() {
+    assert_eq!(parse_hex::<8usize>("00010a0f10a0f0f"), Err(ParseError::OddLength));
+    assert_eq!(parse_hex::<8usize>("0"), Err(ParseError::OddLength));
+    assert_eq!(parse_hex::<8usize>("00010a0f10a0f0"), Err(ParseError::InvalidLength));
+    assert_eq!(parse_hex::<8usize>("00010a0f10a0f0ff00"), Err(ParseError::InvalidLength));
+}
+
+#[test]
+pub fn test_parse_hex_3() {
+    assert_eq!(parse_hex::<8usize>("g0010a0f10a0f0ff"), Err(ParseError::InvalidCharacter(0usize)));
+    assert_eq!(parse_hex::<8usize>("00010a0f10a0f0fG"), Err(ParseError::InvalidCharacter(15usize)));
+    assert_eq!(parse_hex::<8usize>("0x010a0f10a0f0ff"), Err(ParseError::InvalidCharacter(1usize)));
+    assert_eq!(parse_hex::<8usize>("00010a0f 0a0f0ff"), Err(ParseError::InvalidCharacter(8usize)));
+    assert_eq!(parse_hex::<1usize>("\u{e4}"), Err(ParseError::InvalidCharacter(0usize)));
+    assert_eq!(Digest::<8usize>::try_from("00010a0f10a0f0fz"), Err(ParseError::InvalidCharacter(15usize)));
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+include!("include/utils.rs");
+
+use rand_pcg::{
+    rand_core::{Rng, SeedableRng},
+    Pcg64,
+};
+use sponge_hash_aes256::{compute, compute_to_slice, SpongeHash256, DEFAULT_DIGEST_SIZE};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+const ITERATIONS: usize = 512usize;
+const MAX_MESSAGE_LEN: u64 = 1024u64;
+const MAX_CHUNK_LEN: u64 = 80u64;
+
+fn random_below(random: &mut Pcg64, limit: u64) -> usize {
+    (random.next_u64() % limit) as usize
+}
+
+/// Absorb the message via `update()` calls of random sizes, including empty chunks and single bytes
+fn compute_streaming(random: &mut Pcg64, info: Option<&str>, message: &[u8], digest_out: &mut [u8]) {
+    let mut hash: SpongeHash256 = SpongeHash256::with_info(info.unwrap_or_default());
+    let mut remaining = message;
+
+    while !remaining.is_empty() {
+        let chunk_len = random_below(random, MAX_CHUNK_LEN).min(remaining.len());
+        let (chunk, tail) = remaining.split_at(chunk_len);
+        match chunk {
+            [value] => hash.update_byte(*value),
+            _ => hash.update(chunk),
+        }
+        remaining = tail;
+    }
+
+    hash.digest_to_slice(digest_out);
+}
+
+fn do_test(seed: [u8; 32usize], info: Option<&str>) {
+    let mut random = Pcg64::from_seed(seed);
+
+    for _ in 0usize..ITERATIONS {
+        let mut message = vec![0u8; random_below(&mut random, MAX_MESSAGE_LEN)];
+        random.fill_bytes(&mut message);
+
+        // Default digest size
+        let mut digest_streaming = [0u8; DEFAULT_DIGEST_SIZE];
+        compute_streaming(&mut random, info, &message, &mut digest_streaming);
+        assert_digest_eq(&digest_streaming, &compute(info, &message));
+
+        // Random digest size
+        let digest_len = 1usize + random_below(&mut random, 2u64 * DEFAULT_DIGEST_SIZE as u64);
+        let (mut digest_oneshot, mut digest_streaming) = (vec![0u8; digest_len], vec![0u8; digest_len]);
+        compute_to_slice(&mut digest_oneshot, info, &message);
+        compute_streaming(&mut random, info, &message, &mut digest_streaming);
+        assert!(digest_equal(&digest_oneshot, &digest_streaming), "Mismatch for message of {} bytes", message.len());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_equivalence_1a() {
+    do_test(hex!("4e6b1f0c9a2d83e57f10c4b9d2a6e83f0b5c7d19e2f4a6b8c0d1e3f5a7b9c1d3"), None);
+}
+
+#[test]
+pub fn test_equivalence_1b() {
+    do_test(hex!("a9c3e5f7b1d3f5a7c9e1b3d5f7a9c1e3b5d7f9a1c3e5b7d9f1a3c5e7b9d1f3a5"), Some("thingamajig"));
+}
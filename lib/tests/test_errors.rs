@@ -10,3 +10,9 @@ use std::hint::black_box;
 pub fn test_invalid_info_len() {
     black_box(SpongeHash256::<DEFAULT_PERMUTE_ROUNDS>::with_info(str::from_utf8(&[0x61u8; 256usize]).unwrap()));
 }
+
+#[should_panic(expected = "Info length exceeds the allowable maximum!")]
+#[test]
+pub fn test_invalid_info_len_utf8() {
+    black_box(SpongeHash256::<DEFAULT_PERMUTE_ROUNDS>::with_info(&"\u{e4}".repeat(200usize)));
+}
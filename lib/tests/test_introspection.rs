@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+#![cfg(feature = "debug-introspection")]
+
+include!("include/utils.rs");
+
+use sponge_hash_aes256::SpongeHash256;
+
+#[test]
+pub fn test_state_snapshot() {
+    let mut hash = SpongeHash256::default();
+    hash.update(b"The quick brown fox");
+
+    let (state0, state1, state2, offset) = hash.state_snapshot();
+
+    assert_digest_eq(&state0, &hex!("2606ec850b2935b93ce147a467327f46"));
+    assert_digest_eq(&state1, &hex!("7f63abad9ce7458b8eba9d18f72d2d0a"));
+    assert_digest_eq(&state2, &hex!("4973eac6e8521ef5dd6f3b6bbf57eed5"));
+    assert_eq!(offset, 4usize);
+}
+
+#[test]
+pub fn test_state_eq_clone() {
+    let mut hash: SpongeHash256 = SpongeHash256::default();
+    hash.update(b"The quick brown fox");
+
+    let mut clone = hash.clone();
+    assert_eq!(clone, hash);
+
+    clone.update(b" jumps over the lazy dog");
+    assert_ne!(clone, hash);
+
+    hash.update(b" jumps over the lazy dog");
+    assert_eq!(clone, hash);
+}
+
+#[test]
+pub fn test_state_eq_fresh() {
+    assert_eq!(SpongeHash256::<1usize>::new(), SpongeHash256::<1usize>::with_info(""));
+    assert_ne!(SpongeHash256::<1usize>::new(), SpongeHash256::<1usize>::with_info("thingamajig"));
+
+    let mut hash: SpongeHash256 = SpongeHash256::default();
+    hash.update(b"");
+    assert_eq!(hash, SpongeHash256::default());
+}
+
+#[test]
+pub fn test_state_eq_chunked() {
+    let mut hash_1: SpongeHash256 = SpongeHash256::default();
+    let mut hash_2: SpongeHash256 = SpongeHash256::default();
+    let mut hash_3: SpongeHash256 = SpongeHash256::default();
+
+    hash_1.update(b"The quick brown fox jumps over the lazy dog");
+    hash_2.update(b"The quick brown fox");
+    hash_2.update(b" jumps over the lazy dog");
+    b"The quick brown fox jumps over the lazy dog".iter().for_each(|value| hash_3.update_byte(*value));
+
+    assert_eq!(hash_1, hash_2);
+    assert_eq!(hash_1, hash_3);
+}
+
+#[test]
+pub fn test_state_eq_offset() {
+    let mut hash_1: SpongeHash256 = SpongeHash256::default();
+    let mut hash_2: SpongeHash256 = SpongeHash256::default();
+
+    hash_1.update(b"\x00");
+    assert_ne!(hash_1, hash_2);
+
+    hash_2.update(b"\x00");
+    assert_eq!(hash_1, hash_2);
+}
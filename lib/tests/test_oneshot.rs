@@ -4,7 +4,7 @@
 
 include!("include/utils.rs");
 
-use sponge_hash_aes256::{compute, compute_to_slice, DEFAULT_DIGEST_SIZE};
+use sponge_hash_aes256::{compute, compute_chunks, compute_to_slice, derive_keys, SpongeHash256, DEFAULT_DIGEST_SIZE};
 
 // ---------------------------------------------------------------------------
 // Test functions
@@ -23,6 +23,20 @@ fn do_test(expected: &[u8; DEFAULT_DIGEST_SIZE], info: Option<&str>, message: &s
         compute_to_slice(&mut digest, info, message.as_bytes());
         assert_digest_eq(&digest, expected);
     }
+
+    // compute_chunks()
+    {
+        let (head, tail) = message.as_bytes().split_at(message.len() / 2usize);
+        let digest = compute_chunks(info, [head, tail]);
+        assert_digest_eq(&digest, expected);
+    }
+}
+
+fn do_test_prefix<const N: usize, const M: usize>(info: Option<&str>, message: &str) {
+    assert!(N < M);
+    let digest_short: [u8; N] = compute(info, message.as_bytes());
+    let digest_long: [u8; M] = compute(info, message.as_bytes());
+    assert!(digest_equal(&digest_short, &digest_long[..N]));
 }
 
 // ---------------------------------------------------------------------------
@@ -182,3 +196,54 @@ pub fn test_case_6b() {
         do_test(expected, Some("thingamajig"), &MESSAGE[..len]);
     }
 }
+
+#[test]
+pub fn test_case_7a() {
+    do_test_prefix::<24usize, 32usize>(None, "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_7b() {
+    do_test_prefix::<32usize, 64usize>(None, "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_7c() {
+    do_test_prefix::<24usize, 64usize>(Some("thingamajig"), "The quick brown fox jumps over the lazy dog");
+}
+
+#[test]
+pub fn test_case_8a() {
+    let digest: [u8; DEFAULT_DIGEST_SIZE] = compute_chunks(None, ["ab", "c"]);
+    assert_digest_eq(&digest, &compute(None, "abc"));
+}
+
+#[test]
+pub fn test_case_8b() {
+    let digest: [u8; DEFAULT_DIGEST_SIZE] = compute_chunks(Some("thingamajig"), Vec::<&[u8]>::new());
+    assert_digest_eq(&digest, &compute(Some("thingamajig"), b""));
+}
+
+#[test]
+pub fn test_case_9a() {
+    let keys: [[u8; 32usize]; 4usize] = derive_keys(Some("thingamajig"), b"input keying material");
+    assert_eq!(keys, derive_keys::<32usize, 4usize>(Some("thingamajig"), b"input keying material"));
+    for (i, key_i) in keys.iter().enumerate() {
+        assert!(!digest_equal(key_i, &compute::<32usize, _>(Some("thingamajig"), b"input keying material")));
+        for key_j in &keys[(i + 1usize)..] {
+            assert!(!digest_equal(key_i, key_j));
+        }
+    }
+}
+
+#[test]
+pub fn test_case_9b() {
+    let [key_1, key_2]: [[u8; 32usize]; 2usize] = derive_keys(None, b"input keying material");
+    let mut hash: SpongeHash256 = SpongeHash256::new();
+    hash.update(b"input keying material");
+    let (mut expected_1, mut expected_2) = ([0u8; 32usize], [0u8; 32usize]);
+    hash.clone().digest_to_slice_domain(1u8, &mut expected_1);
+    hash.digest_to_slice_domain(2u8, &mut expected_2);
+    assert_digest_eq(&key_1, &expected_1);
+    assert_digest_eq(&key_2, &expected_2);
+}
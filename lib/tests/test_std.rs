@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+#![cfg(feature = "std")]
+
+include!("include/utils.rs");
+
+use sponge_hash_aes256::{compute, SpongeHash256, DEFAULT_DIGEST_SIZE};
+use std::io::{Cursor, Error, ErrorKind, Read, Result};
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+fn create_message(length: usize) -> Vec<u8> {
+    (0usize..length).map(|index| (index % 251usize) as u8).collect()
+}
+
+fn do_test(length: usize) {
+    let message = create_message(length);
+    let mut hash = SpongeHash256::default();
+    let total_bytes = hash.absorb_reader(&mut Cursor::new(&message)).unwrap();
+    assert_eq!(total_bytes, length as u64);
+    assert_digest_eq(&hash.digest::<DEFAULT_DIGEST_SIZE>(), &compute(None, &message));
+}
+
+/// Reader that yields the data in small pieces, interleaved with interruptions, and fails at the end
+struct FlakyReader<'a> {
+    data: &'a [u8],
+    count: usize,
+}
+
+impl Read for FlakyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.count += 1usize;
+        if self.data.is_empty() {
+            return Err(Error::other("end of data"));
+        }
+        if self.count.is_multiple_of(2usize) {
+            return Err(ErrorKind::Interrupted.into());
+        }
+        let length = self.data.len().min(buf.len()).min(7usize);
+        buf[..length].copy_from_slice(&self.data[..length]);
+        self.data = &self.data[length..];
+        Ok(length)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test cases
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_case_1() {
+    for length in [0usize, 1usize, 15usize, 16usize, 17usize, 8191usize, 8192usize, 8193usize, 100000usize] {
+        do_test(length);
+    }
+}
+
+#[test]
+pub fn test_case_2() {
+    let mut hash = SpongeHash256::default();
+    hash.update(b"The quick brown fox ");
+    let total_bytes = hash.absorb_reader(&mut Cursor::new(b"jumps over the lazy dog")).unwrap();
+    assert_eq!(total_bytes, 23u64);
+    assert_digest_eq(&hash.digest(), &hex!("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+}
+
+#[test]
+pub fn test_case_3() {
+    let message = create_message(100usize);
+    let mut hash = SpongeHash256::default();
+    let mut reader = FlakyReader { data: &message, count: 0usize };
+    let error = hash.absorb_reader(&mut reader).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Other);
+    assert!(reader.data.is_empty());
+}
@@ -35,6 +35,15 @@ fn do_test(expected: &[u8; DEFAULT_DIGEST_SIZE], info: Option<&str>, message: &s
         hash.digest_to_slice(&mut digest);
         assert_digest_eq(&digest, expected);
     }
+
+    // SpongeHash256::digest_iter()
+    {
+        let mut hash = create_instance(info);
+        hash.update(message.as_bytes());
+        let mut digest = [0u8; DEFAULT_DIGEST_SIZE];
+        digest.iter_mut().zip(hash.digest_iter()).for_each(|(value, byte)| *value = byte);
+        assert_digest_eq(&digest, expected);
+    }
 }
 
 fn do_test_n(expected: &[u8; DEFAULT_DIGEST_SIZE], info: Option<&str>, count: usize, message: &str) {
@@ -79,6 +88,32 @@ fn do_test_c(expected: &[u8; DEFAULT_DIGEST_SIZE], info: Option<&str>, message_1
     assert_digest_eq(&digest_2, expected);
 }
 
+fn do_test_i<const N: usize>(info: Option<&str>, message: &str) {
+    let mut hash = create_instance(info);
+    hash.update(message.as_bytes());
+
+    let mut digest_iter = hash.clone().digest_iter();
+    let digest_full: [u8; N] = hash.digest();
+
+    for (length, expected) in digest_full.iter().enumerate() {
+        assert_eq!(digest_iter.next(), Some(*expected), "Mismatch at offset {}", length);
+    }
+}
+
+fn do_test_b(info: Option<&str>, length: usize) {
+    let message: Vec<u8> = (0usize..length).map(|value| (value % 251usize) as u8).collect();
+
+    let mut hash_1 = create_instance(info);
+    hash_1.update(&message);
+
+    let mut hash_2 = create_instance(info);
+    message.iter().for_each(|value| hash_2.update_byte(*value));
+
+    let digest_1 = hash_1.digest::<DEFAULT_DIGEST_SIZE>();
+    let digest_2 = hash_2.digest::<DEFAULT_DIGEST_SIZE>();
+    assert_digest_eq(&digest_1, &digest_2);
+}
+
 // ---------------------------------------------------------------------------
 // Test vectors
 // ---------------------------------------------------------------------------
@@ -158,3 +193,106 @@ pub fn test_case_8b() {
         "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
     );
 }
+
+#[test]
+pub fn test_case_9a() {
+    do_test_i::<1usize>(None, "abc");
+}
+
+#[test]
+pub fn test_case_9b() {
+    do_test_i::<100usize>(None, "abc");
+}
+
+#[test]
+pub fn test_case_9c() {
+    do_test_i::<1024usize>(Some("thingamajig"), "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno");
+}
+
+#[test]
+pub fn test_case_10a() {
+    do_test_b(None, 1000usize);
+}
+
+#[test]
+pub fn test_case_10b() {
+    do_test_b(Some("thingamajig"), 1000usize);
+}
+
+#[test]
+pub fn test_case_11a() {
+    let mut hash = create_instance(Some("thingamajig"));
+    assert_eq!(hash.bytes_absorbed(), 0u64);
+    for chunk in ["The quick brown fox", " jumps over", "", " the lazy dog"] {
+        hash.update(chunk);
+    }
+    assert_eq!(hash.bytes_absorbed(), 43u64);
+    hash.update_byte(b'!');
+    assert_eq!(hash.bytes_absorbed(), 44u64);
+}
+
+#[test]
+pub fn test_case_11b() {
+    let mut hash = create_instance(None);
+    for chunk in ["The quick brown fox", " jumps over", " the lazy dog"] {
+        hash.update(chunk);
+    }
+    assert_eq!(hash.bytes_absorbed(), 43u64);
+    assert_digest_eq(&hash.digest(), &hex!("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+}
+
+#[test]
+pub fn test_case_12() {
+    let mut hash: SpongeHash256<3usize> = SpongeHash256::new();
+    hash.update("The quick brown fox jumps over the lazy dog");
+    let formatted = format!("{:?}", hash);
+    assert_eq!(formatted, "SpongeHash256 { rounds: 3, offset: 12, bytes_absorbed: 43, .. }");
+    assert!(!formatted.contains("state"));
+    assert!(!format!("{:#?}", hash).contains("state"));
+}
+
+#[test]
+pub fn test_case_13() {
+    let mut hash = create_instance(None);
+    hash.update("The quick brown fox jumps over the lazy dog");
+
+    let mut digest_default = [0u8; DEFAULT_DIGEST_SIZE];
+    hash.clone().digest_to_slice_domain(0u8, &mut digest_default);
+    assert_digest_eq(&digest_default, &hex!("98983fca13242441443a8e6a5f8e2b4a64f6da8e2b71b2122bb4efeb3d9da35a"));
+
+    let mut digests = [[0u8; DEFAULT_DIGEST_SIZE]; 256usize];
+    for (domain, digest) in digests.iter_mut().enumerate() {
+        hash.clone().digest_to_slice_domain(domain as u8, digest);
+    }
+    assert_digest_eq(&digests[0usize], &digest_default);
+
+    for (index, digest) in digests.iter().enumerate() {
+        for other in digests[index + 1usize..].iter() {
+            assert!(digest.iter().zip(other.iter()).filter(|(value0, value1)| value0 == value1).count() < DEFAULT_DIGEST_SIZE / 4usize);
+        }
+    }
+}
+
+#[test]
+pub fn test_case_14a() {
+    let mut hash_1: SpongeHash256 = SpongeHash256::with_info_bytes(b"app");
+    let mut hash_2: SpongeHash256 = SpongeHash256::with_info("app");
+    hash_1.update("The quick brown fox jumps over the lazy dog");
+    hash_2.update("The quick brown fox jumps over the lazy dog");
+    assert_digest_eq(&hash_1.digest::<DEFAULT_DIGEST_SIZE>(), &hash_2.digest());
+}
+
+#[test]
+pub fn test_case_14b() {
+    let mut hash_1: SpongeHash256 = SpongeHash256::with_info_bytes(&[0x00u8, 0xFFu8, 0x80u8, 0x7Fu8]);
+    let mut hash_2: SpongeHash256 = SpongeHash256::new();
+    hash_1.update("The quick brown fox jumps over the lazy dog");
+    hash_2.update("The quick brown fox jumps over the lazy dog");
+    assert!(!digest_equal(&hash_1.digest::<DEFAULT_DIGEST_SIZE>(), &hash_2.digest::<DEFAULT_DIGEST_SIZE>()));
+}
+
+#[test]
+#[should_panic]
+pub fn test_case_14c() {
+    let _hash: SpongeHash256 = SpongeHash256::with_info_bytes(&[0x41u8; 256usize]);
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: 0BSD
+// SpongeHash-AES256
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+include!("include/utils.rs");
+
+use sponge_hash_aes256::{compute, compute_tree, compute_tree_to_slice, SpongeTree256, DEFAULT_DIGEST_SIZE, TREE_LEAF_SIZE};
+use std::hint::black_box;
+
+// ---------------------------------------------------------------------------
+// Test functions
+// ---------------------------------------------------------------------------
+
+fn do_test(expected: &[u8; DEFAULT_DIGEST_SIZE], message: &[u8]) {
+    // compute_tree()
+    {
+        let digest = compute_tree(message);
+        assert_digest_eq(&digest, expected);
+    }
+
+    // compute_tree_to_slice()
+    {
+        let mut digest = [0u8; DEFAULT_DIGEST_SIZE];
+        compute_tree_to_slice(&mut digest, message);
+        assert_digest_eq(&digest, expected);
+    }
+
+    // SpongeTree256
+    {
+        let leaf_digests: Vec<[u8; DEFAULT_DIGEST_SIZE]> = message.chunks(TREE_LEAF_SIZE).map(SpongeTree256::leaf_digest).collect();
+        let mut tree = SpongeTree256::new();
+        leaf_digests.iter().for_each(|leaf_digest| tree.update(leaf_digest));
+        assert_digest_eq(&tree.digest(), expected);
+    }
+
+    // The tree digest must differ from the plain digest
+    {
+        let digest: [u8; DEFAULT_DIGEST_SIZE] = compute(None, message);
+        assert!(!digest_equal(&digest, expected));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test vectors
+// ---------------------------------------------------------------------------
+
+#[test]
+pub fn test_tree_1a() {
+    do_test(&hex!("759238854d75f5ee5c83903b26dcbd335212a776e643b425a4ed9bc375e6f23c"), &[]);
+}
+
+#[test]
+pub fn test_tree_1b() {
+    do_test(&hex!("80fc80486f785ec317866b13977710b86d68dbe8fcb2451845c39396d14a9053"), b"aaa");
+}
+
+#[test]
+pub fn test_tree_1c() {
+    do_test(&hex!("305a1900dfcb8b58e4acfffc38668ebe6d046cf36509409183f3e786f6eadd5f"), &vec![0x2Au8; TREE_LEAF_SIZE]);
+}
+
+#[test]
+pub fn test_tree_1d() {
+    do_test(&hex!("9dd4e06564246b5c2b733d344dce7067a66ee36c23121f96ce4c75e11b7890f4"), &vec![0x2Au8; (5usize * TREE_LEAF_SIZE) / 2usize]);
+}
+
+#[should_panic(expected = "Leaf size exceeds the allowable maximum!")]
+#[test]
+pub fn test_tree_invalid_leaf() {
+    black_box(SpongeTree256::leaf_digest(&vec![0x2Au8; TREE_LEAF_SIZE + 1usize]));
+}
@@ -14,3 +14,10 @@ pub fn test_version() {
     assert!(version_returned.pre.is_empty());
     assert_eq!(version_returned, version_expected);
 }
+
+#[test]
+pub fn test_version_parts() {
+    let version_expected = Version::parse(PKG_VERSION).expect("Failed to parse version!");
+    let (major, minor, patch) = sponge_hash_aes256::version_parts();
+    assert_eq!(Version::new(major, minor, patch), version_expected);
+}